@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Emitter, Manager};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Account {
+    pub uuid: String,
+    pub username: String,
+    /// Microsoft refresh token, encrypted at rest; `None` for offline accounts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token_encrypted: Option<String>,
+    #[serde(default)]
+    pub active: bool,
+}
+
+fn accounts_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join("accounts.json"))
+}
+
+fn load_accounts(app: &AppHandle) -> Result<Vec<Account>, String> {
+    let path = accounts_path(app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&text).map_err(|e| e.to_string())
+}
+
+fn save_accounts(app: &AppHandle, accounts: &[Account]) -> Result<(), String> {
+    let text = serde_json::to_string_pretty(accounts).map_err(|e| e.to_string())?;
+    fs::write(accounts_path(app)?, text).map_err(|e| e.to_string())?;
+    let _ = app.emit("accounts-changed", public_view(accounts));
+    Ok(())
+}
+
+/// Accounts as shown to the UI: tokens never leave the backend.
+fn public_view(accounts: &[Account]) -> Vec<Account> {
+    accounts
+        .iter()
+        .cloned()
+        .map(|a| Account {
+            refresh_token_encrypted: None,
+            ..a
+        })
+        .collect()
+}
+
+/// The account to launch with: `uuid` if given, otherwise the active one.
+pub fn resolve_account(app: &AppHandle, uuid: Option<&str>) -> Result<Option<Account>, String> {
+    let accounts = load_accounts(app)?;
+    match uuid {
+        Some(uuid) => accounts
+            .into_iter()
+            .find(|a| a.uuid == uuid)
+            .map(Some)
+            .ok_or_else(|| format!("No account with UUID {}", uuid)),
+        None => Ok(accounts.into_iter().find(|a| a.active)),
+    }
+}
+
+#[tauri::command]
+pub fn list_accounts(app: AppHandle) -> Result<Vec<Account>, String> {
+    Ok(public_view(&load_accounts(&app)?))
+}
+
+/// Add a local (offline) account. The first account added becomes active.
+#[tauri::command]
+pub fn add_offline_account(app: AppHandle, username: String) -> Result<Account, String> {
+    let username = username.trim().to_string();
+    // Same constraints the game applies to player names
+    if !(3..=16).contains(&username.len())
+        || !username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err("Usernames are 3-16 characters: letters, digits and _".to_string());
+    }
+
+    let mut accounts = load_accounts(&app)?;
+    if accounts
+        .iter()
+        .any(|a| a.username.eq_ignore_ascii_case(&username))
+    {
+        return Err(format!("An account named {} already exists", username));
+    }
+    let account = Account {
+        uuid: uuid::Uuid::new_v4().to_string(),
+        username,
+        refresh_token_encrypted: None,
+        active: accounts.is_empty(),
+    };
+    accounts.push(account.clone());
+    save_accounts(&app, &accounts)?;
+    Ok(account)
+}
+
+#[tauri::command]
+pub fn set_active_account(app: AppHandle, uuid: String) -> Result<(), String> {
+    let mut accounts = load_accounts(&app)?;
+    if !accounts.iter().any(|a| a.uuid == uuid) {
+        return Err(format!("No account with UUID {}", uuid));
+    }
+    for account in &mut accounts {
+        account.active = account.uuid == uuid;
+    }
+    save_accounts(&app, &accounts)
+}
+
+/// Remove an account; if it was active, the first remaining one takes over.
+#[tauri::command]
+pub fn remove_account(app: AppHandle, uuid: String) -> Result<(), String> {
+    let mut accounts = load_accounts(&app)?;
+    let before = accounts.len();
+    accounts.retain(|a| a.uuid != uuid);
+    if accounts.len() == before {
+        return Err(format!("No account with UUID {}", uuid));
+    }
+    if !accounts.iter().any(|a| a.active) {
+        if let Some(first) = accounts.first_mut() {
+            first.active = true;
+        }
+    }
+    save_accounts(&app, &accounts)
+}
@@ -4,6 +4,12 @@ use std::collections::HashMap;
 #[derive(Debug, Deserialize)]
 pub struct AssetIndexJson {
     pub objects: HashMap<String, AssetObject>,
+    /// `legacy` index (1.6 to 1.7.2): the game reads files by name from `virtual/<index>/`
+    #[serde(default, rename = "virtual")]
+    pub is_virtual: bool,
+    /// `pre-1.6` index: the game reads files by name from `resources/` in its game dir
+    #[serde(default)]
+    pub map_to_resources: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -1,34 +1,46 @@
 use serde::Serialize;
 use std::fs;
-use std::io::{BufRead, BufReader, Read};
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use tauri::{AppHandle, Emitter, Manager, State};
 
-use crate::install::{install_assets, install_client_jar, install_libraries};
-use crate::instance::{Instance, InstanceState};
+use crate::accounts;
+use crate::error::AppError;
+use crate::install::{install_assets, install_client_jar, install_game_files, install_libraries};
+use crate::instance::{
+    read_instance, Instance, InstanceState, LaunchProfile, INSTANCE_SCHEMA_VERSION,
+};
 use crate::java::ensure_java;
 use crate::launch::build_classpath;
 use crate::minecraft::get_manifest;
+use crate::settings::ModsUpdateOnLaunch;
 use crate::version::VersionJson;
+use futures_util::stream::{FuturesUnordered, StreamExt};
 use std::collections::{HashMap, HashSet};
 use std::sync::Mutex;
 
 // Helper function to convert Maven coordinates to file path
 // e.g., "net.fabricmc:fabric-loader:0.18.4" -> "net/fabricmc/fabric-loader/0.18.4/fabric-loader-0.18.4.jar"
-fn maven_coords_to_path(coords: &str) -> Option<String> {
+// Also accepts a classifier and extension: "org.lwjgl:lwjgl:3.3.1:natives-windows@jar"
+pub(crate) fn maven_coords_to_path(coords: &str) -> Option<String> {
+    let (coords, ext) = coords.split_once('@').unwrap_or((coords, "jar"));
     let parts: Vec<&str> = coords.split(':').collect();
-    if parts.len() != 3 {
+    if parts.len() != 3 && parts.len() != 4 {
         return None;
     }
 
     let group_id = parts[0].replace('.', "/");
     let artifact_id = parts[1];
     let version = parts[2];
+    let file_stem = match parts.get(3) {
+        Some(classifier) => format!("{}-{}-{}", artifact_id, version, classifier),
+        None => format!("{}-{}", artifact_id, version),
+    };
 
     Some(format!(
-        "{}/{}/{}/{}-{}.jar",
-        group_id, artifact_id, version, artifact_id, version
+        "{}/{}/{}/{}.{}",
+        group_id, artifact_id, version, file_stem, ext
     ))
 }
 
@@ -38,16 +50,242 @@ pub use crate::loader::*;
 #[derive(Default)]
 pub struct ChildProcessState(pub Mutex<HashMap<String, std::process::Child>>);
 
+/// Base directory for game data: the user's `data_root_override` when it is usable,
+/// otherwise the app data dir. An unusable override falls back rather than failing,
+/// so an existing install at the default location keeps working.
+fn data_root(app: &AppHandle) -> Result<PathBuf, String> {
+    let settings = crate::settings::get_settings(app.clone()).unwrap_or_default();
+    if let Some(dir) = settings
+        .data_root_override
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+    {
+        let path = PathBuf::from(dir);
+        if fs::create_dir_all(&path).is_ok() {
+            return Ok(path);
+        }
+        log::warn!(
+            "Data root override {} is unavailable, using default location",
+            path.display()
+        );
+    }
+    app.path().app_data_dir().map_err(|e| e.to_string())
+}
+
 pub fn minecraft_root(app: &AppHandle) -> Result<PathBuf, String> {
-    let path = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("minecraft");
+    let path = data_root(app)?.join("minecraft");
     fs::create_dir_all(&path).map_err(|e| e.to_string())?;
     Ok(path)
 }
 
+fn assets_dir_override(settings: &crate::settings::Settings) -> Option<PathBuf> {
+    settings
+        .assets_dir_override
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Where asset indexes and objects live: the user's `assets_dir_override` (typically
+/// another launcher's assets folder, shared to save space) when it is usable, otherwise
+/// `minecraft/assets`. Like `data_root`, an unusable override falls back.
+pub fn assets_root(app: &AppHandle) -> Result<PathBuf, String> {
+    let settings = crate::settings::get_settings(app.clone()).unwrap_or_default();
+    if let Some(path) = assets_dir_override(&settings) {
+        if fs::create_dir_all(&path).is_ok() {
+            return Ok(path);
+        }
+        log::warn!(
+            "Assets directory override {} is unavailable, using default location",
+            path.display()
+        );
+    }
+    Ok(minecraft_root(app)?.join("assets"))
+}
+
+/// The assets folder when it comes from `assets_dir_override`. Other launchers use it
+/// too, so cleanup can't tell which of its files are still needed and leaves it alone.
+fn shared_assets_dir(app: &AppHandle) -> Result<Option<PathBuf>, String> {
+    let assets = assets_root(app)?;
+    Ok((assets != minecraft_root(app)?.join("assets")).then_some(assets))
+}
+
+fn refuse_shared_assets(app: &AppHandle) -> Result<(), String> {
+    match shared_assets_dir(app)? {
+        Some(dir) => Err(format!(
+            "Assets at {} are shared with other launchers; manage them from there",
+            dir.display()
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Startup check that the game data directory can be created and written to, so a
+/// read-only volume or profile permission problem is reported instead of failing later.
+#[tauri::command]
+pub fn check_data_dir(app: AppHandle) -> Result<String, String> {
+    let root = data_root(&app)
+        .map_err(|e| format!("Could not determine the launcher data directory: {}", e))?
+        .join("minecraft");
+    crate::settings::ensure_writable(&root)?;
+    Ok(root.to_string_lossy().to_string())
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Serialize)]
+pub struct DataDirIssue {
+    pub severity: DiagnosticSeverity,
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+pub struct DataDirReport {
+    pub root: String,
+    pub instances_ok: u32,
+    /// Worst first
+    pub issues: Vec<DataDirIssue>,
+}
+
+/// Support check for a half-initialized or damaged data dir: the expected folders under
+/// `minecraft/`, instance.json files that don't parse, and instance folders without one.
+/// Nothing is repaired or migrated; broken files are only reported.
+#[tauri::command]
+pub fn diagnose_data_dir(app: AppHandle) -> Result<DataDirReport, String> {
+    let root = data_root(&app)?.join("minecraft");
+    let settings = crate::settings::get_settings(app.clone()).unwrap_or_default();
+    let assets = assets_dir_override(&settings).unwrap_or_else(|| root.join("assets"));
+    let mut issues = Vec::new();
+    let mut issue = |severity, path: &Path, message: String| {
+        issues.push(DataDirIssue {
+            severity,
+            path: path.to_string_lossy().to_string(),
+            message,
+        })
+    };
+
+    if !root.is_dir() {
+        issue(
+            DiagnosticSeverity::Error,
+            &root,
+            "Game data folder does not exist".to_string(),
+        );
+    } else if let Err(e) = crate::settings::ensure_writable(&root) {
+        issue(DiagnosticSeverity::Error, &root, e);
+    }
+    for (dir, path) in [
+        ("versions", root.join("versions")),
+        ("libraries", root.join("libraries")),
+        ("assets", assets.clone()),
+        ("instances", root.join("instances")),
+    ] {
+        if path.exists() && !path.is_dir() {
+            issue(
+                DiagnosticSeverity::Error,
+                &path,
+                format!("`{}` is a file, not a folder", dir),
+            );
+        } else if !path.exists() {
+            // Created on demand by the first install, so only odd once something is installed
+            issue(
+                DiagnosticSeverity::Info,
+                &path,
+                format!(
+                    "`{}` folder is missing; it will be created when needed",
+                    dir
+                ),
+            );
+        }
+    }
+    if root.join("versions").is_dir() && !assets.is_dir() {
+        issue(
+            DiagnosticSeverity::Warning,
+            &assets,
+            "Versions are installed but assets are missing; repair the instances using them"
+                .to_string(),
+        );
+    }
+
+    let mut instances_ok = 0;
+    let mut seen_ids: HashMap<String, PathBuf> = HashMap::new();
+    let instance_dirs = fs::read_dir(root.join("instances"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_dir());
+    for dir in instance_dirs {
+        let meta_path = dir.join("instance.json");
+        if !meta_path.exists() {
+            issue(
+                DiagnosticSeverity::Warning,
+                &dir,
+                "Instance folder has no instance.json and won't show up in the launcher"
+                    .to_string(),
+            );
+            continue;
+        }
+        let raw = match fs::read_to_string(&meta_path)
+            .map_err(|e| e.to_string())
+            .and_then(|t| serde_json::from_str::<serde_json::Value>(&t).map_err(|e| e.to_string()))
+        {
+            Ok(raw) => raw,
+            Err(e) => {
+                issue(
+                    DiagnosticSeverity::Error,
+                    &meta_path,
+                    format!("instance.json is unreadable: {}", e),
+                );
+                continue;
+            }
+        };
+        // `read_instance` would migrate this in place; here it's only reported
+        let instance = match serde_json::from_value::<Instance>(raw) {
+            Ok(instance) => instance,
+            Err(e) => {
+                issue(
+                    DiagnosticSeverity::Warning,
+                    &meta_path,
+                    format!(
+                        "instance.json doesn't match the current format and will be migrated on next load ({})",
+                        e
+                    ),
+                );
+                continue;
+            }
+        };
+        if let Some(other) = seen_ids.insert(instance.id.clone(), dir.clone()) {
+            issue(
+                DiagnosticSeverity::Error,
+                &meta_path,
+                format!(
+                    "Instance id {} is also used by {}",
+                    instance.id,
+                    other.display()
+                ),
+            );
+            continue;
+        }
+        instances_ok += 1;
+    }
+
+    issues.sort_by(|a, b| b.severity.cmp(&a.severity));
+    Ok(DataDirReport {
+        root: root.to_string_lossy().to_string(),
+        instances_ok,
+        issues,
+    })
+}
+
 fn versions_root(app: &AppHandle) -> Result<PathBuf, String> {
     let path = minecraft_root(app)?.join("versions");
     fs::create_dir_all(&path).map_err(|e| e.to_string())?;
@@ -73,6 +311,22 @@ fn sanitize_folder_name(name: &str) -> String {
     s.trim().to_string().trim_end_matches('.').to_string()
 }
 
+/// Join `relative` onto `base`, refusing absolute paths and `..` so the result can't
+/// escape `base`. Used for paths that come from modpacks or the UI.
+pub(crate) fn resolve_within(base: &Path, relative: &str) -> Result<PathBuf, String> {
+    let rel = Path::new(relative);
+    let clean = rel.components().all(|c| {
+        matches!(
+            c,
+            std::path::Component::Normal(_) | std::path::Component::CurDir
+        )
+    });
+    if relative.is_empty() || !clean {
+        return Err(format!("Path escapes {}: {}", base.display(), relative));
+    }
+    Ok(base.join(rel))
+}
+
 /// Return a unique folder name: base_name or "base_name (2)", "base_name (3)", etc.
 fn unique_instance_folder_name(app: &AppHandle, base_name: &str) -> Result<String, String> {
     let base = if base_name.is_empty() {
@@ -114,11 +368,9 @@ pub fn instance_dir(app: &AppHandle, id: &str) -> Result<PathBuf, String> {
         for entry in entries.flatten() {
             let meta_path = entry.path().join("instance.json");
             if meta_path.exists() {
-                if let Ok(text) = fs::read_to_string(&meta_path) {
-                    if let Ok(instance) = serde_json::from_str::<Instance>(&text) {
-                        if instance.id == id {
-                            return Ok(entry.path());
-                        }
+                if let Ok(instance) = read_instance(&meta_path) {
+                    if instance.id == id {
+                        return Ok(entry.path());
                     }
                 }
             }
@@ -131,9 +383,36 @@ pub fn instance_meta_path(app: &AppHandle, id: &str) -> Result<PathBuf, String>
     Ok(instance_dir(app, id)?.join("instance.json"))
 }
 
+/// The version manifest; `refresh` skips the cached copy (for "refresh versions" in the UI).
 #[tauri::command]
-pub async fn get_version_manifest() -> Result<crate::minecraft::VersionManifest, String> {
-    get_manifest().await
+pub async fn get_version_manifest(
+    refresh: Option<bool>,
+) -> Result<crate::minecraft::VersionManifest, AppError> {
+    if refresh.unwrap_or(false) {
+        crate::minecraft::refresh_manifest().await
+    } else {
+        get_manifest().await
+    }
+}
+
+/// Recreate an instance folder from backed-up metadata. Game files aren't included,
+/// so machine-specific paths are dropped and launch recovery installs the version.
+pub(crate) fn restore_instance_metadata(
+    app: &AppHandle,
+    mut instance: Instance,
+) -> Result<(), String> {
+    let folder_name = unique_instance_folder_name(app, &instance.name)?;
+    let dir = instances_root(app)?.join(folder_name);
+    fs::create_dir_all(dir.join(".minecraft")).map_err(|e| e.to_string())?;
+
+    instance.java_path = None;
+    instance.state = InstanceState::Ready;
+    instance.schema_version = instance.schema_version.max(INSTANCE_SCHEMA_VERSION);
+    fs::write(
+        dir.join("instance.json"),
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -145,10 +424,8 @@ pub async fn list_instances(app: AppHandle) -> Result<Vec<Instance>, String> {
         for entry in entries.flatten() {
             let meta_path = entry.path().join("instance.json");
             if meta_path.exists() {
-                if let Ok(text) = fs::read_to_string(meta_path) {
-                    if let Ok(instance) = serde_json::from_str::<Instance>(&text) {
-                        instances.push(instance);
-                    }
+                if let Ok(instance) = read_instance(&meta_path) {
+                    instances.push(instance);
                 }
             }
         }
@@ -158,12 +435,258 @@ pub async fn list_instances(app: AppHandle) -> Result<Vec<Instance>, String> {
     Ok(instances)
 }
 
+/// Like `list_instances`, but filtered server-side. Every filter is optional:
+/// `query` is a case-insensitive name substring, `tags` must all be present on
+/// the instance, `loader` matches the installed loader ("vanilla" for none) and
+/// `mc_version` matches the game version.
+#[tauri::command]
+pub async fn list_instances_filtered(
+    app: AppHandle,
+    query: Option<String>,
+    tags: Option<Vec<String>>,
+    loader: Option<String>,
+    mc_version: Option<String>,
+) -> Result<Vec<Instance>, String> {
+    let query = query
+        .map(|q| q.trim().to_lowercase())
+        .filter(|q| !q.is_empty());
+    let tags = normalize_tags(tags.unwrap_or_default());
+    let loader = loader
+        .map(|l| l.trim().to_lowercase())
+        .filter(|l| !l.is_empty());
+    let mc_version = mc_version
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+
+    let instances = list_instances(app).await?;
+    Ok(instances
+        .into_iter()
+        .filter(|i| {
+            query
+                .as_ref()
+                .is_none_or(|q| i.name.to_lowercase().contains(q))
+        })
+        .filter(|i| {
+            tags.iter()
+                .all(|t| i.tags.iter().any(|it| it.eq_ignore_ascii_case(t)))
+        })
+        .filter(|i| {
+            loader.as_ref().is_none_or(|l| match &i.loader {
+                Some(il) => il.eq_ignore_ascii_case(l),
+                None => l == "vanilla",
+            })
+        })
+        .filter(|i| {
+            mc_version
+                .as_ref()
+                .is_none_or(|v| i.mc_version.as_ref().unwrap_or(&i.version) == v)
+        })
+        .collect())
+}
+
+/// Trim, drop empties and de-duplicate (case-insensitively), keeping first-seen order.
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    tags.into_iter()
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty() && seen.insert(t.to_lowercase()))
+        .collect()
+}
+
+#[tauri::command]
+pub async fn set_instance_tags(
+    app: AppHandle,
+    instance_id: String,
+    tags: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let meta_path = instance_meta_path(&app, &instance_id)?;
+    let mut instance = read_instance(&meta_path)?;
+
+    instance.tags = normalize_tags(tags);
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(instance.tags)
+}
+
+// --- Instance Groups ---
+
+/// Instances sharing a group. `name` is unset for the ungrouped bucket.
+#[derive(Serialize)]
+pub struct InstanceGroup {
+    pub name: Option<String>,
+    pub instances: Vec<Instance>,
+}
+
+/// Trim a group name; an empty one means "no group". Group names follow the
+/// same rules as instance names.
+fn normalize_group(group: Option<String>) -> Result<Option<String>, String> {
+    match group.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(name) => validate_instance_name(name)
+            .map(Some)
+            .map_err(|e| e.replacen("Instance name", "Group name", 1)),
+    }
+}
+
+/// Spell `group` the way existing instances already do, so "Modded" and "modded"
+/// don't become two groups.
+fn canonical_group(instances: &[Instance], group: Option<String>) -> Option<String> {
+    let group = group?;
+    instances
+        .iter()
+        .filter_map(|i| i.group.as_ref())
+        .find(|g| g.eq_ignore_ascii_case(&group))
+        .cloned()
+        .or(Some(group))
+}
+
+/// Put an instance in a group, or take it out of its group with `None`.
+#[tauri::command]
+pub async fn set_instance_group(
+    app: AppHandle,
+    instance_id: String,
+    group: Option<String>,
+) -> Result<Instance, String> {
+    let group = normalize_group(group)?;
+    let instances = list_instances(app.clone()).await?;
+    let meta_path = instance_meta_path(&app, &instance_id)?;
+    let mut instance = read_instance(&meta_path)?;
+
+    instance.group = canonical_group(&instances, group);
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+    let _ = app.emit("instance-state-changed", &instance);
+    Ok(instance)
+}
+
+/// All instances by group: named groups alphabetically, then the ungrouped bucket.
+/// Within a group, instances keep `list_instances` order (newest first).
+#[tauri::command]
+pub async fn list_instance_groups(app: AppHandle) -> Result<Vec<InstanceGroup>, String> {
+    let mut groups: Vec<InstanceGroup> = Vec::new();
+    for instance in list_instances(app).await? {
+        let key = instance.group.as_deref().map(str::to_lowercase);
+        match groups
+            .iter_mut()
+            .find(|g| g.name.as_deref().map(str::to_lowercase) == key)
+        {
+            Some(group) => group.instances.push(instance),
+            None => groups.push(InstanceGroup {
+                name: instance.group.clone(),
+                instances: vec![instance],
+            }),
+        }
+    }
+    groups.sort_by_cached_key(|g| (g.name.is_none(), g.name.as_deref().map(str::to_lowercase)));
+    Ok(groups)
+}
+
+/// Move every member of `group` to `new_group` (`None` ungroups them). Returns how
+/// many instances changed.
+async fn reassign_group(
+    app: &AppHandle,
+    group: &str,
+    new_group: Option<String>,
+) -> Result<usize, String> {
+    let in_group = |i: &Instance| {
+        i.group
+            .as_deref()
+            .is_some_and(|g| g.eq_ignore_ascii_case(group.trim()))
+    };
+    let (members, others): (Vec<Instance>, Vec<Instance>) = list_instances(app.clone())
+        .await?
+        .into_iter()
+        .partition(in_group);
+    // Matched against the other groups only, so a rename can change a group's case
+    let new_group = canonical_group(&others, new_group);
+    let mut moved = 0;
+    for mut instance in members {
+        instance.group = new_group.clone();
+        fs::write(
+            instance_meta_path(app, &instance.id)?,
+            serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+        )
+        .map_err(|e| e.to_string())?;
+        let _ = app.emit("instance-state-changed", &instance);
+        moved += 1;
+    }
+    Ok(moved)
+}
+
+/// Rename a group, or merge it into another by giving that one's name.
+#[tauri::command]
+pub async fn rename_instance_group(
+    app: AppHandle,
+    group: String,
+    new_name: String,
+) -> Result<usize, String> {
+    let new_name = normalize_group(Some(new_name))?.ok_or("Group name can't be empty")?;
+    reassign_group(&app, &group, Some(new_name)).await
+}
+
+/// Delete a group; its instances stay, ungrouped.
+#[tauri::command]
+pub async fn delete_instance_group(app: AppHandle, group: String) -> Result<usize, String> {
+    reassign_group(&app, &group, None).await
+}
+
+/// Longest display name accepted, in characters.
+const MAX_INSTANCE_NAME_CHARS: usize = 64;
+
+/// Trim a display name and reject empty, overlong or control-character names.
+fn validate_instance_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Instance name can't be empty".to_string());
+    }
+    if name.chars().count() > MAX_INSTANCE_NAME_CHARS {
+        return Err(format!(
+            "Instance name is longer than {} characters",
+            MAX_INSTANCE_NAME_CHARS
+        ));
+    }
+    if name.chars().any(char::is_control) {
+        return Err("Instance name can't contain control characters".to_string());
+    }
+    Ok(name.to_string())
+}
+
+/// Change only an instance's display name. Its folder keeps its name, and every
+/// other field is re-read from disk so a state change made meanwhile is kept.
+#[tauri::command]
+pub async fn rename_instance(
+    app: AppHandle,
+    instance_id: String,
+    new_name: String,
+) -> Result<Instance, String> {
+    let name = validate_instance_name(&new_name)?;
+    let meta_path = instance_meta_path(&app, &instance_id)?;
+    let mut instance = read_instance(&meta_path)?;
+
+    instance.name = name;
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+    let _ = app.emit("instance-state-changed", &instance);
+    Ok(instance)
+}
+
 #[tauri::command]
 pub async fn create_instance(
     app: AppHandle,
     name: String,
     version: String,
 ) -> Result<String, String> {
+    let name = validate_instance_name(&name)?;
     let id = uuid::Uuid::new_v4().to_string();
     let folder_name = unique_instance_folder_name(&app, &name)?;
     let root = instances_root(&app)?;
@@ -186,9 +709,20 @@ pub async fn create_instance(
         max_memory: None,
         min_memory: None,
         java_args: None,
+        game_args: None,
+        auto_connect_server: None,
+        auto_load_world: None,
         java_warning_ignored: false,
+        demo: false,
+        schema_version: INSTANCE_SCHEMA_VERSION,
         loader: None,
         loader_version: None,
+        tags: Vec::new(),
+        group: None,
+        launch_profiles: Vec::new(),
+        private_versions: false,
+        pinned_version: false,
+        auto_update_mods_on_launch: None,
     };
 
     let meta_path = dir.join("instance.json");
@@ -201,67 +735,264 @@ pub async fn create_instance(
     Ok(instance.id)
 }
 
+/// Folders (and files) carried over from an external `.minecraft` by `import_external_instance`.
+const IMPORTED_GAME_DATA: &[&str] = &["saves", "mods", "config", "resourcepacks", "options.txt"];
+
+fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Result<(), String> {
+    fs::create_dir_all(dst).map_err(|e| e.to_string())?;
+    for entry in fs::read_dir(src).map_err(|e| e.to_string())?.flatten() {
+        let from = entry.path();
+        let to = dst.join(entry.file_name());
+        if from.is_dir() {
+            copy_dir_recursive(&from, &to)?;
+        } else {
+            fs::copy(&from, &to).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn symlink_path(src: &std::path::Path, dst: &std::path::Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(src, dst)
+}
+
+#[cfg(windows)]
+fn symlink_path(src: &std::path::Path, dst: &std::path::Path) -> std::io::Result<()> {
+    if src.is_dir() {
+        std::os::windows::fs::symlink_dir(src, dst)
+    } else {
+        std::os::windows::fs::symlink_file(src, dst)
+    }
+}
+
+/// The game dir under a user-picked folder. MultiMC/Prism instance folders keep it one level down.
+fn locate_external_game_dir(given: &str) -> Result<PathBuf, String> {
+    let looks_like_mc =
+        |p: &std::path::Path| p.join("saves").is_dir() || p.join("versions").is_dir();
+
+    let root = PathBuf::from(given);
+    [
+        root.clone(),
+        root.join(".minecraft"),
+        root.join("minecraft"),
+    ]
+    .into_iter()
+    .find(|p| looks_like_mc(p))
+    .ok_or_else(|| {
+        format!(
+            "{} doesn't look like a Minecraft directory (no saves or versions folder)",
+            given
+        )
+    })
+}
+
+/// Create an instance from an existing vanilla launcher or MultiMC/Prism game dir,
+/// copying (or symlinking) its worlds, mods, configs and resource packs.
 #[tauri::command]
-pub async fn delete_instance(
+pub async fn import_external_instance(
     app: AppHandle,
-    instance_id: String,
-    delete_version: bool,
-) -> Result<(), String> {
-    let dir = instance_dir(&app, &instance_id)?;
-
-    // If delete_version is true, we need to check if other instances use it
-    if delete_version {
-        let instances = list_instances(app.clone()).await?;
-        // Determine version id to delete. If the instance had a loader installed, delete the derived loader-backed version
-        let version_id = {
-            let meta_text =
-                fs::read_to_string(dir.join("instance.json")).map_err(|e| e.to_string())?;
-            let instance: Instance = serde_json::from_str(&meta_text).map_err(|e| e.to_string())?;
-            if let (Some(loader), Some(loader_version)) =
-                (instance.loader.clone(), instance.loader_version.clone())
-            {
-                format!("{}-loader-{}-{}", loader, loader_version, instance.version)
-            } else {
-                instance.version
-            }
-        };
+    name: String,
+    source_minecraft_dir: String,
+    mc_version: String,
+    loader: Option<String>,
+    loader_version: Option<String>,
+    symlink: Option<bool>,
+) -> Result<String, String> {
+    let source = locate_external_game_dir(&source_minecraft_dir)?;
+
+    let loader = loader
+        .map(|l| l.trim().to_lowercase())
+        .filter(|l| !l.is_empty() && l != "vanilla");
+    if let Some(l) = &loader {
+        if l != "fabric" && l != "quilt" {
+            return Err(format!("Importing {} instances is not supported yet", l));
+        }
+    }
+    let loader_version = match (&loader, loader_version) {
+        (Some(_), Some(v)) => Some(v),
+        (Some(l), None) => get_recommended_loader_version(l.clone(), mc_version.clone())
+            .await
+            .map_err(|e| e.to_string())?,
+        (None, _) => None,
+    };
 
-        let other_uses = instances
-            .iter()
-            .filter(|i| {
-                if i.id == instance_id {
-                    return false;
-                }
-                let other_vid = if let (Some(loader), Some(loader_version)) =
-                    (i.loader.clone(), i.loader_version.clone())
-                {
-                    format!("{}-loader-{}-{}", loader, loader_version, i.version)
-                } else {
-                    i.version.clone()
-                };
-                other_vid == version_id
-            })
-            .count();
+    let instance_id = create_instance(app.clone(), name, mc_version).await?;
+    let game_dir = instance_dir(&app, &instance_id)?.join(".minecraft");
 
-        if other_uses == 0 {
-            let version_dir = versions_root(&app)?.join(&version_id);
-            if version_dir.exists() {
-                fs::remove_dir_all(version_dir).map_err(|e| e.to_string())?;
-            }
+    for item in IMPORTED_GAME_DATA {
+        let from = source.join(item);
+        if !from.exists() {
+            continue;
+        }
+        let to = game_dir.join(item);
+        if symlink.unwrap_or(false) && symlink_path(&from, &to).is_ok() {
+            continue;
         }
+        // Copy when symlinking wasn't asked for or isn't permitted (e.g. Windows without developer mode)
+        let copied = if from.is_dir() {
+            copy_dir_recursive(&from, &to)
+        } else {
+            fs::copy(&from, &to).map(|_| ()).map_err(|e| e.to_string())
+        };
+        copied.map_err(|e| format!("Failed to import {}: {}", item, e))?;
     }
 
-    if dir.exists() {
-        fs::remove_dir_all(dir).map_err(|e| e.to_string())?;
+    // Launch recovery installs the loader from these fields on first start
+    if loader.is_some() {
+        let meta_path = instance_meta_path(&app, &instance_id)?;
+        let mut instance = read_instance(&meta_path)?;
+        instance.loader = loader;
+        instance.loader_version = loader_version;
+        fs::write(
+            &meta_path,
+            serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+        )
+        .map_err(|e| e.to_string())?;
     }
 
-    Ok(())
+    Ok(instance_id)
+}
+
+#[derive(Serialize)]
+pub struct ExternalDirInfo {
+    /// The game dir that was inspected, possibly a `.minecraft` below the given path
+    pub game_dir: String,
+    /// `vanilla` (has `launcher_profiles.json`) or `multimc` (MultiMC/Prism `instance.cfg`)
+    pub detected_launcher: Option<String>,
+    pub mc_versions_present: Vec<String>,
+    /// Folder names under `saves/` that hold a `level.dat`
+    pub worlds: Vec<String>,
+    pub mods_count: usize,
+    /// Mods, loader versions or loader components suggest this isn't plain vanilla
+    pub has_loader_signs: bool,
 }
 
+/// Look at a game dir from another launcher before importing it. Read-only.
 #[tauri::command]
-pub async fn check_version_usage(
-    app: AppHandle,
-    instance_id: String,
+pub async fn inspect_external_dir(path: String) -> Result<ExternalDirInfo, String> {
+    let game_dir = locate_external_game_dir(&path)?;
+    // MultiMC/Prism keep instance.cfg and mmc-pack.json next to the game dir
+    let instance_root = game_dir.parent().unwrap_or(&game_dir);
+    let is_loader_name = |name: &str| {
+        let name = name.to_lowercase();
+        ["fabric", "quilt", "forge", "neoforge"]
+            .iter()
+            .any(|l| name.contains(l))
+    };
+
+    let detected_launcher = if game_dir.join("launcher_profiles.json").is_file() {
+        Some("vanilla".to_string())
+    } else if instance_root.join("instance.cfg").is_file() {
+        Some("multimc".to_string())
+    } else {
+        None
+    };
+
+    let subdirs = |dir: PathBuf| -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter(|e| e.path().is_dir())
+                    .map(|e| e.file_name().to_string_lossy().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        names.sort();
+        names
+    };
+
+    let versions_dir = game_dir.join("versions");
+    let mut mc_versions_present: Vec<String> = subdirs(versions_dir.clone())
+        .into_iter()
+        .filter(|v| versions_dir.join(v).join(format!("{}.json", v)).is_file())
+        .collect();
+    let mut has_loader_signs =
+        mc_versions_present.iter().any(|v| is_loader_name(v)) || game_dir.join(".fabric").is_dir();
+
+    // MultiMC/Prism record the game and loader as components instead of version folders
+    if let Some(components) = fs::read_to_string(instance_root.join("mmc-pack.json"))
+        .ok()
+        .and_then(|t| serde_json::from_str::<serde_json::Value>(&t).ok())
+        .and_then(|v| v.get("components")?.as_array().cloned())
+    {
+        for c in &components {
+            let uid = c.get("uid").and_then(|v| v.as_str()).unwrap_or_default();
+            let version = c.get("version").and_then(|v| v.as_str());
+            if uid == "net.minecraft" {
+                if let Some(v) = version.filter(|v| !mc_versions_present.iter().any(|p| p == v)) {
+                    mc_versions_present.push(v.to_string());
+                }
+            } else if is_loader_name(uid) {
+                has_loader_signs = true;
+            }
+        }
+    }
+
+    let worlds: Vec<String> = subdirs(game_dir.join("saves"))
+        .into_iter()
+        .filter(|w| game_dir.join("saves").join(w).join("level.dat").is_file())
+        .collect();
+
+    let mods_count = fs::read_dir(game_dir.join("mods"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().extension() == Some(std::ffi::OsStr::new("jar")))
+                .count()
+        })
+        .unwrap_or(0);
+    has_loader_signs |= mods_count > 0;
+
+    Ok(ExternalDirInfo {
+        game_dir: game_dir.to_string_lossy().to_string(),
+        detected_launcher,
+        mc_versions_present,
+        worlds,
+        mods_count,
+        has_loader_signs,
+    })
+}
+
+#[tauri::command]
+pub async fn delete_instance(
+    app: AppHandle,
+    instance_id: String,
+    delete_version: bool,
+) -> Result<(), String> {
+    let dir = instance_dir(&app, &instance_id)?;
+
+    // If delete_version is true, we need to check if other instances use it
+    if delete_version {
+        let instances = list_instances(app.clone()).await?;
+        // Determine version id to delete. If the instance had a loader installed, delete the derived loader-backed version
+        let version_id = read_instance(&dir.join("instance.json"))?.launch_version_id();
+
+        let other_uses = instances
+            .iter()
+            .filter(|i| i.id != instance_id && i.launch_version_id() == version_id)
+            .count();
+
+        if other_uses == 0 {
+            let version_dir = versions_root(&app)?.join(&version_id);
+            if version_dir.exists() {
+                fs::remove_dir_all(version_dir).map_err(|e| e.to_string())?;
+            }
+        }
+    }
+
+    if dir.exists() {
+        fs::remove_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn check_version_usage(
+    app: AppHandle,
+    instance_id: String,
     version_id: String,
 ) -> Result<bool, String> {
     let instances = list_instances(app).await?;
@@ -277,54 +1008,47 @@ pub async fn download_version(
     app: AppHandle,
     instance_id: String,
     version_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let version_json_path = versions_root(&app)?
         .join(&version_id)
         .join(format!("{version_id}.json"));
 
     // 1️⃣ Download version metadata
-    let manifest = get_manifest().await?;
-    let version_info = manifest
-        .versions
-        .iter()
-        .find(|v| v.id == version_id)
-        .ok_or("Version not found in manifest")?;
+    let version_info = crate::minecraft::find_version(&version_id).await?;
 
     let version_json_text = crate::download::download_text(&version_info.url).await?;
-    fs::create_dir_all(version_json_path.parent().unwrap()).map_err(|e| e.to_string())?;
-    fs::write(&version_json_path, &version_json_text).map_err(|e| e.to_string())?;
+    fs::create_dir_all(version_json_path.parent().unwrap())?;
+    fs::write(&version_json_path, &version_json_text)?;
 
-    let version: VersionJson =
-        serde_json::from_str(&version_json_text).map_err(|e| e.to_string())?;
+    let version: VersionJson = serde_json::from_str(&version_json_text)?;
 
     // Perform Java installation first
-    let java_result = ensure_java(&app, &version_id).await;
+    let java_result = ensure_java(
+        &app,
+        &version_id,
+        version.java_version.as_ref().map(|j| j.major_version),
+    )
+    .await;
 
     // Perform installation
-    let result: Result<(), String> = async {
+    let result: Result<(), AppError> = async {
         if let Err(e) = &java_result {
-            return Err(format!("Java installation failed: {}", e));
+            return Err(AppError::Other(format!("Java installation failed: {}", e)));
         }
-        install_client_jar(&app, &version_id, &version).await?;
-        install_libraries(&app, &version).await?;
-        install_assets(&app, &version).await?;
-        Ok(())
+        install_game_files(&app, &version_id, &version).await
     }
     .await;
 
     // Update instance state
-    let mut instance = {
-        let meta_text = fs::read_to_string(instance_meta_path(&app, &instance_id)?)
-            .map_err(|e| e.to_string())?;
-        serde_json::from_str::<Instance>(&meta_text).map_err(|e| e.to_string())?
-    };
+    let mut instance = read_instance(&instance_meta_path(&app, &instance_id)?)?;
 
     if let Err(e) = result {
         instance.state = InstanceState::Error;
         let _ = fs::write(
             instance_meta_path(&app, &instance_id)?,
-            serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+            serde_json::to_string_pretty(&instance)?,
         );
+        crate::install::emit_install_finished(&app, &instance, Some(&e.to_string()));
         return Err(e);
     }
 
@@ -336,67 +1060,175 @@ pub async fn download_version(
 
     fs::write(
         instance_meta_path(&app, &instance_id)?,
-        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
-    )
-    .map_err(|e| e.to_string())?;
+        serde_json::to_string_pretty(&instance)?,
+    )?;
+    crate::install::emit_install_finished(&app, &instance, None);
+
+    log::info!("Installation completed successfully for {}", version_id);
+    Ok(())
+}
+
+/// Fill in a missing `assetIndex` by walking the `inheritsFrom` chain.
+pub(crate) async fn resolve_inherited_asset_index(
+    app: &AppHandle,
+    version: &mut VersionJson,
+) -> Result<(), AppError> {
+    let mut parent = version.inheritsFrom.clone();
+    // Chains are one or two levels in practice; the cap guards against cycles
+    for _ in 0..5 {
+        if version.assetIndex.is_some() {
+            return Ok(());
+        }
+        let Some(parent_id) = parent else {
+            break;
+        };
+        let parent_json = ensure_vanilla_version(app, &parent_id).await?;
+        version.assetIndex = parent_json.assetIndex;
+        parent = parent_json.inheritsFrom;
+    }
+    if version.assetIndex.is_none() {
+        return Err(AppError::NotFound(format!(
+            "No assetIndex found for {} or its parents",
+            version.id.as_deref().unwrap_or("<unknown>")
+        )));
+    }
+    Ok(())
+}
 
-    println!("✓ Installation completed successfully for {}", version_id);
+/// Give an inheriting profile its parent's legacy `minecraftArguments` when it has
+/// neither that nor a modern `arguments` object of its own.
+pub(crate) async fn resolve_inherited_legacy_arguments(
+    app: &AppHandle,
+    version: &mut VersionJson,
+) -> Result<(), AppError> {
+    let mut parent = version.inheritsFrom.clone();
+    for _ in 0..5 {
+        if version.arguments.is_some() || version.minecraftArguments.is_some() {
+            break;
+        }
+        let Some(parent_id) = parent else {
+            break;
+        };
+        let parent_json = ensure_vanilla_version(app, &parent_id).await?;
+        version.minecraftArguments = parent_json.minecraftArguments;
+        parent = parent_json.inheritsFrom;
+    }
     Ok(())
 }
 
-// Ensure vanilla Minecraft version files (version JSON, client, libraries, assets) are present
+/// Game arguments from `arguments.game` that are gated on launcher features and
+/// enabled by `features`. Unconditional arguments are set by the launcher itself.
+fn feature_game_args(version: &VersionJson, features: &crate::rules::Features) -> Vec<String> {
+    let Some(game) = version
+        .arguments
+        .as_ref()
+        .and_then(|a| a.get("game"))
+        .and_then(|g| g.as_array())
+    else {
+        return Vec::new();
+    };
+    let mut args = Vec::new();
+    for entry in game {
+        let Some(rules) = entry
+            .get("rules")
+            .and_then(|r| serde_json::from_value::<Vec<crate::version::Rule>>(r.clone()).ok())
+        else {
+            continue;
+        };
+        if rules.iter().all(|r| r.features.is_empty())
+            || !crate::rules::rules_allow_with(&rules, features)
+        {
+            continue;
+        }
+        match entry.get("value") {
+            Some(serde_json::Value::String(v)) => args.push(v.clone()),
+            Some(serde_json::Value::Array(vs)) => {
+                args.extend(vs.iter().filter_map(|v| v.as_str()).map(str::to_string))
+            }
+            _ => {}
+        }
+    }
+    args
+}
+
+/// Expand a pre-1.13 `minecraftArguments` template. Unknown `${...}` tokens are passed
+/// through unchanged, as the vanilla launcher does.
+fn legacy_game_args(template: &str, values: &HashMap<&str, String>) -> Vec<String> {
+    template
+        .split_whitespace()
+        .map(|token| {
+            token
+                .strip_prefix("${")
+                .and_then(|t| t.strip_suffix('}'))
+                .and_then(|key| values.get(key))
+                .cloned()
+                .unwrap_or_else(|| token.to_string())
+        })
+        .collect()
+}
+
+/// Ensure vanilla Minecraft version files (version JSON, client, libraries, assets) are present.
 async fn ensure_vanilla_version(
     app: &AppHandle,
     mc_version: &str,
-) -> Result<crate::version::VersionJson, String> {
+) -> Result<crate::version::VersionJson, AppError> {
     // Check if we already have the version json on disk
     let version_json_path = versions_root(app)?
         .join(mc_version)
         .join(format!("{}.json", mc_version));
     if version_json_path.exists() {
-        let text = std::fs::read_to_string(&version_json_path).map_err(|e| e.to_string())?;
-        let version: crate::version::VersionJson =
-            serde_json::from_str(&text).map_err(|e| e.to_string())?;
+        let text = std::fs::read_to_string(&version_json_path)?;
+        let version: crate::version::VersionJson = serde_json::from_str(&text)?;
         return Ok(version);
     }
 
     // Fetch manifest and download version json
-    let manifest = get_manifest().await?;
-    let version_info = manifest
-        .versions
-        .iter()
-        .find(|v| v.id == mc_version)
-        .ok_or(format!(
-            "Minecraft version {} not found in manifest",
-            mc_version
-        ))?;
+    let version_info = crate::minecraft::find_version(mc_version).await?;
 
     let version_json_text = crate::download::download_text(&version_info.url).await?;
 
-    std::fs::create_dir_all(version_json_path.parent().unwrap()).map_err(|e| e.to_string())?;
-    std::fs::write(&version_json_path, &version_json_text).map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(version_json_path.parent().unwrap())?;
+    std::fs::write(&version_json_path, &version_json_text)?;
 
-    let version: crate::version::VersionJson =
-        serde_json::from_str(&version_json_text).map_err(|e| e.to_string())?;
+    let version: crate::version::VersionJson = serde_json::from_str(&version_json_text)?;
 
     // Install client jar, libraries and assets
-    install_client_jar(app, mc_version, &version).await?;
-    install_libraries(app, &version).await?;
-    install_assets(app, &version).await?;
+    install_game_files(app, mc_version, &version).await?;
 
     Ok(version)
 }
 
+/// Drop a Modrinth-style `+<mc version>` suffix ("0.141.2+1.21.11" -> "0.141.2"), which
+/// loader meta doesn't know. Build metadata such as "+build.1" is part of the version
+/// and stays.
+pub(crate) fn normalize_loader_version(version: &str) -> &str {
+    match version.split_once('+') {
+        Some((base, suffix))
+            if suffix.contains('.') && suffix.chars().all(|c| c.is_ascii_digit() || c == '.') =>
+        {
+            base
+        }
+        _ => version,
+    }
+}
+
 #[tauri::command]
 pub async fn install_loader(
     app: AppHandle,
     loader_type: String,
     mc_version: String,
     loader_version: String,
-) -> Result<(String, String), String> {
+) -> Result<(String, String), AppError> {
     // Support only fabric and quilt for now
+    if loader_type != "fabric" && loader_type != "quilt" {
+        return Err(AppError::LoaderUnsupported(format!(
+            "Unsupported loader type: {}",
+            loader_type
+        )));
+    }
+
     // Track the effective loader version we end up using (may change due to fallback)
-    let mut effective_loader_version = loader_version.clone();
+    let mut effective_loader_version = normalize_loader_version(&loader_version).to_string();
 
     // Helper to build profile URL for a given version
     let build_profile_url = |lt: &str, mc: &str, v: &str| -> String {
@@ -422,8 +1254,9 @@ pub async fn install_loader(
         Ok(t) => t,
         Err(e) => {
             // Only try the fallback for known "no loader version found" responses
-            if e.contains("no loader version found")
-                || (e.starts_with("HTTP 400") && e.contains("no loader"))
+            let msg = e.message();
+            if msg.contains("no loader version found")
+                || (msg.starts_with("HTTP 400") && msg.contains("no loader"))
             {
                 // Build the versions listing URL and try to resolve a real loader version
                 let list_url = match loader_type.as_str() {
@@ -437,7 +1270,7 @@ pub async fn install_loader(
                     _ => return Err(e),
                 };
 
-                std::println!(
+                log::info!(
                     "Loader version {} not found for {}, trying to resolve from list",
                     loader_version,
                     mc_version
@@ -525,29 +1358,26 @@ pub async fn install_loader(
     // Parse and validate inheritsFrom (include a truncated response snippet on parse errors)
     let profile_json: serde_json::Value = serde_json::from_str(&profile_text).map_err(|e| {
         let snippet: String = profile_text.chars().take(200).collect();
-        format!("{} - response (truncated): {}", e.to_string(), snippet)
+        AppError::Parse(format!("{} - response (truncated): {}", e, snippet))
     })?;
     let inherits = profile_json
         .get("inheritsFrom")
         .and_then(|v| v.as_str())
-        .ok_or("profile missing inheritsFrom")?;
+        .ok_or_else(|| AppError::Parse("profile missing inheritsFrom".to_string()))?;
 
     if inherits != mc_version {
-        return Err(format!(
+        return Err(AppError::Verification(format!(
             "profile inheritsFrom mismatch: expected {}, found {}",
             mc_version, inherits
-        ));
+        )));
     }
 
     // Map into our VersionJson struct (this will ignore extra profile fields)
-    // Derived version id format: <loader>-loader-<loaderVersion>-<mcVersion>
-    let derived_id = format!(
-        "{}-loader-{}-{}",
-        loader_type, effective_loader_version, mc_version
-    );
+    let derived_id =
+        crate::loader::derived_version_id(&loader_type, &effective_loader_version, &mc_version);
 
     let derived_dir = versions_root(&app)?.join(&derived_id);
-    std::fs::create_dir_all(&derived_dir).map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&derived_dir)?;
     let derived_json_path = derived_dir.join(format!("{}.json", derived_id));
     if derived_json_path.exists() {
         return Ok((derived_id, effective_loader_version));
@@ -609,6 +1439,35 @@ pub async fn install_loader(
                             }
                         }
 
+                        let rules: Vec<crate::version::Rule> = lib
+                            .get("rules")
+                            .and_then(|v| serde_json::from_value(v.clone()).ok())
+                            .unwrap_or_default();
+
+                        // Coordinate-only library (no artifact, no url): find a configured repo that has it
+                        if artifact_opt.is_none() && crate::rules::rules_allow(&rules) {
+                            if let Some(path) = maven_coords_to_path(name) {
+                                let repos = crate::settings::get_settings(app.clone())
+                                    .unwrap_or_default()
+                                    .maven_repositories;
+                                artifact_opt =
+                                    crate::download::resolve_maven_artifact(&path, &repos)
+                                        .await
+                                        .map(|url| crate::version::Artifact {
+                                            path,
+                                            url,
+                                            sha1: String::new(),
+                                            size: 0,
+                                        });
+                                if artifact_opt.is_none() {
+                                    log::warn!(
+                                        "[install_loader] {} not found in any configured Maven repo",
+                                        name
+                                    );
+                                }
+                            }
+                        }
+
                         let lib_struct = crate::version::Library {
                             name: name.to_string(),
                             downloads: crate::version::LibraryDownloads {
@@ -616,7 +1475,7 @@ pub async fn install_loader(
                                 classifiers: std::collections::HashMap::new(),
                             },
                             natives: std::collections::HashMap::new(),
-                            rules: Vec::new(),
+                            rules,
                         };
 
                         libraries.push(lib_struct);
@@ -656,17 +1515,37 @@ pub async fn install_loader(
             crate::version::VersionJson {
                 id: None,
                 inheritsFrom: Some(mc_version.to_string()),
-                releaseTime: None,
-                time: None,
-                r#type: None,
+                releaseTime: base.releaseTime.clone(),
+                time: base.time.clone(),
+                r#type: base.r#type.clone(),
                 arguments: None,
+                minecraftArguments: profile_json
+                    .get("minecraftArguments")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+                    .or(base.minecraftArguments.clone()),
                 libraries,
                 downloads,
                 mainClass: main_class,
                 assetIndex: base.assetIndex.clone(),
+                java_version: base.java_version.clone(),
             }
         }
     };
+    let mut version_json = version_json;
+    resolve_inherited_asset_index(&app, &mut version_json).await?;
+
+    // Loader profiles often leave these out; take them from the vanilla parent so the
+    // derived JSON neither carries nulls nor calls a snapshot a release
+    if version_json.releaseTime.is_none()
+        || version_json.time.is_none()
+        || version_json.r#type.is_none()
+    {
+        let parent = ensure_vanilla_version(&app, &mc_version).await?;
+        version_json.releaseTime = version_json.releaseTime.or(parent.releaseTime);
+        version_json.time = version_json.time.or(parent.time);
+        version_json.r#type = version_json.r#type.or(parent.r#type);
+    }
 
     // CRITICAL: Ensure inheritsFrom is set correctly for derived versions
     // The derived version should inherit from the base MC version
@@ -674,7 +1553,7 @@ pub async fn install_loader(
 
     // Add inheritsFrom field if it's missing (this is crucial for Fabric)
     // We need to create a custom struct that includes inheritsFrom
-    let version_with_inherits = serde_json::json!({
+    let mut version_with_inherits = serde_json::json!({
         "id": derived_id,
         "inheritsFrom": final_version_json.inheritsFrom.as_ref().unwrap_or(&mc_version),
         "releaseTime": final_version_json.releaseTime,
@@ -682,15 +1561,20 @@ pub async fn install_loader(
         "type": final_version_json.r#type.as_ref().unwrap_or(&"release".to_string()),
         "mainClass": final_version_json.mainClass,
         "arguments": final_version_json.arguments,
+        "minecraftArguments": final_version_json.minecraftArguments,
         "libraries": final_version_json.libraries,
         "downloads": final_version_json.downloads,
-        "assetIndex": final_version_json.assetIndex
+        "assetIndex": final_version_json.assetIndex,
+        "javaVersion": final_version_json.java_version
     });
+    // Absent beats `null` for tools reading this file; every field here is optional on read
+    if let Some(fields) = version_with_inherits.as_object_mut() {
+        fields.retain(|_, v| !v.is_null());
+    }
 
     // Persist the derived version JSON (pretty) so the launcher treats it as a distinct version
-    let derived_text =
-        serde_json::to_string_pretty(&version_with_inherits).map_err(|e| e.to_string())?;
-    std::fs::write(&derived_json_path, &derived_text).map_err(|e| e.to_string())?;
+    let derived_text = serde_json::to_string_pretty(&version_with_inherits)?;
+    std::fs::write(&derived_json_path, &derived_text)?;
 
     // Verify that the version JSON contains Fabric loader libraries
     if loader_type == "fabric" {
@@ -700,8 +1584,8 @@ pub async fn install_loader(
         });
 
         if !has_fabric_loader {
-            println!("Warning: Fabric profile JSON does not contain fabric-loader libraries");
-            println!(
+            log::warn!("Fabric profile JSON does not contain fabric-loader libraries");
+            log::warn!(
                 "Libraries found: {:?}",
                 final_version_json
                     .libraries
@@ -710,23 +1594,25 @@ pub async fn install_loader(
                     .collect::<Vec<_>>()
             );
         } else {
-            println!("Fabric loader libraries found in profile JSON");
+            log::debug!("Fabric loader libraries found in profile JSON");
         }
     }
 
     // Install client/jar, libraries and assets for the derived version
-    println!("Installing client JAR for derived version: {}", derived_id);
-    install_client_jar(&app, &derived_id, &final_version_json).await?;
+    log::info!("Installing client JAR for derived version: {}", derived_id);
+    let vanilla = ensure_vanilla_version(&app, &mc_version).await?;
+    crate::install::install_derived_client_jar(&app, &derived_id, &mc_version, &vanilla).await?;
 
-    println!("Installing libraries for derived version: {}", derived_id);
+    log::info!("Installing libraries for derived version: {}", derived_id);
     install_libraries(&app, &final_version_json).await?;
 
-    println!("Installing assets for derived version: {}", derived_id);
+    log::info!("Installing assets for derived version: {}", derived_id);
     install_assets(&app, &final_version_json).await?;
 
-    println!(
+    log::info!(
         "Loader installation completed successfully: {} {}",
-        loader_type, effective_loader_version
+        loader_type,
+        effective_loader_version
     );
     Ok((derived_id, effective_loader_version))
 }
@@ -739,160 +1625,768 @@ pub struct InstanceLog {
     pub message: String,
 }
 
+/// Whether `instance_id` has a live game process; reaps the handle if it has exited.
+fn child_running(process_state: &State<'_, ChildProcessState>, instance_id: &str) -> bool {
+    let mut lock = process_state.0.lock().unwrap();
+    match lock.get_mut(instance_id).map(|child| child.try_wait()) {
+        Some(Ok(None)) => true,
+        Some(_) => {
+            lock.remove(instance_id);
+            false
+        }
+        None => false,
+    }
+}
+
+/// Version ID an instance launches with: the derived loader version when a loader is set.
+/// The `<version_id>.<ext>` file an instance launches with. Instances with private
+/// versions look in their own `versions/` first, file by file, so dropping in just a
+/// patched jar is enough; anything not found there comes from the shared folder.
+fn instance_version_file(
+    app: &AppHandle,
+    instance_root: &Path,
+    instance: &Instance,
+    version_id: &str,
+    ext: &str,
+) -> Result<PathBuf, String> {
+    let file_name = format!("{}.{}", version_id, ext);
+    if instance.private_versions {
+        let private = instance_root
+            .join("versions")
+            .join(version_id)
+            .join(&file_name);
+        if private.exists() {
+            return Ok(private);
+        }
+    }
+    Ok(versions_root(app)?.join(version_id).join(file_name))
+}
+
+/// Turn private versions on or off for an instance. Turning them on copies the
+/// current version JSON and client jar into the instance as a starting point for
+/// patching; turning them off leaves those copies on disk but stops using them.
 #[tauri::command]
-pub async fn launch_instance(
+pub async fn set_private_versions(
     app: AppHandle,
     instance_id: String,
-    process_state: State<'_, ChildProcessState>,
-) -> Result<(), String> {
-    let instance_root = instance_dir(&app, &instance_id)?;
-    let game_dir = instance_root.join(".minecraft");
-
-    fs::create_dir_all(&game_dir).map_err(|e| e.to_string())?;
-
-    let meta_text =
-        fs::read_to_string(instance_root.join("instance.json")).map_err(|e| e.to_string())?;
-
-    let instance: Instance = serde_json::from_str(&meta_text).map_err(|e| e.to_string())?;
-
-    // Determine the version JSON to use: if loader info is present, prefer derived loader-backed version; otherwise use instance.version
-    let version_id = if let (Some(loader), Some(loader_v), Some(mc_v)) = (
-        instance.loader.clone(),
-        instance.loader_version.clone(),
-        instance.mc_version.clone(),
-    ) {
-        format!("{}-loader-{}-{}", loader, loader_v, mc_v)
-    } else if let (Some(loader), Some(loader_v)) =
-        (instance.loader.clone(), instance.loader_version.clone())
-    {
-        // Fallback: use instance.version as mc version
-        format!("{}-loader-{}-{}", loader, loader_v, instance.version)
-    } else {
-        instance.version.clone()
-    };
-
-    let version_json_path = versions_root(&app)?
-        .join(&version_id)
-        .join(format!("{version_id}.json"));
-
-    // If the derived version JSON is missing, attempt recovery:
-    // - If we have loader info on the instance, try to install the loader (creates derived version)
-    // - Otherwise try to ensure vanilla version files
-    if !version_json_path.exists() {
-        if let (Some(loader), Some(loader_v)) =
-            (instance.loader.clone(), instance.loader_version.clone())
-        {
-            let mc_v = instance
-                .mc_version
-                .clone()
-                .unwrap_or(instance.version.clone());
-            let _ = app.emit(
-                "loader-install-log",
-                format!(
-                    "Derived version {} missing, attempting to install loader {} {}",
-                    version_id, loader, loader_v
-                ),
-            );
-            match install_loader(app.clone(), loader.clone(), mc_v.clone(), loader_v.clone()).await
-            {
-                Ok((_derived, used_version)) => {
-                    // success — derived version should now exist (used_version is the actual loader version chosen)
-                    let _ = app.emit(
-                        "loader-install-log",
-                        format!(
-                            "install_loader resolved loader version: {} -> {}",
-                            loader_v, used_version
-                        ),
-                    );
-                }
-                Err(e) => {
-                    return Err(format!(
-                        "Derived version {} missing and install_loader failed: {}",
-                        version_id, e
-                    ));
-                }
-            }
-        } else {
-            // No loader info -> try to ensure vanilla version json exists/install it
-            if let Err(e) = ensure_vanilla_version(&app, &instance.version).await {
-                return Err(format!(
-                    "Version JSON missing at {} and failed to install vanilla {}: {}",
-                    version_json_path.to_string_lossy(),
-                    instance.version,
-                    e
-                ));
+    enabled: bool,
+) -> Result<Instance, String> {
+    let root = instance_dir(&app, &instance_id)?;
+    let meta_path = root.join("instance.json");
+    let mut instance = read_instance(&meta_path)?;
+
+    if enabled {
+        let version_id = instance.launch_version_id();
+        let shared = versions_root(&app)?.join(&version_id);
+        let private = root.join("versions").join(&version_id);
+        fs::create_dir_all(&private).map_err(|e| e.to_string())?;
+        for ext in ["json", "jar"] {
+            let file_name = format!("{}.{}", version_id, ext);
+            let (from, to) = (shared.join(&file_name), private.join(&file_name));
+            // Never clobber a copy that may already carry patches
+            if from.exists() && !to.exists() {
+                fs::copy(&from, &to).map_err(|e| e.to_string())?;
             }
         }
     }
 
-    // Re-check that the JSON exists after recovery attempt
-    if !version_json_path.exists() {
-        return Err(format!(
-            "Version JSON not found at path: {}",
-            version_json_path.to_string_lossy()
-        ));
-    }
+    instance.private_versions = enabled;
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+    let _ = app.emit("instance-state-changed", &instance);
+    Ok(instance)
+}
 
-    let text = fs::read_to_string(&version_json_path).map_err(|e| {
+/// Read a version JSON for launch, filling in what it inherits from its parent.
+async fn read_launch_version(app: &AppHandle, path: &Path) -> Result<VersionJson, String> {
+    let text = fs::read_to_string(path).map_err(|e| {
         format!(
             "Failed to read version JSON at {}: {}",
-            version_json_path.to_string_lossy(),
-            e.to_string()
+            path.to_string_lossy(),
+            e
         )
     })?;
 
-    let version: VersionJson = serde_json::from_str(&text).map_err(|e| {
+    let mut version: VersionJson = serde_json::from_str(&text).map_err(|e| {
         format!(
             "Failed to parse version JSON at {}: {}",
-            version_json_path.to_string_lossy(),
-            e.to_string()
+            path.to_string_lossy(),
+            e
         )
     })?;
+    resolve_inherited_asset_index(app, &mut version).await?;
+    resolve_inherited_legacy_arguments(app, &mut version).await?;
+    Ok(version)
+}
 
-    // Confirm client JAR exists too and if missing, attempt to recover similarly
-    let client_jar = versions_root(&app)?
-        .join(&version_id)
-        .join(format!("{}.jar", version_id));
-    if !client_jar.exists() {
-        if let (Some(loader), Some(loader_v)) =
-            (instance.loader.clone(), instance.loader_version.clone())
-        {
-            let mc_v = instance
-                .mc_version
-                .clone()
-                .unwrap_or(instance.version.clone());
-            let _ = app.emit(
-                "loader-install-log",
-                format!(
-                    "Client JAR missing for {} - attempting loader install {} {}",
-                    version_id, loader, loader_v
-                ),
-            );
-            match install_loader(app.clone(), loader.clone(), mc_v.clone(), loader_v.clone()).await
-            {
-                Ok((_derived, used_version)) => {
-                    let _ = app.emit(
-                        "loader-install-log",
-                        format!(
-                            "install_loader resolved loader version: {} -> {}",
-                            loader_v, used_version
-                        ),
-                    );
-                }
-                Err(e) => {
-                    return Err(format!(
-                        "Client JAR missing at {} and failed to install loader {} {}: {}",
-                        client_jar.to_string_lossy(),
-                        loader,
-                        loader_v,
-                        e
-                    ));
-                }
-            }
-        } else {
-            // Try to install vanilla client jar
-            if let Err(e) = install_client_jar(&app, &instance.version, &version).await {
-                return Err(format!(
+/// Store the loader version `install_loader` actually resolved, so `Instance::launch_version_id`
+/// names the derived version that exists on disk rather than the one requested.
+fn record_resolved_loader_version(
+    app: &AppHandle,
+    instance_id: &str,
+    loader_version: &str,
+) -> Result<(), String> {
+    let meta_path = instance_meta_path(app, instance_id)?;
+    let mut instance = read_instance(&meta_path)?;
+    let current = instance.loader_version.as_deref();
+    if current == Some(loader_version) {
+        return Ok(());
+    }
+    // A stripped "+<mc version>" suffix names the same loader, so a pin doesn't object
+    if current.map(normalize_loader_version) != Some(loader_version) {
+        // Only reached when the pinned loader version could not be installed
+        instance.ensure_unpinned(&format!("switching its loader to {}", loader_version))?;
+    }
+    instance.loader_version = Some(loader_version.to_string());
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())
+}
+
+#[derive(Serialize)]
+pub struct InstallStatus {
+    pub json: bool,
+    pub client_jar: bool,
+    pub libraries_complete: bool,
+    pub assets_complete: bool,
+}
+
+/// Read-only check of what's on disk for a version; sizes are compared where known.
+fn version_install_status(app: &AppHandle, version_id: &str) -> Result<InstallStatus, String> {
+    let mc_root = minecraft_root(app)?;
+    let version_dir = mc_root.join("versions").join(version_id);
+    let json_path = version_dir.join(format!("{}.json", version_id));
+
+    let mut status = InstallStatus {
+        json: json_path.exists(),
+        client_jar: version_dir.join(format!("{}.jar", version_id)).exists(),
+        libraries_complete: false,
+        assets_complete: false,
+    };
+    let Some(version) = fs::read_to_string(&json_path)
+        .ok()
+        .and_then(|t| serde_json::from_str::<VersionJson>(&t).ok())
+    else {
+        return Ok(status);
+    };
+
+    let size_ok = |path: &std::path::Path, size: u64| {
+        fs::metadata(path)
+            .map(|m| size == 0 || m.len() == size)
+            .unwrap_or(false)
+    };
+
+    let libraries = mc_root.join("libraries");
+    status.libraries_complete = version
+        .libraries
+        .iter()
+        .filter(|lib| crate::rules::rules_allow(&lib.rules))
+        .flat_map(|lib| {
+            lib.downloads
+                .artifact
+                .iter()
+                .chain(lib.downloads.classifiers.values())
+        })
+        .all(|a| size_ok(&libraries.join(&a.path), a.size));
+
+    // Inheriting profiles may leave assetIndex to the parent; only look on disk here
+    let asset_index = version.assetIndex.clone().or_else(|| {
+        let parent = version.inheritsFrom.as_ref()?;
+        let text = fs::read_to_string(
+            mc_root
+                .join("versions")
+                .join(parent)
+                .join(format!("{}.json", parent)),
+        )
+        .ok()?;
+        serde_json::from_str::<VersionJson>(&text).ok()?.assetIndex
+    });
+    if let Some(asset_index) = asset_index {
+        let assets = assets_root(app)?;
+        status.assets_complete = fs::read_to_string(
+            assets
+                .join("indexes")
+                .join(format!("{}.json", asset_index.id)),
+        )
+        .ok()
+        .and_then(|t| serde_json::from_str::<crate::assets::AssetIndexJson>(&t).ok())
+        .is_some_and(|index| {
+            index.objects.values().all(|obj| {
+                size_ok(
+                    &assets.join("objects").join(&obj.hash[..2]).join(&obj.hash),
+                    obj.size,
+                )
+            })
+        });
+    }
+
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn is_version_installed(
+    app: AppHandle,
+    version_id: String,
+) -> Result<InstallStatus, String> {
+    version_install_status(&app, &version_id)
+}
+
+/// Like `is_version_installed`, resolving the instance's derived loader version.
+#[tauri::command]
+pub async fn is_instance_installed(
+    app: AppHandle,
+    instance_id: String,
+) -> Result<InstallStatus, String> {
+    let instance = read_instance(&instance_meta_path(&app, &instance_id)?)?;
+    version_install_status(&app, &instance.launch_version_id())
+}
+
+/// Outcome of `dry_run_launch`: everything `launch_instance` checks, without the game.
+#[derive(Serialize)]
+pub struct DryRunReport {
+    pub version_id: String,
+    pub java_ok: bool,
+    pub java_path: String,
+    pub java_version: Option<u8>,
+    pub required_java: u8,
+    pub classpath_entries: Vec<String>,
+    pub missing_files: Vec<String>,
+    /// Every natives jar for this OS is present; LWJGL unpacks them itself at startup
+    pub natives_extracted: bool,
+    /// Why the version couldn't be resolved at all, if it couldn't
+    pub error: Option<String>,
+    pub would_launch: bool,
+}
+
+/// Run the launch preparation for an instance (version JSON, inherited fields, client
+/// jar, classpath, natives, Java) and report what would stop it, without spawning the
+/// game or repairing anything of the instance's own. Resolving an inherited profile can
+/// still fetch its vanilla parent, as a real launch would.
+#[tauri::command]
+pub async fn dry_run_launch(app: AppHandle, instance_id: String) -> Result<DryRunReport, String> {
+    let instance_root = instance_dir(&app, &instance_id)?;
+    let instance = read_instance(&instance_root.join("instance.json"))?;
+    let version_id = instance.launch_version_id();
+
+    let java = check_java_compatibility(app.clone(), instance_id.clone()).await?;
+    let mut report = DryRunReport {
+        version_id: version_id.clone(),
+        java_ok: java.compatible && java.actual_version.is_some(),
+        java_path: java.path,
+        java_version: java.actual_version,
+        required_java: java.required_version,
+        classpath_entries: Vec::new(),
+        missing_files: Vec::new(),
+        natives_extracted: false,
+        error: None,
+        would_launch: false,
+    };
+
+    let version_json_path =
+        instance_version_file(&app, &instance_root, &instance, &version_id, "json")?;
+    if !version_json_path.is_file() {
+        report
+            .missing_files
+            .push(version_json_path.to_string_lossy().to_string());
+        report.error = Some(format!(
+            "Version JSON not found at {}",
+            version_json_path.display()
+        ));
+        return Ok(report);
+    }
+    let version = match read_launch_version(&app, &version_json_path).await {
+        Ok(version) => version,
+        Err(e) => {
+            report.error = Some(e);
+            return Ok(report);
+        }
+    };
+
+    let libraries = crate::launch::library_paths(&app, &version)?;
+    let client_jar = instance_version_file(&app, &instance_root, &instance, &version_id, "jar")?;
+    report.natives_extracted = libraries
+        .iter()
+        .filter(|(_, native)| *native)
+        .all(|(path, _)| path.is_file());
+    for path in libraries
+        .iter()
+        .map(|(path, _)| path)
+        .chain(std::iter::once(&client_jar))
+    {
+        if !path.is_file() {
+            report
+                .missing_files
+                .push(path.to_string_lossy().to_string());
+        }
+        report
+            .classpath_entries
+            .push(path.to_string_lossy().to_string());
+    }
+    if let Some(index) = &version.assetIndex {
+        let index_path = assets_root(&app)?
+            .join("indexes")
+            .join(format!("{}.json", index.id));
+        if !index_path.exists() {
+            report
+                .missing_files
+                .push(index_path.to_string_lossy().to_string());
+        }
+    }
+
+    report.would_launch = report.java_ok && report.missing_files.is_empty();
+    log::info!(
+        "Dry run for {} ({}): would_launch={}, {} missing files",
+        instance_id,
+        version_id,
+        report.would_launch,
+        report.missing_files.len()
+    );
+    Ok(report)
+}
+
+#[tauri::command]
+pub async fn launch_instance(
+    app: AppHandle,
+    instance_id: String,
+    account_uuid: Option<String>,
+    profile_name: Option<String>,
+    process_state: State<'_, ChildProcessState>,
+) -> Result<(), String> {
+    launch_game(
+        app,
+        instance_id,
+        account_uuid,
+        profile_name,
+        process_state,
+        false,
+    )
+    .await
+}
+
+// --- Safe Mode ---
+
+/// Where the real mods folder waits while the game runs in safe mode.
+const SAFE_MODE_MODS_DIR: &str = "mods.safe-mode";
+
+#[derive(Serialize, Clone)]
+pub struct SafeModeEvent {
+    pub instance_id: String,
+}
+
+/// Launch with an empty mods folder to tell whether a crash comes from the mods.
+/// The real folder is moved aside and put back when the game exits (or, if the
+/// launcher was closed meanwhile, before the next launch).
+#[tauri::command]
+pub async fn launch_safe_mode(
+    app: AppHandle,
+    instance_id: String,
+    account_uuid: Option<String>,
+    process_state: State<'_, ChildProcessState>,
+) -> Result<(), String> {
+    if child_running(&process_state, &instance_id) {
+        return Err("This instance is already running".to_string());
+    }
+    end_safe_mode(&app, &instance_id)?;
+
+    let game_dir = instance_dir(&app, &instance_id)?.join(".minecraft");
+    let mods_dir = game_dir.join("mods");
+    if mods_dir.exists() {
+        fs::rename(&mods_dir, game_dir.join(SAFE_MODE_MODS_DIR))
+            .map_err(|e| format!("Failed to move the mods folder aside: {}", e))?;
+    }
+    fs::create_dir_all(&mods_dir).map_err(|e| e.to_string())?;
+    log::info!("Launching instance {} in safe mode", instance_id);
+    let _ = app.emit(
+        "safe-mode-started",
+        SafeModeEvent {
+            instance_id: instance_id.clone(),
+        },
+    );
+
+    let result = launch_game(
+        app.clone(),
+        instance_id.clone(),
+        account_uuid,
+        None,
+        process_state,
+        true,
+    )
+    .await;
+    if result.is_err() {
+        if let Err(e) = end_safe_mode(&app, &instance_id) {
+            log::warn!("Failed to restore mods for {}: {}", instance_id, e);
+        }
+    }
+    result
+}
+
+/// Put the mods folder back after a safe-mode session. Anything added to the
+/// empty folder meanwhile is kept unless the real folder has a file of that name.
+/// Returns false when the instance wasn't in safe mode.
+fn end_safe_mode(app: &AppHandle, instance_id: &str) -> Result<bool, String> {
+    let game_dir = instance_dir(app, instance_id)?.join(".minecraft");
+    let aside = game_dir.join(SAFE_MODE_MODS_DIR);
+    if !aside.is_dir() {
+        return Ok(false);
+    }
+
+    let mods_dir = game_dir.join("mods");
+    if mods_dir.is_dir() {
+        for entry in fs::read_dir(&mods_dir)
+            .map_err(|e| e.to_string())?
+            .flatten()
+        {
+            let target = aside.join(entry.file_name());
+            if !target.exists() {
+                fs::rename(entry.path(), target).map_err(|e| e.to_string())?;
+            }
+        }
+        fs::remove_dir_all(&mods_dir).map_err(|e| e.to_string())?;
+    }
+    fs::rename(&aside, &mods_dir)
+        .map_err(|e| format!("Failed to restore the mods folder: {}", e))?;
+
+    log::info!("Restored mods for instance {} after safe mode", instance_id);
+    let _ = app.emit(
+        "safe-mode-ended",
+        SafeModeEvent {
+            instance_id: instance_id.to_string(),
+        },
+    );
+    Ok(true)
+}
+
+/// Put back mods left aside by a safe-mode session the launcher never saw end
+/// (it crashed or was killed while the game ran). Called once at startup.
+pub(crate) fn restore_safe_mode_mods(app: &AppHandle) {
+    let Ok(entries) =
+        instances_root(app).and_then(|dir| fs::read_dir(dir).map_err(|e| e.to_string()))
+    else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if !entry
+            .path()
+            .join(".minecraft")
+            .join(SAFE_MODE_MODS_DIR)
+            .is_dir()
+        {
+            continue;
+        }
+        let Ok(instance) = read_instance(&entry.path().join("instance.json")) else {
+            continue;
+        };
+        if let Err(e) = end_safe_mode(app, &instance.id) {
+            log::warn!(
+                "Could not restore mods for {} after safe mode: {}",
+                instance.id,
+                e
+            );
+        }
+    }
+}
+
+/// How long updating mods may hold up a launch before the game starts anyway.
+const LAUNCH_MODS_UPDATE_TIMEOUT_SECS: u64 = 120;
+
+/// Payload of `launch-mods-update-finished`; `summary` is unset when the update
+/// failed or ran out of time, with the reason in `error`. In check-only mode nothing is
+/// downloaded and the updates found are listed in `available`.
+#[derive(Serialize, Clone)]
+pub struct LaunchModsUpdate {
+    pub instance_id: String,
+    pub summary: Option<ModsUpdateSummary>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub available: Vec<UpdatedMod>,
+    pub error: Option<String>,
+}
+
+/// Payload of `launch-mods-update-progress`, sent as each mod finishes downloading.
+#[derive(Serialize, Clone)]
+pub struct LaunchModsProgress {
+    pub instance_id: String,
+    #[serde(flatten)]
+    pub progress: ModsInstallProgress,
+}
+
+/// Check or update enabled mods before the game starts, as the instance (or the global
+/// default) asks. Pinned instances are left alone. Never fails the launch: a failed or
+/// slow update is reported and the game starts with what it has.
+async fn update_mods_before_launch(app: &AppHandle, root: &Path, instance: &Instance) {
+    let mode = instance.auto_update_mods_on_launch.unwrap_or_else(|| {
+        crate::settings::get_settings(app.clone())
+            .unwrap_or_default()
+            .auto_update_mods_on_launch
+    });
+    if mode == ModsUpdateOnLaunch::Off || instance.pinned_version || instance.loader.is_none() {
+        return;
+    }
+
+    let _ = app.emit("launch-mods-update-started", &instance.id);
+    let work = async {
+        if mode == ModsUpdateOnLaunch::Check {
+            let (pending, summary) = find_mod_updates(root, instance, false).await?;
+            let available: Vec<UpdatedMod> = pending
+                .into_iter()
+                .map(|p| UpdatedMod {
+                    old_file: p.name,
+                    new_file: p.file.filename,
+                    version: p.version,
+                })
+                .collect();
+            return Ok((summary, available));
+        }
+        let progress = |progress: ModsInstallProgress| {
+            let _ = app.emit(
+                "launch-mods-update-progress",
+                LaunchModsProgress {
+                    instance_id: instance.id.clone(),
+                    progress,
+                },
+            );
+        };
+        let summary = update_instance_mods(root, instance, false, progress).await?;
+        Ok::<_, String>((summary, Vec::new()))
+    };
+    let timeout = std::time::Duration::from_secs(LAUNCH_MODS_UPDATE_TIMEOUT_SECS);
+    let (summary, available, error) = match tokio::time::timeout(timeout, work).await {
+        Ok(Ok((summary, available))) => (Some(summary), available, None),
+        Ok(Err(e)) => (None, Vec::new(), Some(e)),
+        Err(_) => (
+            None,
+            Vec::new(),
+            Some(format!(
+                "Updating mods took longer than {}s",
+                LAUNCH_MODS_UPDATE_TIMEOUT_SECS
+            )),
+        ),
+    };
+    if let Some(e) = &error {
+        log::warn!("Mod update before launching {} failed: {}", instance.id, e);
+    }
+    let _ = app.emit(
+        "launch-mods-update-finished",
+        LaunchModsUpdate {
+            instance_id: instance.id.clone(),
+            summary,
+            available,
+            error,
+        },
+    );
+}
+
+/// Something the user should know about a launch that still went ahead.
+#[derive(Serialize, Clone)]
+pub struct LaunchNotice {
+    pub instance_id: String,
+    pub message: String,
+}
+
+async fn launch_game(
+    app: AppHandle,
+    instance_id: String,
+    account_uuid: Option<String>,
+    profile_name: Option<String>,
+    process_state: State<'_, ChildProcessState>,
+    safe_mode: bool,
+) -> Result<(), String> {
+    if child_running(&process_state, &instance_id) {
+        return Err("This instance is already running".to_string());
+    }
+    // Left over from a safe-mode session that never saw the game exit
+    if !safe_mode {
+        end_safe_mode(&app, &instance_id)?;
+    }
+
+    // Without any stored account the game still runs offline as "Player"
+    let (player_name, player_uuid) = match accounts::resolve_account(&app, account_uuid.as_deref())?
+    {
+        Some(account) => (account.username, account.uuid),
+        None => (
+            "Player".to_string(),
+            "00000000-0000-0000-0000-000000000000".to_string(),
+        ),
+    };
+
+    let instance_root = instance_dir(&app, &instance_id)?;
+    let game_dir = instance_root.join(".minecraft");
+
+    fs::create_dir_all(&game_dir).map_err(|e| e.to_string())?;
+
+    let mut instance = read_instance(&instance_root.join("instance.json"))?;
+
+    // The mods folder is set aside in safe mode, so there is nothing to update
+    if !safe_mode {
+        update_mods_before_launch(&app, &instance_root, &instance).await;
+    }
+
+    // Kept apart from `instance` so the overrides never get written back to instance.json
+    let profile = match profile_name.as_deref() {
+        Some(name) => instance
+            .launch_profiles
+            .iter()
+            .find(|p| p.name == name)
+            .cloned()
+            .ok_or_else(|| format!("No launch profile named {}", name))?,
+        None => LaunchProfile::default(),
+    };
+
+    // Determine the version JSON to use: if loader info is present, prefer derived loader-backed version; otherwise use instance.version
+    let mut version_id = instance.launch_version_id();
+
+    let mut version_json_path =
+        instance_version_file(&app, &instance_root, &instance, &version_id, "json")?;
+
+    // If the derived version JSON is missing, attempt recovery:
+    // - If we have loader info on the instance, try to install the loader (creates derived version)
+    // - Otherwise try to ensure vanilla version files
+    if !version_json_path.exists() {
+        if let (Some(loader), Some(loader_v)) =
+            (instance.loader.clone(), instance.loader_version.clone())
+        {
+            let mc_v = instance
+                .mc_version
+                .clone()
+                .unwrap_or(instance.version.clone());
+            let _ = app.emit(
+                "loader-install-log",
+                format!(
+                    "Derived version {} missing, attempting to install loader {} {}",
+                    version_id, loader, loader_v
+                ),
+            );
+            match install_loader(app.clone(), loader.clone(), mc_v.clone(), loader_v.clone()).await
+            {
+                Ok((derived, used_version)) => {
+                    // success — derived version should now exist (used_version is the actual loader version chosen)
+                    let _ = app.emit(
+                        "loader-install-log",
+                        format!(
+                            "install_loader resolved loader version: {} -> {}",
+                            loader_v, used_version
+                        ),
+                    );
+                    // A fallback version means a different derived id; launch that one and remember it
+                    if used_version != loader_v {
+                        record_resolved_loader_version(&app, &instance_id, &used_version)?;
+                        instance.loader_version = Some(used_version);
+                        version_json_path = instance_version_file(
+                            &app,
+                            &instance_root,
+                            &instance,
+                            &derived,
+                            "json",
+                        )?;
+                        version_id = derived;
+                    }
+                }
+                Err(e) => {
+                    return Err(format!(
+                        "Derived version {} missing and install_loader failed: {}",
+                        version_id, e
+                    ));
+                }
+            }
+        } else {
+            // No loader info -> try to ensure vanilla version json exists/install it
+            if let Err(e) = ensure_vanilla_version(&app, &instance.version).await {
+                return Err(format!(
+                    "Version JSON missing at {} and failed to install vanilla {}: {}",
+                    version_json_path.to_string_lossy(),
+                    instance.version,
+                    e
+                ));
+            }
+        }
+    }
+
+    // Re-check that the JSON exists after recovery attempt
+    if !version_json_path.exists() {
+        return Err(format!(
+            "Version JSON not found at path: {}",
+            version_json_path.to_string_lossy()
+        ));
+    }
+
+    let mut version = read_launch_version(&app, &version_json_path).await?;
+
+    // Confirm client JAR exists too and if missing, attempt to recover similarly
+    let mut client_jar =
+        instance_version_file(&app, &instance_root, &instance, &version_id, "jar")?;
+    if !client_jar.exists() && version_id != instance.version {
+        // Loader versions share the vanilla jar; relinking is cheaper than a loader reinstall
+        let mc_v = instance.mc_version.as_deref().unwrap_or(&instance.version);
+        if let Ok(vanilla) = ensure_vanilla_version(&app, mc_v).await {
+            let _ =
+                crate::install::install_derived_client_jar(&app, &version_id, mc_v, &vanilla).await;
+        }
+    }
+    if !client_jar.exists() {
+        if let (Some(loader), Some(loader_v)) =
+            (instance.loader.clone(), instance.loader_version.clone())
+        {
+            let mc_v = instance
+                .mc_version
+                .clone()
+                .unwrap_or(instance.version.clone());
+            let _ = app.emit(
+                "loader-install-log",
+                format!(
+                    "Client JAR missing for {} - attempting loader install {} {}",
+                    version_id, loader, loader_v
+                ),
+            );
+            match install_loader(app.clone(), loader.clone(), mc_v.clone(), loader_v.clone()).await
+            {
+                Ok((derived, used_version)) => {
+                    let _ = app.emit(
+                        "loader-install-log",
+                        format!(
+                            "install_loader resolved loader version: {} -> {}",
+                            loader_v, used_version
+                        ),
+                    );
+                    // Same as the version JSON recovery: a fallback is a different derived id
+                    if used_version != loader_v {
+                        record_resolved_loader_version(&app, &instance_id, &used_version)?;
+                        instance.loader_version = Some(used_version);
+                        let json_path = instance_version_file(
+                            &app,
+                            &instance_root,
+                            &instance,
+                            &derived,
+                            "json",
+                        )?;
+                        version = read_launch_version(&app, &json_path).await?;
+                        client_jar = instance_version_file(
+                            &app,
+                            &instance_root,
+                            &instance,
+                            &derived,
+                            "jar",
+                        )?;
+                        version_id = derived;
+                    }
+                }
+                Err(e) => {
+                    return Err(format!(
+                        "Client JAR missing at {} and failed to install loader {} {}: {}",
+                        client_jar.to_string_lossy(),
+                        loader,
+                        loader_v,
+                        e
+                    ));
+                }
+            }
+        } else {
+            // Try to install vanilla client jar
+            if let Err(e) = install_client_jar(&app, &instance.version, &version).await {
+                return Err(format!(
                     "Client JAR missing at {} and failed to install vanilla client for {}: {}",
                     client_jar.to_string_lossy(),
                     instance.version,
@@ -909,74 +2403,195 @@ pub async fn launch_instance(
         }
     }
 
-    let classpath = build_classpath(&app, &version_id, &version)?;
-    println!("Launch classpath: {}", classpath);
-    println!("Launch main class: {}", version.mainClass);
-    println!("Launch version ID: {}", version_id);
+    let settings = crate::settings::get_settings(app.clone()).unwrap_or_default();
 
-    let mc_root = minecraft_root(&app)?;
+    // Pre-launch integrity check; re-downloads anything missing or damaged
+    crate::install::verify_installation(&app, &version, settings.verify_on_launch).await?;
 
-    let settings = crate::settings::get_settings(app.clone()).unwrap_or_default();
+    let classpath = build_classpath(&app, &version, &client_jar)?;
+    log::debug!("Launch classpath: {}", classpath);
+    log::debug!("Launch main class: {}", version.mainClass);
+    log::debug!("Launch version ID: {}", version_id);
+
+    let mc_root = minecraft_root(&app)?;
+    let assets_dir = assets_root(&app)?;
+    // Old versions read assets by name from here rather than from the hashed objects
+    let legacy_assets_dir = crate::install::prepare_legacy_assets(&app, &version, &game_dir)
+        .unwrap_or_else(|e| {
+            log::warn!("Could not lay out legacy assets: {}", e);
+            None
+        });
 
     // Java selection priority:
-    // 1. Instance override
+    // 1. Launch profile, then instance override
     // 2. Global setting override
     // 3. Instance auto-detected path
     // 4. "java"
-    let java_cmd = instance
+    let java_cmd = profile
         .java_path_override
         .as_deref()
+        .or(instance.java_path_override.as_deref())
         .or(settings.global_java_path.as_deref())
         .or(instance.java_path.as_deref())
         .unwrap_or("java");
 
     let mut command = Command::new(java_cmd);
 
-    // Memory settings (Instance override > Global settings)
-    let min_mem = instance.min_memory.unwrap_or(settings.min_memory);
-    let max_mem = instance.max_memory.unwrap_or(settings.max_memory);
+    // Memory settings (Instance override > auto-sized > Global settings)
+    let global_max = if settings.auto_memory {
+        crate::settings::auto_max_memory_mb().unwrap_or(settings.max_memory)
+    } else {
+        settings.max_memory
+    };
+    let max_mem = profile
+        .max_memory
+        .or(instance.max_memory)
+        .unwrap_or(global_max);
+    let min_mem = profile
+        .min_memory
+        .or(instance.min_memory)
+        .unwrap_or(settings.min_memory)
+        .min(max_mem);
     command.arg(format!("-Xms{}M", min_mem));
     command.arg(format!("-Xmx{}M", max_mem));
 
-    // JVM args (Global settings + Instance override)
-    for arg in settings.global_java_args.split_whitespace() {
+    // JVM args (Global settings or recommended preset + Instance override)
+    let global_args = if settings.use_recommended_flags {
+        crate::settings::recommended_jvm_args(max_mem)
+    } else {
+        settings.global_java_args.clone()
+    };
+    for arg in global_args.split_whitespace() {
         command.arg(arg);
     }
-    if let Some(args) = &instance.java_args {
+    if let Some(args) = profile.java_args.as_ref().or(instance.java_args.as_ref()) {
         for arg in args.split_whitespace() {
             command.arg(arg);
         }
     }
 
-    command
-        .arg("-cp")
-        .arg(classpath)
-        .arg(&version.mainClass)
-        .arg("--username")
-        .arg("Player")
-        .arg("--uuid")
-        .arg("00000000-0000-0000-0000-000000000000")
-        .arg("--accessToken")
-        .arg("0")
-        .arg("--userType")
-        .arg("offline")
-        .arg("--version")
-        .arg(version_id)
-        .arg("--gameDir")
-        .arg(game_dir.to_string_lossy().to_string())
-        .arg("--assetsDir")
-        .arg(mc_root.join("assets").to_string_lossy().to_string())
-        .arg("--assetIndex")
-        .arg(&version.assetIndex.id);
-    std::println!("Asset index ID: {}", version.assetIndex.id);
-    std::println!(
+    command.arg("-cp").arg(classpath).arg(&version.mainClass);
+    match version.minecraftArguments.as_deref() {
+        // 1.12.2 and older only understand their own template (Forge adds --tweakClass there)
+        Some(template) if version.arguments.is_none() => {
+            let game_assets = legacy_assets_dir
+                .as_deref()
+                .unwrap_or(&assets_dir)
+                .to_string_lossy()
+                .to_string();
+            let assets_dir = assets_dir.to_string_lossy().to_string();
+            let values: HashMap<&str, String> = HashMap::from([
+                ("auth_player_name", player_name.clone()),
+                ("auth_uuid", player_uuid.clone()),
+                ("auth_access_token", "0".to_string()),
+                ("auth_session", "0".to_string()),
+                ("user_type", "legacy".to_string()),
+                ("user_properties", "{}".to_string()),
+                ("version_name", version_id.clone()),
+                (
+                    "version_type",
+                    version
+                        .r#type
+                        .clone()
+                        .unwrap_or_else(|| "release".to_string()),
+                ),
+                ("game_directory", game_dir.to_string_lossy().to_string()),
+                ("assets_root", assets_dir.clone()),
+                ("game_assets", game_assets),
+                ("assets_index_name", version.asset_index()?.id.clone()),
+            ]);
+            command.args(legacy_game_args(template, &values));
+        }
+        _ => {
+            command
+                .arg("--username")
+                .arg(&player_name)
+                .arg("--uuid")
+                .arg(&player_uuid)
+                .arg("--accessToken")
+                .arg("0")
+                .arg("--userType")
+                .arg("offline")
+                .arg("--version")
+                .arg(&version_id)
+                .arg("--gameDir")
+                .arg(game_dir.to_string_lossy().to_string())
+                .arg("--assetsDir")
+                .arg(assets_dir.to_string_lossy().to_string())
+                .arg("--assetIndex")
+                .arg(&version.asset_index()?.id);
+        }
+    }
+    if instance.demo {
+        let features = crate::rules::Features { is_demo_user: true };
+        let mut demo_args = feature_game_args(&version, &features);
+        // Legacy and loader-derived JSONs carry no `arguments` object to take it from
+        if demo_args.is_empty() {
+            demo_args.push("--demo".to_string());
+        }
+        command.args(demo_args);
+    }
+    // The demo world is singleplayer only
+    if let Some(address) = instance
+        .auto_connect_server
+        .as_ref()
+        .filter(|_| !instance.demo)
+    {
+        let (host, port) = parse_server_address(address)?;
+        let mc_v = instance.mc_version.as_deref().unwrap_or(&instance.version);
+        if supports_quick_play(mc_v) {
+            let target = if host.contains(':') {
+                format!("[{}]:{}", host, port)
+            } else {
+                format!("{}:{}", host, port)
+            };
+            command.arg("--quickPlayMultiplayer").arg(target);
+        } else {
+            command
+                .arg("--server")
+                .arg(host)
+                .arg("--port")
+                .arg(port.to_string());
+        }
+    }
+    // A server to join wins over a world to load; the game takes one Quick Play target
+    if let Some(world) = instance
+        .auto_load_world
+        .as_ref()
+        .filter(|_| !instance.demo && instance.auto_connect_server.is_none())
+    {
+        let mc_v = instance.mc_version.as_deref().unwrap_or(&instance.version);
+        if !supports_quick_play(mc_v) {
+            let _ = app.emit(
+                "launch-notice",
+                LaunchNotice {
+                    instance_id: instance_id.clone(),
+                    message: format!(
+                        "Minecraft {} has no Quick Play; not auto-loading world {}",
+                        mc_v, world
+                    ),
+                },
+            );
+        } else if !game_dir
+            .join("saves")
+            .join(world)
+            .join("level.dat")
+            .is_file()
+        {
+            return Err(format!("World \"{}\" was not found in saves", world));
+        } else {
+            command.arg("--quickPlaySingleplayer").arg(world);
+        }
+    }
+    if let Some(args) = profile.game_args.as_ref().or(instance.game_args.as_ref()) {
+        command.args(parse_game_args(args)?);
+    }
+    log::debug!("Asset index ID: {}", version.asset_index()?.id);
+    log::debug!(
         "Version JSON: {}",
         serde_json::to_string_pretty(&version.assetIndex).unwrap()
     );
-    std::println!(
-        "Assets directory: {}",
-        mc_root.join("assets").to_string_lossy().to_string()
-    );
+    log::debug!("Assets directory: {}", assets_dir.display());
     // Run game with CWD = game dir so mods (e.g. Crash Assistant) write config to instance/.minecraft/config/, not project folder
     command.current_dir(&game_dir);
 
@@ -999,7 +2614,8 @@ pub async fn launch_instance(
     app.emit("instance-state-changed", &instance_running)
         .map_err(|e| e.to_string())?;
 
-    if settings.close_on_launch {
+    // Safe mode stays open so it can restore the mods folder when the game exits
+    if settings.close_on_launch && !safe_mode {
         command.spawn().map_err(|e| e.to_string())?;
         app.exit(0);
     } else {
@@ -1008,9 +2624,17 @@ pub async fn launch_instance(
                 let stdout = child.stdout.take().unwrap();
                 let stderr = child.stderr.take().unwrap();
 
-                // Store child process handle for killing later
+                // Store child process handle for killing later. A second launch can race
+                // past the check above during setup; never drop a live handle for it.
                 {
                     let mut lock = process_state.inner().0.lock().unwrap();
+                    if let Some(existing) = lock.get_mut(&instance_id) {
+                        if matches!(existing.try_wait(), Ok(None)) {
+                            let _ = child.kill();
+                            let _ = child.wait();
+                            return Err("This instance is already running".to_string());
+                        }
+                    }
                     lock.insert(instance_id.clone(), child);
                 }
 
@@ -1079,18 +2703,19 @@ pub async fn launch_instance(
                             // Game closed or killed, update state back to Ready
                             if let Ok(root) = instance_dir(&app_handle, &inst_id) {
                                 let meta_path = root.join("instance.json");
-                                if let Ok(text) = fs::read_to_string(&meta_path) {
-                                    if let Ok(mut inst) = serde_json::from_str::<Instance>(&text) {
-                                        inst.state = InstanceState::Ready;
-                                        if let Ok(updated_text) =
-                                            serde_json::to_string_pretty(&inst)
-                                        {
-                                            let _ = fs::write(&meta_path, updated_text);
-                                            let _ = app_handle.emit("instance-state-changed", inst);
-                                        }
+                                if let Ok(mut inst) = read_instance(&meta_path) {
+                                    inst.state = InstanceState::Ready;
+                                    if let Ok(updated_text) = serde_json::to_string_pretty(&inst) {
+                                        let _ = fs::write(&meta_path, updated_text);
+                                        let _ = app_handle.emit("instance-state-changed", inst);
                                     }
                                 }
                             }
+                            if safe_mode {
+                                if let Err(e) = end_safe_mode(&app_handle, &inst_id) {
+                                    log::warn!("Failed to restore mods for {}: {}", inst_id, e);
+                                }
+                            }
                             break;
                         }
                     }
@@ -1103,15 +2728,199 @@ pub async fn launch_instance(
     Ok(())
 }
 
+/// Game arguments the launcher always sets itself; users can't override them via `game_args`.
+const RESERVED_GAME_ARGS: &[&str] = &[
+    "--username",
+    "--uuid",
+    "--accessToken",
+    "--userType",
+    "--version",
+    "--gameDir",
+    "--assetsDir",
+    "--assetIndex",
+];
+
+/// Split an instance's `game_args` on whitespace, rejecting reserved flags.
+fn parse_game_args(args: &str) -> Result<Vec<String>, String> {
+    let parts: Vec<String> = args.split_whitespace().map(String::from).collect();
+    for part in &parts {
+        let flag = part.split('=').next().unwrap_or(part);
+        if RESERVED_GAME_ARGS.contains(&flag) {
+            return Err(format!(
+                "Game argument {} is set by the launcher and can't be overridden",
+                flag
+            ));
+        }
+    }
+    Ok(parts)
+}
+
+/// Parse `host`, `host:port` or `[ipv6]:port`, defaulting the port to 25565.
+fn parse_server_address(address: &str) -> Result<(String, u16), String> {
+    let address = address.trim();
+    let invalid = || format!("Invalid server address: {}", address);
+    if address.is_empty() || address.contains(char::is_whitespace) {
+        return Err(invalid());
+    }
+
+    let (host, port) = if let Some(rest) = address.strip_prefix('[') {
+        let (host, after) = rest.split_once(']').ok_or_else(invalid)?;
+        let port = match after {
+            "" => None,
+            p => Some(p.strip_prefix(':').ok_or_else(invalid)?),
+        };
+        (host, port)
+    } else if address.matches(':').count() > 1 {
+        // Bare IPv6 without brackets
+        (address, None)
+    } else {
+        match address.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (address, None),
+        }
+    };
+
+    if host.is_empty() {
+        return Err(invalid());
+    }
+    let port = match port {
+        Some(p) => p
+            .parse::<u16>()
+            .ok()
+            .filter(|p| *p != 0)
+            .ok_or_else(invalid)?,
+        None => 25565,
+    };
+    Ok((host.to_string(), port))
+}
+
+/// Quick Play (`--quickPlayMultiplayer`, `--quickPlaySingleplayer`) arrived in 1.20 / 23w14a;
+/// older versions can only join a server, with `--server`/`--port`.
+fn supports_quick_play(mc_version: &str) -> bool {
+    if let Some(v) = crate::java::parse_version(mc_version) {
+        v >= (1, 20, 0)
+    } else if let Some(week) = crate::java::parse_snapshot(mc_version) {
+        week >= (23, 14)
+    } else {
+        false
+    }
+}
+
 #[tauri::command]
-pub fn save_instance(app: AppHandle, instance: Instance) -> Result<(), String> {
+pub fn save_instance(app: AppHandle, mut instance: Instance) -> Result<(), String> {
+    let meta_path = instance_meta_path(&app, &instance.id)?;
+    instance.schema_version = crate::migrate::schema_for_save(&meta_path, INSTANCE_SCHEMA_VERSION);
+    let stored = read_instance(&meta_path).ok();
+    // Names from before the length limit stay valid until they're edited
+    if stored.as_ref().map(|s| &s.name) != Some(&instance.name) {
+        instance.name = validate_instance_name(&instance.name)?;
+    }
+    // Unpinning and changing the version in one save is allowed; that's the user's call
+    if let Some(stored) = stored {
+        let version_changed = (
+            &stored.version,
+            &stored.mc_version,
+            &stored.loader,
+            &stored.loader_version,
+        ) != (
+            &instance.version,
+            &instance.mc_version,
+            &instance.loader,
+            &instance.loader_version,
+        );
+        if version_changed && instance.pinned_version {
+            stored.ensure_unpinned("changing its version")?;
+        }
+    }
+    if let Some(args) = &instance.game_args {
+        parse_game_args(args)?;
+    }
+    if let Some(address) = &instance.auto_connect_server {
+        parse_server_address(address)?;
+    }
+    if let Some(world) = &instance.auto_load_world {
+        validate_world_name(world)?;
+    }
     fs::write(
-        instance_meta_path(&app, &instance.id)?,
+        &meta_path,
         serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
     )
     .map_err(|e| e.to_string())
 }
 
+/// Add or replace (by name) a launch profile. Returns the instance's profiles.
+#[tauri::command]
+pub fn save_launch_profile(
+    app: AppHandle,
+    instance_id: String,
+    mut profile: LaunchProfile,
+) -> Result<Vec<LaunchProfile>, String> {
+    profile.name = profile.name.trim().to_string();
+    if profile.name.is_empty() {
+        return Err("Launch profiles need a name".to_string());
+    }
+    if let Some(args) = &profile.game_args {
+        parse_game_args(args)?;
+    }
+
+    let meta_path = instance_meta_path(&app, &instance_id)?;
+    let mut instance = read_instance(&meta_path)?;
+    match instance
+        .launch_profiles
+        .iter_mut()
+        .find(|p| p.name == profile.name)
+    {
+        Some(existing) => *existing = profile,
+        None => instance.launch_profiles.push(profile),
+    }
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(instance.launch_profiles)
+}
+
+#[tauri::command]
+pub fn delete_launch_profile(
+    app: AppHandle,
+    instance_id: String,
+    name: String,
+) -> Result<Vec<LaunchProfile>, String> {
+    let meta_path = instance_meta_path(&app, &instance_id)?;
+    let mut instance = read_instance(&meta_path)?;
+    let before = instance.launch_profiles.len();
+    instance.launch_profiles.retain(|p| p.name != name);
+    if instance.launch_profiles.len() == before {
+        return Err(format!("No launch profile named {}", name));
+    }
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(instance.launch_profiles)
+}
+
+/// `javaVersion.majorVersion` from the instance's version JSON, or from the base
+/// Minecraft version's JSON when the derived loader profile doesn't carry it.
+fn declared_java_version(app: &AppHandle, instance: &Instance) -> Option<u8> {
+    let versions = versions_root(app).ok()?;
+    let mc_version = instance
+        .mc_version
+        .clone()
+        .unwrap_or(instance.version.clone());
+    [instance.launch_version_id(), mc_version]
+        .iter()
+        .find_map(|id| {
+            let text = fs::read_to_string(versions.join(id).join(format!("{}.json", id))).ok()?;
+            serde_json::from_str::<VersionJson>(&text)
+                .ok()?
+                .java_version
+                .map(|j| j.major_version)
+        })
+}
+
 #[derive(Serialize)]
 pub struct JavaCompatibility {
     pub compatible: bool,
@@ -1120,19 +2929,70 @@ pub struct JavaCompatibility {
     pub path: String,
 }
 
+#[derive(Serialize)]
+pub struct JavaArgsValidation {
+    pub valid: bool,
+    pub java_path: String,
+    /// What the JVM printed when it rejected the arguments
+    pub error: Option<String>,
+}
+
+/// Dry-run `java <args> -version` with the instance's Java so bad flags
+/// (e.g. ZGC options on Java 8) are caught before they break a launch.
+#[tauri::command]
+pub async fn validate_java_args(
+    app: AppHandle,
+    instance_id: String,
+    args: String,
+) -> Result<JavaArgsValidation, String> {
+    let instance = read_instance(&instance_meta_path(&app, &instance_id)?)?;
+    let java_path = crate::java::get_intended_java_path(&app, &instance);
+
+    // Split exactly like launch_instance does
+    let output = Command::new(&java_path)
+        .args(args.split_whitespace())
+        .arg("-version")
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", java_path, e))?;
+
+    let error = if output.status.success() {
+        None
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // The JVM's own "Error: ..." lines say which flag was refused
+        let reason: Vec<&str> = stderr
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .take(5)
+            .collect();
+        Some(if reason.is_empty() {
+            format!("Java exited with {}", output.status)
+        } else {
+            reason.join("\n")
+        })
+    };
+
+    Ok(JavaArgsValidation {
+        valid: error.is_none(),
+        java_path,
+        error,
+    })
+}
+
 #[tauri::command]
 pub async fn check_java_compatibility(
     app: AppHandle,
     instance_id: String,
 ) -> Result<JavaCompatibility, String> {
     let instance_root = instance_dir(&app, &instance_id)?;
-    let meta_text =
-        fs::read_to_string(instance_root.join("instance.json")).map_err(|e| e.to_string())?;
-    let instance: Instance = serde_json::from_str(&meta_text).map_err(|e| e.to_string())?;
+    let instance = read_instance(&instance_root.join("instance.json"))?;
 
     // Use the base Minecraft version if present (derived loader versions have a different id)
     let mc_version_for_java = instance.mc_version.as_deref().unwrap_or(&instance.version);
-    let required_version = crate::java::get_required_java_version(mc_version_for_java);
+    let required_version = declared_java_version(&app, &instance)
+        .unwrap_or_else(|| crate::java::get_required_java_version(mc_version_for_java));
     let path = crate::java::get_intended_java_path(&app, &instance);
 
     // Global setting: skip java compatibility entirely
@@ -1164,17 +3024,210 @@ pub async fn check_java_compatibility(
         path,
     })
 }
+
+/// What `resolve_java_for_instance` found or did.
+#[derive(Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JavaResolutionStatus {
+    /// The Java the instance will launch with already matches
+    Compatible,
+    /// A matching system install was found and stored as the instance's Java
+    FoundSystem,
+    /// A matching runtime was downloaded and stored as the instance's Java
+    Downloaded,
+    /// Nothing matching is installed; see `steps`
+    NeedsDownload,
+    /// A global or instance override points at the wrong Java; only the user can change it
+    OverrideMismatch,
+}
+
+#[derive(Serialize)]
+pub struct JavaResolution {
+    pub status: JavaResolutionStatus,
+    pub required_version: u8,
+    /// The Java the instance launches with after this call
+    pub java_path: String,
+    /// What the user can do when `status` isn't resolved
+    pub steps: Vec<String>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct JavaResolveProgress {
+    pub instance_id: String,
+    pub required_version: u8,
+    /// "searching", "downloading" or "done"
+    pub stage: String,
+}
+
+/// Make sure an instance has the Java its version needs. An existing match is left alone;
+/// otherwise a system install is looked up and, with `auto_download`, a runtime is
+/// downloaded. The result is stored as `instance.java_path`. Progress goes out as
+/// `java-resolve-progress`.
+#[tauri::command]
+pub async fn resolve_java_for_instance(
+    app: AppHandle,
+    instance_id: String,
+    auto_download: bool,
+) -> Result<JavaResolution, String> {
+    let compat = check_java_compatibility(app.clone(), instance_id.clone()).await?;
+    let required_version = compat.required_version;
+    let resolution = |status, java_path, steps| JavaResolution {
+        status,
+        required_version,
+        java_path,
+        steps,
+    };
+    if compat.compatible && compat.actual_version.is_some() {
+        return Ok(resolution(
+            JavaResolutionStatus::Compatible,
+            compat.path,
+            Vec::new(),
+        ));
+    }
+
+    let meta_path = instance_meta_path(&app, &instance_id)?;
+    let mut instance = read_instance(&meta_path)?;
+    let settings = crate::settings::get_settings(app.clone()).unwrap_or_default();
+    // These win over `java_path` at launch (global first), so storing a new path wouldn't help
+    let active_override = [
+        ("launcher settings", settings.global_java_path.as_deref()),
+        ("instance settings", instance.java_path_override.as_deref()),
+    ]
+    .into_iter()
+    .find_map(|(place, path)| path.map(|p| (place, p)));
+    if let Some((place, p)) = active_override {
+        return Ok(resolution(
+            JavaResolutionStatus::OverrideMismatch,
+            compat.path,
+            vec![format!(
+                "Change the Java path in {} ({}) to a Java {} install, or clear it",
+                place, p, required_version
+            )],
+        ));
+    }
+
+    let progress = |stage: &str| {
+        let _ = app.emit(
+            "java-resolve-progress",
+            JavaResolveProgress {
+                instance_id: instance_id.clone(),
+                required_version,
+                stage: stage.to_string(),
+            },
+        );
+    };
+    progress("searching");
+    let (status, path) = match crate::java::find_system_java(required_version)? {
+        Some(path) => (JavaResolutionStatus::FoundSystem, path),
+        None if auto_download => {
+            progress("downloading");
+            let path = crate::java::download_java(&app, required_version).await?;
+            (JavaResolutionStatus::Downloaded, path)
+        }
+        None => {
+            progress("done");
+            return Ok(resolution(
+                JavaResolutionStatus::NeedsDownload,
+                compat.path,
+                vec![
+                    format!("Download Java {} (Eclipse Temurin)", required_version),
+                    format!(
+                        "Or install Java {} yourself and set it as this instance's Java path",
+                        required_version
+                    ),
+                ],
+            ));
+        }
+    };
+
+    log::info!(
+        "Using Java {} at {} for instance {}",
+        required_version,
+        path,
+        instance_id
+    );
+    instance.java_path = Some(path.clone());
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+    let _ = app.emit("instance-state-changed", &instance);
+    progress("done");
+    Ok(resolution(status, path, Vec::new()))
+}
+
+/// Kill a game process together with anything it spawned. On Windows the JVM
+/// is often wrapped (javaw via a launcher script), and `Child::kill` only ends
+/// the outer process, so the whole tree is taken down with `taskkill /T`.
+fn kill_process_tree(child: &mut std::process::Child) {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        let _ = std::process::Command::new("taskkill")
+            .args(["/PID", &child.id().to_string(), "/T", "/F"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .status();
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Kill every tracked game process and put `Running` instances back to `Ready`.
+/// Returns the number of processes killed.
+pub fn kill_all_children(app: &AppHandle) -> usize {
+    let children: Vec<(String, std::process::Child)> = {
+        let state = app.state::<ChildProcessState>();
+        let mut lock = state.0.lock().unwrap_or_else(|e| e.into_inner());
+        lock.drain().collect()
+    };
+    let killed = children.len();
+    for (_, mut child) in children {
+        kill_process_tree(&mut child);
+    }
+
+    // Also covers instances whose monitor thread has not caught up yet
+    let Ok(root) = instances_root(app) else {
+        return killed;
+    };
+    let Ok(entries) = fs::read_dir(root) else {
+        return killed;
+    };
+    for entry in entries.flatten() {
+        let meta_path = entry.path().join("instance.json");
+        let Ok(mut inst) = read_instance(&meta_path) else {
+            continue;
+        };
+        if inst.state != InstanceState::Running {
+            continue;
+        }
+        inst.state = InstanceState::Ready;
+        if let Ok(updated_text) = serde_json::to_string_pretty(&inst) {
+            let _ = fs::write(&meta_path, updated_text);
+            let _ = app.emit("instance-state-changed", inst);
+        }
+    }
+    killed
+}
+
 #[tauri::command]
 pub async fn kill_instance(
     instance_id: String,
     process_state: State<'_, ChildProcessState>,
 ) -> Result<(), String> {
-    let mut lock = process_state.inner().0.lock().unwrap();
-    if let Some(mut child) = lock.remove(&instance_id) {
-        let _ = child.kill();
+    let child = process_state.inner().0.lock().unwrap().remove(&instance_id);
+    if let Some(mut child) = child {
+        kill_process_tree(&mut child);
     }
     Ok(())
 }
+
+/// Stop every running instance. Returns how many game processes were killed.
+#[tauri::command]
+pub async fn kill_all_instances(app: AppHandle) -> Result<usize, String> {
+    Ok(kill_all_children(&app))
+}
 #[tauri::command]
 pub async fn search_projects(
     query: String,
@@ -1190,6 +3243,14 @@ pub async fn get_project_versions(
     crate::modrinth::get_project_versions(&project_id).await
 }
 
+/// One Modrinth version, including its changelog.
+#[tauri::command]
+pub async fn get_modrinth_version(
+    version_id: String,
+) -> Result<crate::modrinth::ModrinthVersion, String> {
+    crate::modrinth::get_version(&version_id).await
+}
+
 /// List mod versions compatible with the instance's Minecraft version and loader (for version picker).
 #[tauri::command]
 pub async fn get_compatible_mod_versions(
@@ -1198,8 +3259,7 @@ pub async fn get_compatible_mod_versions(
     project_id: String,
 ) -> Result<Vec<crate::modrinth::ModrinthVersion>, String> {
     let root = instance_dir(&app, &instance_id)?;
-    let meta_text = fs::read_to_string(root.join("instance.json")).map_err(|e| e.to_string())?;
-    let instance: Instance = serde_json::from_str(&meta_text).map_err(|e| e.to_string())?;
+    let instance = read_instance(&root.join("instance.json"))?;
 
     let mc_version = instance
         .mc_version
@@ -1218,6 +3278,36 @@ pub async fn get_compatible_mod_versions(
     crate::modrinth::list_compatible_versions(&project_id, mc_version, loader).await
 }
 
+/// List a project's versions that support `mc_version` and `loader`, newest first.
+/// `offset`/`limit` page through the filtered list so the mod browser can "load more".
+#[tauri::command]
+pub async fn get_compatible_project_versions(
+    project_id: String,
+    mc_version: String,
+    loader: String,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> Result<Vec<crate::modrinth::ModrinthVersion>, String> {
+    let versions = crate::modrinth::get_filtered_project_versions(
+        &project_id,
+        &mc_version,
+        &loader.to_lowercase(),
+    )
+    .await?;
+
+    let offset = offset.unwrap_or(0);
+    let limit = limit.unwrap_or(versions.len());
+    Ok(versions.into_iter().skip(offset).take(limit).collect())
+}
+
+/// Local file path for a project icon (downloaded once, then served from the icon cache).
+#[tauri::command]
+pub async fn get_cached_icon(app: AppHandle, url: String) -> Result<String, String> {
+    crate::modrinth::cached_icon(&app, &url)
+        .await
+        .map(|p| p.to_string_lossy().to_string())
+}
+
 #[tauri::command]
 pub async fn get_popular_mods(
     _app: AppHandle,
@@ -1238,9 +3328,10 @@ pub async fn download_loader_version(
     project_id: String,
     version_id: String,
 ) -> Result<(), String> {
+    let root = instance_dir(&app, &instance_id)?;
+    read_instance(&root.join("instance.json"))?.ensure_unpinned("installing a loader")?;
     let version = crate::modrinth::get_version(&version_id).await?;
 
-    let root = instance_dir(&app, &instance_id)?;
     let mc_dir = root.join(".minecraft");
     let loader_dir = mc_dir.join("loaders").join(&project_id).join(&version_id);
     std::fs::create_dir_all(&loader_dir).map_err(|e| e.to_string())?;
@@ -1248,7 +3339,8 @@ pub async fn download_loader_version(
     // Download files
     for file in &version.files {
         let target = loader_dir.join(&file.filename);
-        crate::download::download_to_file(&file.url, &target).await?;
+        crate::download::download_verified(&file.url, &target, file.expected_hash().as_ref())
+            .await?;
     }
 
     // Determine loader type from Modrinth version metadata (prefer explicit loader names like fabric/quilt)
@@ -1269,247 +3361,312 @@ pub async fn download_loader_version(
     };
 
     // Update instance metadata to record loader presence after download (store loader type & version)
-    if let Ok(meta_text) = std::fs::read_to_string(root.join("instance.json")) {
-        if let Ok(mut inst) = serde_json::from_str::<Instance>(&meta_text) {
-            inst.loader = Some(loader_type.clone());
-            inst.loader_version = Some(version.version_number.clone());
-            let _ = std::fs::write(
-                root.join("instance.json"),
-                serde_json::to_string_pretty(&inst).map_err(|e| e.to_string())?,
-            );
-        }
+    if let Ok(mut inst) = read_instance(&root.join("instance.json")) {
+        inst.loader = Some(loader_type.clone());
+        inst.loader_version = Some(version.version_number.clone());
+        let _ = std::fs::write(
+            root.join("instance.json"),
+            serde_json::to_string_pretty(&inst).map_err(|e| e.to_string())?,
+        );
     }
 
-    // Try to find an installer jar and run it (best-effort)
-    for entry in std::fs::read_dir(&loader_dir).map_err(|e| e.to_string())? {
-        if let Ok(entry) = entry {
-            let path = entry.path();
-            if let Some(name) = path.file_name().and_then(|s| s.to_str()) {
-                let lname = name.to_lowercase();
-                if lname.contains("installer")
-                    || lname.contains("fabric-installer")
-                    || lname.contains("forge-installer")
-                {
-                    // Attempt to run installer with Java: java -jar <installer> client -dir <mc_dir> -mcversion <version> -loader <version_number>
-                    let installer_path = path.to_string_lossy().to_string();
-
-                    // Read instance metadata so we can pick the correct Java to run the installer
-                    let instance_obj = {
-                        let meta_text = std::fs::read_to_string(root.join("instance.json"))
-                            .map_err(|e| e.to_string())?;
-                        serde_json::from_str::<Instance>(&meta_text).map_err(|e| e.to_string())?
-                    };
-                    let instance_version = instance_obj.version.clone();
-                    let java_cmd = crate::java::get_intended_java_path(&app, &instance_obj);
-
-                    // Determine loader type for verification (prefer explicit loader names)
-                    let loader_type_for_verify = if let Some(l) = version.loaders.first() {
-                        let lt = l.to_lowercase();
-                        if lt.contains("fabric") {
-                            "fabric".to_string()
-                        } else if lt.contains("quilt") {
-                            "quilt".to_string()
-                        } else {
-                            l.clone()
-                        }
-                    } else {
-                        project_id.clone()
-                    };
+    let instance_obj = { read_instance(&root.join("instance.json"))? };
+    let instance_version = instance_obj
+        .mc_version
+        .clone()
+        .unwrap_or(instance_obj.version.clone());
+    // Use the loader's version number (e.g., "0.141.2+1.21.11") for installer arguments and verification
+    let loader_number = version.version_number.clone();
+    let settings = crate::settings::get_settings(app.clone()).unwrap_or_default();
 
-                    // Clone for thread
-                    let app_clone = app.clone();
-                    let iid = instance_id.clone();
-                    let pid = project_id.clone();
-                    let _vid = version_id.clone(); // kept for reference if needed
-                                                   // Use the loader's version number (e.g., "0.141.2+1.21.11") for installer arguments and verification
-                    let loader_number = version.version_number.clone();
-                    let mc_dir_str = mc_dir.to_string_lossy().to_string();
-                    let installer = installer_path.clone();
-                    let java = java_cmd.clone();
-                    let loader_type_for_verify = loader_type_for_verify.clone();
-                    let loader_number = loader_number.clone();
-
-                    std::thread::spawn(move || {
-                        use std::io::{BufRead, BufReader};
-
-                        // Use the intended Java command for the instance (may be a custom path)
-                        let mut cmd = std::process::Command::new(&java);
-
-                        // Fabric installer supports a non-interactive "-y" flag to accept prompts.
-                        // Use the canonical invocation: `java -jar fabric-installer.jar client -dir <mc_dir> -mcversion <version> -loader <version> -y`
-                        cmd.arg("-jar")
-                            .arg(&installer)
-                            .arg("client")
-                            .arg("-dir")
-                            .arg(&mc_dir_str)
-                            .arg("-mcversion")
-                            .arg(&instance_version)
-                            .arg("-loader")
-                            .arg(&loader_number)
-                            .arg("-y")
-                            .current_dir(std::path::Path::new(&mc_dir_str));
-
-                        if let Ok(mut child) = cmd
-                            .stdout(std::process::Stdio::piped())
-                            .stderr(std::process::Stdio::piped())
-                            .spawn()
-                        {
-                            if let Some(stdout) = child.stdout.take() {
-                                let reader = BufReader::new(stdout);
-                                for line in reader.lines().flatten() {
-                                    let _ = app_clone.emit(
-                                        "instance-log",
-                                        InstanceLog {
-                                            instance_id: iid.clone(),
-                                            message: line,
-                                        },
-                                    );
-                                }
-                            }
-                            if let Some(stderr) = child.stderr.take() {
-                                let reader = BufReader::new(stderr);
-                                for line in reader.lines().flatten() {
-                                    let _ = app_clone.emit(
-                                        "instance-log",
-                                        InstanceLog {
-                                            instance_id: iid.clone(),
-                                            message: line,
-                                        },
-                                    );
-                                }
-                            }
-                            let _ = child.wait();
+    // Installer jars are opt-in: the meta-profile path below needs no subprocess
+    let installer = if settings.run_loader_installers {
+        std::fs::read_dir(&loader_dir)
+            .map_err(|e| e.to_string())?
+            .flatten()
+            .map(|e| e.path())
+            .find(|p| {
+                p.file_name()
+                    .and_then(|s| s.to_str())
+                    .is_some_and(|n| n.to_lowercase().contains("installer"))
+            })
+    } else {
+        None
+    };
 
-                            // Verify installation: if Fabric, use `fabric_installed` with explicit versions; otherwise use heuristic `loader_verification`.
-                            let success =
-                                if loader_type_for_verify.to_lowercase().contains("fabric") {
-                                    crate::loader::fabric_installed(
-                                        std::path::Path::new(&mc_dir_str),
-                                        &instance_version,
-                                        &loader_number,
-                                    )
-                                } else {
-                                    crate::loader::loader_verification(
-                                        std::path::Path::new(&mc_dir_str),
-                                        &loader_type_for_verify,
-                                    )
-                                };
+    if let Some(installer_path) = installer {
+        let java_cmd = crate::java::get_intended_java_path(&app, &instance_obj);
+        let app_clone = app.clone();
+        let iid = instance_id.clone();
+        let pid = project_id.clone();
+
+        std::thread::spawn(move || {
+            if let Err(e) = run_loader_installer(
+                &app_clone,
+                &iid,
+                &java_cmd,
+                &installer_path,
+                &loader_type,
+                &mc_dir,
+                &instance_version,
+                &loader_number,
+            ) {
+                let _ = app_clone.emit("loader-install-log", format!("Loader installer: {}", e));
+            }
 
-                            // Mark instance metadata (already set) and emit installed event with details
-                            let _ = app_clone.emit(
-                                "loader-installed",
-                                LoaderInstalled {
-                                    instance_id: iid.clone(),
-                                    project_id: pid.clone(),
-                                    version_id: loader_number.clone(),
-                                    success,
-                                },
+            // Verify installation: if Fabric, use `fabric_installed` with explicit versions; otherwise use heuristic `loader_verification`.
+            let success = if loader_type == "fabric" {
+                crate::loader::fabric_installed(&mc_dir, &instance_version, &loader_number)
+            } else {
+                crate::loader::loader_verification(&mc_dir, &loader_type)
+            };
+            emit_loader_result(&app_clone, &iid, &pid, &loader_number, success);
+
+            // Ensure derived version JSON exists by calling `install_loader` asynchronously.
+            if success {
+                let app_for_install = app_clone.clone();
+                tauri::async_runtime::spawn(async move {
+                    match install_loader(
+                        app_for_install.clone(),
+                        loader_type,
+                        instance_version,
+                        loader_number,
+                    )
+                    .await
+                    {
+                        Ok((derived_id, used_version)) => {
+                            let _ = app_for_install.emit(
+                                "loader-install-log",
+                                format!(
+                                    "install_loader created derived version {} (used {})",
+                                    derived_id, used_version
+                                ),
                             );
-
-                            // Ensure derived version JSON exists by calling `install_loader` asynchronously.
-                            if success {
-                                let app_for_install = app_clone.clone();
-                                let lt = loader_type_for_verify.clone();
-                                let mc_v = instance_version.clone();
-                                let lnum = loader_number.clone();
-                                tauri::async_runtime::spawn(async move {
-                                    match crate::commands::install_loader(
-                                        app_for_install.clone(),
-                                        lt,
-                                        mc_v,
-                                        lnum,
-                                    )
-                                    .await
-                                    {
-                                        Ok((derived_id, used_version)) => {
-                                            let _ = app_for_install.emit("loader-install-log", format!("install_loader created derived version {} (used {})", derived_id, used_version));
-                                        }
-                                        Err(e) => {
-                                            let _ = app_for_install.emit(
-                                                "loader-install-log",
-                                                format!("install_loader failed: {}", e),
-                                            );
-                                        }
-                                    }
-                                });
+                            if let Err(e) = record_resolved_loader_version(
+                                &app_for_install,
+                                &iid,
+                                &used_version,
+                            ) {
+                                let _ = app_for_install.emit(
+                                    "loader-install-log",
+                                    format!("Could not record loader version: {}", e),
+                                );
                             }
-                        } else {
-                            // Could not spawn installer, attempt verification (use normalized loader type)
-                            let success =
-                                if loader_type_for_verify.to_lowercase().contains("fabric") {
-                                    crate::loader::fabric_installed(
-                                        std::path::Path::new(&mc_dir_str),
-                                        &instance_version,
-                                        &loader_number,
-                                    )
-                                } else {
-                                    crate::loader::loader_verification(
-                                        std::path::Path::new(&mc_dir_str),
-                                        &loader_type_for_verify,
-                                    )
-                                };
-                            let _ = app_clone.emit(
-                                "loader-installed",
-                                LoaderInstalled {
-                                    instance_id: iid.clone(),
-                                    project_id: pid.clone(),
-                                    version_id: loader_number.clone(),
-                                    success,
-                                },
+                        }
+                        Err(e) => {
+                            let _ = app_for_install.emit(
+                                "loader-install-log",
+                                format!("install_loader failed: {}", e),
                             );
-
-                            if success {
-                                let app_for_install = app_clone.clone();
-                                let lt = loader_type_for_verify.clone();
-                                let mc_v = instance_version.clone();
-                                let lnum = loader_number.clone();
-                                tauri::async_runtime::spawn(async move {
-                                    match crate::commands::install_loader(
-                                        app_for_install.clone(),
-                                        lt,
-                                        mc_v,
-                                        lnum,
-                                    )
-                                    .await
-                                    {
-                                        Ok((derived_id, used_version)) => {
-                                            let _ = app_for_install.emit("loader-install-log", format!("install_loader created derived version {} (used {})", derived_id, used_version));
-                                        }
-                                        Err(e) => {
-                                            let _ = app_for_install.emit(
-                                                "loader-install-log",
-                                                format!("install_loader failed: {}", e),
-                                            );
-                                        }
-                                    }
-                                });
-                            }
                         }
-                    });
+                    }
+                });
+            }
+        });
 
-                    return Ok(());
-                }
+        return Ok(());
+    }
+
+    if loader_type == "fabric" || loader_type == "quilt" {
+        // Meta-profile install, same as modpacks use
+        crate::loader::emit_loader_progress(
+            &app,
+            &instance_id,
+            "installing",
+            20,
+            format!("Installing {} {}", loader_type, loader_number),
+        );
+        let result = install_loader(
+            app.clone(),
+            loader_type.clone(),
+            instance_version,
+            loader_number.clone(),
+        )
+        .await;
+        let loader_number = match &result {
+            Ok((_, used_version)) => {
+                record_resolved_loader_version(&app, &instance_id, used_version)?;
+                used_version.clone()
             }
-        }
+            Err(e) => {
+                let _ = app.emit(
+                    "loader-install-log",
+                    format!("install_loader failed: {}", e),
+                );
+                loader_number
+            }
+        };
+        emit_loader_result(
+            &app,
+            &instance_id,
+            &project_id,
+            &loader_number,
+            result.is_ok(),
+        );
+        return Ok(());
     }
 
-    // No installer found, attempt verification and emit installed event (may be partial)
+    // No installer and no meta profile: verify what's there and report (may be partial)
     let success = crate::loader::loader_verification(&mc_dir, &loader_type);
-    let _ = app
-        .emit(
-            "loader-installed",
-            LoaderInstalled {
-                instance_id: instance_id.clone(),
-                project_id: project_id.clone(),
-                version_id: version_id.clone(),
-                success,
-            },
-        )
-        .map_err(|e| e.to_string())?;
+    emit_loader_result(&app, &instance_id, &project_id, &version_id, success);
 
     Ok(())
 }
 
+/// Loader installer jars get this long before they're killed.
+const LOADER_INSTALLER_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
+fn emit_loader_result(
+    app: &AppHandle,
+    instance_id: &str,
+    project_id: &str,
+    version_id: &str,
+    success: bool,
+) {
+    let _ = app.emit(
+        "loader-installed",
+        LoaderInstalled {
+            instance_id: instance_id.to_string(),
+            project_id: project_id.to_string(),
+            version_id: version_id.to_string(),
+            success,
+        },
+    );
+    let _ = app.emit(
+        "loader-install-finished",
+        crate::loader::LoaderInstallFinished {
+            instance_id: instance_id.to_string(),
+            success,
+        },
+    );
+}
+
+/// Run a loader's installer jar with the arguments that loader expects, streaming its
+/// output to `instance-log` and killing it after `LOADER_INSTALLER_TIMEOUT`. Blocking.
+#[allow(clippy::too_many_arguments)]
+fn run_loader_installer(
+    app: &AppHandle,
+    instance_id: &str,
+    java: &str,
+    installer: &std::path::Path,
+    loader_type: &str,
+    mc_dir: &std::path::Path,
+    mc_version: &str,
+    loader_version: &str,
+) -> Result<(), String> {
+    let mut cmd = Command::new(java);
+    cmd.arg("-jar").arg(installer);
+    match loader_type {
+        // java -jar fabric-installer.jar client -dir <mc_dir> -mcversion <version> -loader <version> -y
+        "fabric" => {
+            cmd.arg("client")
+                .arg("-dir")
+                .arg(mc_dir)
+                .arg("-mcversion")
+                .arg(mc_version)
+                .arg("-loader")
+                .arg(loader_version)
+                .arg("-y");
+        }
+        // java -jar quilt-installer.jar install client <version> <loader> --install-dir=<mc_dir>
+        "quilt" => {
+            cmd.arg("install")
+                .arg("client")
+                .arg(mc_version)
+                .arg(loader_version)
+                .arg(format!("--install-dir={}", mc_dir.to_string_lossy()));
+        }
+        // java -jar forge-installer.jar --installClient <mc_dir>
+        "forge" | "neoforge" => {
+            cmd.arg("--installClient").arg(mc_dir);
+        }
+        other => return Err(format!("No known installer arguments for {}", other)),
+    }
+    cmd.current_dir(mc_dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    crate::loader::emit_loader_progress(
+        app,
+        instance_id,
+        "starting",
+        5,
+        "Starting loader installer",
+    );
+    let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+
+    // Drain both pipes concurrently so a chatty stderr can't stall the installer
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    let out_app = app.clone();
+    let out_id = instance_id.to_string();
+    let out_reader = std::thread::spawn(move || {
+        let Some(stdout) = stdout else { return };
+        let mut percent = 5;
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if let Some((phase, p)) = crate::loader::installer_phase(&line) {
+                if p > percent {
+                    percent = p;
+                    crate::loader::emit_loader_progress(&out_app, &out_id, phase, p, &line);
+                }
+            }
+            let _ = out_app.emit(
+                "instance-log",
+                InstanceLog {
+                    instance_id: out_id.clone(),
+                    message: line,
+                },
+            );
+        }
+    });
+    let err_app = app.clone();
+    let err_id = instance_id.to_string();
+    let err_reader = std::thread::spawn(move || {
+        let Some(stderr) = stderr else { return };
+        for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+            let _ = err_app.emit(
+                "instance-log",
+                InstanceLog {
+                    instance_id: err_id.clone(),
+                    message: line,
+                },
+            );
+        }
+    });
+
+    let deadline = std::time::Instant::now() + LOADER_INSTALLER_TIMEOUT;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Ok(status),
+            Ok(None) if std::time::Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break Err(format!(
+                    "timed out after {} minutes and was stopped",
+                    LOADER_INSTALLER_TIMEOUT.as_secs() / 60
+                ));
+            }
+            Ok(None) => std::thread::sleep(std::time::Duration::from_millis(250)),
+            Err(e) => break Err(e.to_string()),
+        }
+    };
+    let _ = out_reader.join();
+    let _ = err_reader.join();
+
+    match status? {
+        s if s.success() => Ok(()),
+        s => Err(format!("exited with {}", s)),
+    }
+}
+
+/// Payload of `modpack-server-pack`: the pack being installed is mostly server-only files.
+#[derive(Serialize, Clone)]
+pub struct ServerPackWarning {
+    pub instance_id: String,
+    #[serde(flatten)]
+    pub sides: crate::modrinth::ModpackSides,
+}
+
 #[tauri::command]
 pub async fn install_modpack_version(
     app: AppHandle,
@@ -1517,23 +3674,140 @@ pub async fn install_modpack_version(
     version_id: String,
 ) -> Result<(), String> {
     let version = crate::modrinth::get_version(&version_id).await?;
-    let game_version = version
-        .game_versions
-        .first()
-        .ok_or("No game version specified")?
-        .clone();
 
+    // Step 1: Download and check the .mrpack before any instance exists, so a
+    // malformed pack never leaves a half-created instance behind
+    let mut mrpack_path_opt: Option<PathBuf> = None;
+    let mut checked_pack = None;
+    if let Some(file) = version
+        .files
+        .iter()
+        .find(|f| f.filename.to_lowercase().ends_with(".mrpack"))
+    {
+        let target = std::env::temp_dir().join(format!("{}.mrpack", uuid::Uuid::new_v4()));
+        let checked = match crate::download::download_verified(
+            &file.url,
+            &target,
+            file.expected_hash().as_ref(),
+        )
+        .await
+        {
+            Ok(_) => read_downloaded_modpack(&target),
+            Err(e) => Err(format!("Failed to download modpack: {}", e)),
+        };
+        match checked {
+            Ok(checked) => {
+                checked_pack = Some(checked);
+                mrpack_path_opt = Some(target);
+            }
+            Err(e) => {
+                let _ = fs::remove_file(&target);
+                return Err(e);
+            }
+        }
+    }
+
+    // Step 2: Determine Minecraft version; the pack index is authoritative
+    let game_version = match &checked_pack {
+        Some((_, mc_version, _)) => mc_version.clone(),
+        None => version
+            .game_versions
+            .first()
+            .ok_or("No game version specified")?
+            .clone(),
+    };
+
+    let (mut instance, root) = match create_modpack_instance(&app, &name, &game_version) {
+        Ok(created) => created,
+        Err(e) => {
+            if let Some(path) = &mrpack_path_opt {
+                let _ = fs::remove_file(path);
+            }
+            return Err(e);
+        }
+    };
+    let inst_id = instance.id.clone();
+    let meta_path = root.join("instance.json");
+
+    // Steps 3-5; any failure leaves the instance in the Error state below
+    let result: Result<(), String> = async {
+        if let (Some(mrpack_path), Some((idx, mc_version, loader_info))) =
+            (&mrpack_path_opt, checked_pack)
+        {
+            let _ = app.emit("modpack-download-complete", &inst_id);
+            warn_if_server_pack(&app, &inst_id, &idx);
+
+            // Steps 3-4: Install vanilla Minecraft and the loader
+            install_modpack_base(&app, &mut instance, &meta_path, &mc_version, loader_info).await?;
+
+            // Step 5: Extract modpack contents (mods, overrides)
+            let _ = app.emit("modpack-extract-started", &inst_id);
+            crate::install::emit_install_phase(
+                &app,
+                Some(&inst_id),
+                crate::install::InstallPhase::DownloadingMods,
+            );
+            crate::modrinth::install_mrpack(&app, &inst_id, mrpack_path)
+                .await
+                .map_err(|e| format!("Failed to extract modpack: {}", e))?;
+            let _ = app.emit("modpack-extract-complete", &inst_id);
+        } else {
+            // Fallback to Modrinth version metadata
+            let loader_info = if !version.loaders.is_empty() {
+                Some(LoaderInfo {
+                    loader_type: normalize_loader_type(&version.loaders[0]),
+                    version: None, // Will be resolved later
+                })
+            } else {
+                None
+            };
+
+            // Steps 3-4: Install vanilla Minecraft and the loader
+            install_modpack_base(&app, &mut instance, &meta_path, &game_version, loader_info)
+                .await?;
+
+            // Step 5: download individual files (legacy modpack format)
+            let _ = app.emit("modpack-files-download-started", &inst_id);
+            crate::install::emit_install_phase(
+                &app,
+                Some(&inst_id),
+                crate::install::InstallPhase::DownloadingMods,
+            );
+
+            for file in &version.files {
+                download_mod_file(&root, file).await?;
+                let _ = app.emit("file-downloaded", &file.filename);
+            }
+
+            let _ = app.emit("modpack-files-download-complete", &inst_id);
+        }
+        Ok(())
+    }
+    .await;
+    if let Some(path) = &mrpack_path_opt {
+        let _ = fs::remove_file(path);
+    }
+
+    finish_modpack_install(&app, &mut instance, &meta_path, result)
+}
+
+/// Create the folder and instance.json for a modpack about to be installed, in the
+/// `Installing` state (uuid in config, folder = name).
+fn create_modpack_instance(
+    app: &AppHandle,
+    name: &str,
+    game_version: &str,
+) -> Result<(Instance, PathBuf), String> {
     let inst_id = uuid::Uuid::new_v4().to_string();
-    let folder_name = unique_instance_folder_name(&app, &name)?;
-    let root = instances_root(&app)?.join(&folder_name);
+    let folder_name = unique_instance_folder_name(app, name)?;
+    let root = instances_root(app)?.join(&folder_name);
     fs::create_dir_all(&root).map_err(|e| e.to_string())?;
 
-    // Create initial instance with installing state (uuid in config, folder = name)
-    let mut instance = Instance {
+    let instance = Instance {
         id: inst_id.clone(),
-        name: name.clone(),
-        version: game_version.clone(),
-        mc_version: Some(game_version.clone()),
+        name: name.to_string(),
+        version: game_version.to_string(),
+        mc_version: Some(game_version.to_string()),
         state: InstanceState::Installing,
         created_at: chrono::Utc::now().timestamp() as u64,
         last_played: None,
@@ -1544,214 +3818,244 @@ pub async fn install_modpack_version(
         max_memory: None,
         min_memory: None,
         java_args: None,
+        game_args: None,
+        auto_connect_server: None,
+        auto_load_world: None,
         java_warning_ignored: false,
+        demo: false,
+        schema_version: INSTANCE_SCHEMA_VERSION,
         loader: None,
         loader_version: None,
+        tags: Vec::new(),
+        group: None,
+        launch_profiles: Vec::new(),
+        private_versions: false,
+        pinned_version: false,
+        auto_update_mods_on_launch: None,
     };
 
-    let meta_path = root.join("instance.json");
     let json = serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?;
-    fs::write(&meta_path, json).map_err(|e| e.to_string())?;
+    fs::write(root.join("instance.json"), json).map_err(|e| e.to_string())?;
 
     // Emit installation started event
     let _ = app.emit("instance-install-started", &inst_id);
+    Ok((instance, root))
+}
 
-    // Step 1: Download and parse .mrpack file to extract modpack metadata
-    let mut mrpack_path_opt: Option<std::path::PathBuf> = None;
-    let mut modpack_index: Option<crate::modrinth::ModpackIndex> = None;
-
-    for file in &version.files {
-        if file.filename.to_lowercase().ends_with(".mrpack") {
-            let target = root.join(&file.filename);
-
-            // Download the .mrpack file
-            match crate::download::download_to_file(&file.url, &target).await {
-                Ok(_) => {
-                    // Parse modpack index to extract loader information
-                    match crate::modrinth::parse_mrpack_index(&target) {
-                        Ok(idx) => {
-                            modpack_index = Some(idx);
-                            mrpack_path_opt = Some(target);
-                            let _ = app.emit("modpack-download-complete", &inst_id);
-                            break;
-                        }
-                        Err(e) => {
-                            let _ = std::fs::remove_file(&target);
-                            let _ = app.emit(
-                                "instance-install-error",
-                                format!("Failed to parse modpack: {}", e),
-                            );
-                            return Err(format!("Failed to parse modpack: {}", e));
-                        }
-                    }
-                }
-                Err(e) => {
-                    let _ = app.emit(
-                        "instance-install-error",
-                        format!("Failed to download modpack: {}", e),
-                    );
-                    return Err(format!("Failed to download modpack: {}", e));
-                }
-            }
-        }
-    }
-
-    // Step 2: Determine Minecraft version and loader requirements
-    let (resolved_mc_version, loader_info) = if let Some(ref idx) = modpack_index {
-        // Use modpack index for accurate information
-        let mc_ver = idx
-            .version_id
-            .clone()
-            .unwrap_or_else(|| game_version.clone());
-
-        // Extract loader information from dependencies
-        let loader_info = extract_loader_from_dependencies(&idx.dependencies)?;
-
-        (mc_ver, loader_info)
-    } else {
-        // Fallback to Modrinth version metadata
-        let loader_info = if !version.loaders.is_empty() {
-            Some(LoaderInfo {
-                loader_type: normalize_loader_type(&version.loaders[0]),
-                version: None, // Will be resolved later
-            })
-        } else {
-            None
-        };
-
-        (game_version.clone(), loader_info)
-    };
+/// Install vanilla Minecraft, then the pack's loader if it has one, recording the
+/// loader on the instance.
+async fn install_modpack_base(
+    app: &AppHandle,
+    instance: &mut Instance,
+    meta_path: &Path,
+    mc_version: &str,
+    loader_info: Option<LoaderInfo>,
+) -> Result<(), String> {
+    let inst_id = instance.id.clone();
 
-    // Step 3: Install vanilla Minecraft version
     let _ = app.emit("vanilla-install-started", &inst_id);
-    let _base_version = ensure_vanilla_version(&app, &resolved_mc_version)
+    crate::install::emit_install_phase(
+        app,
+        Some(&inst_id),
+        crate::install::InstallPhase::InstallingVanilla {
+            mc_version: mc_version.to_string(),
+        },
+    );
+    let _base_version = ensure_vanilla_version(app, mc_version)
         .await
-        .map_err(|e| {
-            let _ = app.emit(
-                "instance-install-error",
-                format!("Failed to install vanilla Minecraft: {}", e),
-            );
-            e
-        })?;
+        .map_err(|e| format!("Failed to install vanilla Minecraft: {}", e))?;
     let _ = app.emit("vanilla-install-complete", &inst_id);
 
-    // Step 4: Install loader if required
-    if let Some(loader_info) = loader_info {
-        if loader_info.loader_type == "forge" {
-            // Forge not supported yet
-            let mut inst_err = instance.clone();
-            inst_err.state = InstanceState::Error;
-            let _ = fs::write(
-                &meta_path,
-                serde_json::to_string_pretty(&inst_err).map_err(|e| e.to_string())?,
-            );
-            let _ = app.emit(
-                "instance-install-error",
-                "Forge modpacks are not supported yet",
-            );
-            return Err("Forge modpacks are not supported yet".to_string());
-        }
+    let Some(loader_info) = loader_info else {
+        return Ok(());
+    };
+    if loader_info.loader_type == "forge" {
+        // Forge not supported yet
+        return Err("Forge modpacks are not supported yet".to_string());
+    }
 
-        let _ = app.emit(
-            "loader-install-started",
-            format!("Installing {} loader", loader_info.loader_type),
-        );
+    crate::install::emit_install_phase(
+        app,
+        Some(&inst_id),
+        crate::install::InstallPhase::InstallingLoader {
+            loader: loader_info.loader_type.clone(),
+        },
+    );
 
-        // Install loader with proper error handling and verification
-        let (_derived_version_id, actual_loader_version) = install_loader_robust(
-            &app,
-            &loader_info.loader_type,
-            &resolved_mc_version,
-            loader_info.version.as_deref(),
-            &inst_id,
-        )
-        .await?;
+    // Install loader with proper error handling and verification
+    let (_derived_version_id, actual_loader_version) = install_loader_robust(
+        app,
+        &loader_info.loader_type,
+        mc_version,
+        loader_info.version.as_deref(),
+        &inst_id,
+    )
+    .await?;
+
+    // Update instance metadata with loader information
+    // Keep the version as the base MC version, not the derived version
+    instance.loader = Some(loader_info.loader_type.clone());
+    instance.loader_version = Some(actual_loader_version.clone());
+    instance.version = mc_version.to_string();
+    instance.mc_version = Some(mc_version.to_string());
+
+    let json = serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?;
+    fs::write(meta_path, json).map_err(|e| e.to_string())?;
+
+    // Emit loader installed event
+    let _ = app.emit(
+        "loader-installed",
+        LoaderInstalled {
+            instance_id: inst_id,
+            project_id: loader_info.loader_type,
+            version_id: actual_loader_version,
+            success: true,
+        },
+    );
+    Ok(())
+}
 
-        // Update instance metadata with loader information
-        // Keep the version as the base MC version, not the derived version
-        instance.loader = Some(loader_info.loader_type.clone());
-        instance.loader_version = Some(actual_loader_version.clone());
-        instance.version = resolved_mc_version.clone();
-        instance.mc_version = Some(resolved_mc_version.clone());
+/// Record the final state of a modpack install and report it.
+fn finish_modpack_install(
+    app: &AppHandle,
+    instance: &mut Instance,
+    meta_path: &Path,
+    result: Result<(), String>,
+) -> Result<(), String> {
+    instance.state = if result.is_ok() {
+        InstanceState::Ready
+    } else {
+        InstanceState::Error
+    };
+    let json = serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?;
+    fs::write(meta_path, json).map_err(|e| e.to_string())?;
 
-        let json = serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?;
-        fs::write(&meta_path, json).map_err(|e| e.to_string())?;
+    crate::install::emit_install_finished(app, instance, result.as_ref().err().map(String::as_str));
+    result?;
+    crate::install::emit_install_phase(app, Some(&instance.id), crate::install::InstallPhase::Done);
+    app.emit("list_instances", ()).map_err(|e| e.to_string())?;
+    Ok(())
+}
 
-        // Emit loader installed event
+fn warn_if_server_pack(app: &AppHandle, instance_id: &str, index: &crate::modrinth::ModpackIndex) {
+    let sides = crate::modrinth::count_sides(index);
+    if sides.looks_like_server_pack() {
         let _ = app.emit(
-            "loader-installed",
-            LoaderInstalled {
-                instance_id: inst_id.clone(),
-                project_id: loader_info.loader_type.clone(),
-                version_id: actual_loader_version.clone(),
-                success: true,
+            "modpack-server-pack",
+            ServerPackWarning {
+                instance_id: instance_id.to_string(),
+                sides,
             },
         );
+    }
+}
 
-        let _ = app.emit(
-            "loader-install-complete",
-            format!(
-                "{} {} installed successfully",
-                loader_info.loader_type, actual_loader_version
-            ),
-        );
+/// The Minecraft version and loader a downloaded pack archive asks for. Only Modrinth
+/// packs can be installed; a CurseForge export (`manifest.json`) is named as such.
+fn read_downloaded_modpack(
+    path: &Path,
+) -> Result<(crate::modrinth::ModpackIndex, String, Option<LoaderInfo>), String> {
+    let file = fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file)
+        .map_err(|_| "The download is not a modpack archive".to_string())?;
+    if archive.by_name("modrinth.index.json").is_err() {
+        return Err(if archive.by_name("manifest.json").is_ok() {
+            "CurseForge modpacks are not supported yet".to_string()
+        } else {
+            "The archive has no modpack index (modrinth.index.json)".to_string()
+        });
     }
 
-    // Step 5: Extract modpack contents (mods, overrides)
-    if let Some(mrpack_path) = mrpack_path_opt {
-        let _ = app.emit("modpack-extract-started", &inst_id);
+    let index = crate::modrinth::parse_mrpack_index(path)
+        .map_err(|e| format!("Failed to parse modpack: {}", e))?;
+    let mc_version = index
+        .dependencies
+        .get("minecraft")
+        .cloned()
+        .ok_or("The modpack doesn't say which Minecraft version it needs")?;
+    let loader_info = extract_loader_from_dependencies(&index.dependencies)?;
+    if loader_info
+        .as_ref()
+        .is_some_and(|l| l.loader_type == "forge")
+    {
+        return Err("Forge modpacks are not supported yet".to_string());
+    }
+    Ok((index, mc_version, loader_info))
+}
 
-        match crate::modrinth::install_mrpack(&app, &inst_id, &mrpack_path).await {
-            Ok(_) => {
-                let _ = std::fs::remove_file(&mrpack_path);
-                let _ = app.emit("modpack-extract-complete", &inst_id);
-            }
-            Err(e) => {
-                let _ = std::fs::remove_file(&mrpack_path);
-                let _ = app.emit(
-                    "instance-install-error",
-                    format!("Failed to extract modpack: {}", e),
-                );
-                return Err(format!("Failed to extract modpack: {}", e));
-            }
+/// Install a modpack from a direct download link (a personal site, GitHub releases)
+/// rather than a Modrinth project. The file is checked to be a Modrinth pack before
+/// any instance is created.
+#[tauri::command]
+pub async fn install_modpack_from_url(
+    app: AppHandle,
+    name: String,
+    url: String,
+) -> Result<(), String> {
+    let name = validate_instance_name(&name)?;
+    let parsed = reqwest::Url::parse(url.trim()).map_err(|e| format!("Invalid URL: {}", e))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err("Only http and https links are supported".to_string());
+    }
+
+    let download = std::env::temp_dir().join(format!("{}.mrpack", uuid::Uuid::new_v4()));
+    let checked =
+        match crate::download::download_with_retry(parsed.as_str(), &download, None, 3).await {
+            Ok(()) => read_downloaded_modpack(&download),
+            Err(e) => Err(format!("Failed to download modpack: {}", e)),
+        };
+    let (index, mc_version, loader_info) = match checked {
+        Ok(checked) => checked,
+        Err(e) => {
+            let _ = fs::remove_file(&download);
+            return Err(e);
         }
-    } else {
-        // Fallback: download individual files (legacy modpack format)
-        let _ = app.emit("modpack-files-download-started", &inst_id);
-
-        for file in &version.files {
-            if !file.filename.to_lowercase().ends_with(".mrpack") {
-                let target = root.join(".minecraft").join("mods").join(&file.filename);
-                if let Some(parent) = target.parent() {
-                    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
-                }
+    };
 
-                match crate::download::download_to_file(&file.url, &target).await {
-                    Ok(_) => {
-                        let _ = app.emit("file-downloaded", &file.filename);
-                    }
-                    Err(e) => {
-                        let _ = app.emit(
-                            "instance-install-error",
-                            format!("Failed to download {}: {}", file.filename, e),
-                        );
-                        return Err(format!("Failed to download {}: {}", file.filename, e));
-                    }
-                }
-            }
+    let (mut instance, root) = match create_modpack_instance(&app, &name, &mc_version) {
+        Ok(created) => created,
+        Err(e) => {
+            let _ = fs::remove_file(&download);
+            return Err(e);
         }
+    };
+    let inst_id = instance.id.clone();
+    let meta_path = root.join("instance.json");
+    warn_if_server_pack(&app, &inst_id, &index);
+
+    let result: Result<(), String> = async {
+        install_modpack_base(&app, &mut instance, &meta_path, &mc_version, loader_info).await?;
 
-        let _ = app.emit("modpack-files-download-complete", &inst_id);
+        let _ = app.emit("modpack-extract-started", &inst_id);
+        crate::install::emit_install_phase(
+            &app,
+            Some(&inst_id),
+            crate::install::InstallPhase::DownloadingMods,
+        );
+        crate::modrinth::install_mrpack(&app, &inst_id, &download)
+            .await
+            .map_err(|e| format!("Failed to extract modpack: {}", e))?;
+        let _ = app.emit("modpack-extract-complete", &inst_id);
+        Ok(())
     }
+    .await;
+    let _ = fs::remove_file(&download);
 
-    // Step 6: Mark instance as ready
-    instance.state = InstanceState::Ready;
-    let json = serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?;
-    fs::write(&meta_path, json).map_err(|e| e.to_string())?;
+    finish_modpack_install(&app, &mut instance, &meta_path, result)
+}
 
-    let _ = app.emit("instance-install-complete", &inst_id);
-    app.emit("list_instances", ()).map_err(|e| e.to_string())?;
-    Ok(())
+/// Put a modpack instance back the way the pack shipped it (see `modrinth::reset_mrpack`).
+/// Saves are left alone.
+#[tauri::command]
+pub async fn reset_to_modpack_defaults(
+    app: AppHandle,
+    instance_id: String,
+    process_state: State<'_, ChildProcessState>,
+) -> Result<crate::modrinth::ModpackReset, String> {
+    if child_running(&process_state, &instance_id) {
+        return Err("Close the game before resetting the instance".to_string());
+    }
+    crate::modrinth::reset_mrpack(&app, &instance_id).await
 }
 
 // Helper struct for loader information
@@ -1803,13 +4107,281 @@ fn normalize_loader_type(loader: &str) -> String {
     }
 }
 
+/// Check an instance's loader install piece by piece: the derived version JSON and
+/// the loader and mapping libraries it declares.
+#[tauri::command]
+pub async fn verify_loader(
+    app: AppHandle,
+    instance_id: String,
+) -> Result<crate::loader::LoaderVerification, String> {
+    let root = instance_dir(&app, &instance_id)?;
+    let instance = read_instance(&root.join("instance.json"))?;
+    let Some(loader) = instance.loader.as_deref() else {
+        return Err("This instance has no mod loader".to_string());
+    };
+    let version_id = instance.launch_version_id();
+    let version_json = instance_version_file(&app, &root, &instance, &version_id, "json")?;
+    Ok(crate::loader::verify_loader_files(
+        &minecraft_root(&app)?,
+        &version_json,
+        loader,
+        &version_id,
+    ))
+}
+
+/// Throw away an instance's derived loader version and install the recorded loader again.
+#[tauri::command]
+pub async fn reinstall_loader(app: AppHandle, instance_id: String) -> Result<String, String> {
+    let meta_path = instance_meta_path(&app, &instance_id)?;
+    let mut instance = read_instance(&meta_path)?;
+
+    let loader_type = instance
+        .loader
+        .clone()
+        .ok_or("Instance has no mod loader to reinstall")?;
+    let loader_version = instance.loader_version.clone();
+    let mc_version = instance
+        .mc_version
+        .clone()
+        .unwrap_or(instance.version.clone());
+
+    // Derived version (what the launcher uses) plus any profile the installer jar left behind
+    let derived_dir = versions_root(&app)?.join(instance.launch_version_id());
+    if derived_dir.exists() {
+        fs::remove_dir_all(&derived_dir).map_err(|e| e.to_string())?;
+    }
+    if let Some(lv) = &loader_version {
+        let installer_profile = instance_dir(&app, &instance_id)?
+            .join(".minecraft")
+            .join("versions")
+            .join(crate::loader::derived_version_id(
+                &loader_type,
+                lv,
+                &mc_version,
+            ));
+        if installer_profile.exists() {
+            let _ = fs::remove_dir_all(&installer_profile);
+        }
+    }
+
+    instance.state = InstanceState::Installing;
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+    crate::install::emit_install_phase(
+        &app,
+        Some(&instance_id),
+        crate::install::InstallPhase::InstallingLoader {
+            loader: loader_type.clone(),
+        },
+    );
+
+    let result = install_loader_robust(
+        &app,
+        &loader_type,
+        &mc_version,
+        loader_version.as_deref(),
+        &instance_id,
+    )
+    .await
+    // The recorded version may be gone upstream; a pinned instance must not fall back
+    .and_then(|(derived, actual)| match &loader_version {
+        Some(pinned) if instance.pinned_version && &actual != pinned => Err(format!(
+            "{} {} is no longer available and {} is pinned to it",
+            loader_type, pinned, instance.name
+        )),
+        _ => Ok((derived, actual)),
+    });
+
+    let success = result.is_ok();
+    match &result {
+        Ok((_, actual_version)) => {
+            instance.loader_version = Some(actual_version.clone());
+            instance.state = InstanceState::Ready;
+        }
+        Err(_) => instance.state = InstanceState::Error,
+    }
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+
+    let _ = app.emit(
+        "loader-installed",
+        LoaderInstalled {
+            instance_id: instance_id.clone(),
+            project_id: loader_type.clone(),
+            version_id: instance.loader_version.clone().unwrap_or_default(),
+            success,
+        },
+    );
+    let _ = app.emit(
+        "loader-install-finished",
+        crate::loader::LoaderInstallFinished {
+            instance_id: instance_id.clone(),
+            success,
+        },
+    );
+
+    result.map(|(_, actual_version)| actual_version)
+}
+
+#[derive(Serialize)]
+pub struct LoaderChange {
+    pub loader: String,
+    pub loader_version: String,
+    /// Mod files whose metadata names a loader the new one can't run
+    pub incompatible_mods: Vec<String>,
+}
+
+/// Whether a mod built for `mod_loader` can run under `loader`.
+fn mod_loader_compatible(mod_loader: &str, loader: &str) -> bool {
+    // Quilt loads Fabric mods; NeoForge still reads Forge's mods.toml
+    mod_loader == loader
+        || (loader == "quilt" && mod_loader == "fabric")
+        || (loader == "neoforge" && mod_loader == "forge")
+}
+
+/// Switch an instance to a different mod loader in place. Mods, saves and config
+/// are left alone; mods that won't load under the new loader are reported back.
+#[tauri::command]
+pub async fn change_instance_loader(
+    app: AppHandle,
+    process_state: State<'_, ChildProcessState>,
+    instance_id: String,
+    new_loader_type: String,
+    loader_version: Option<String>,
+    delete_old_version: Option<bool>,
+) -> Result<LoaderChange, String> {
+    if child_running(&process_state, &instance_id) {
+        return Err("Close the game before changing its mod loader".to_string());
+    }
+    let meta_path = instance_meta_path(&app, &instance_id)?;
+    let mut instance = read_instance(&meta_path)?;
+    instance.ensure_unpinned("changing its mod loader")?;
+
+    let new_loader = new_loader_type.trim().to_lowercase();
+    if instance.loader.as_deref() == Some(new_loader.as_str()) && loader_version.is_none() {
+        return Err(format!("Instance already uses {}", new_loader));
+    }
+    let mc_version = instance
+        .mc_version
+        .clone()
+        .unwrap_or(instance.version.clone());
+    let old_version_id = instance.launch_version_id();
+    let previous_state = instance.state.clone();
+
+    instance.state = InstanceState::Installing;
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+    crate::install::emit_install_phase(
+        &app,
+        Some(&instance_id),
+        crate::install::InstallPhase::InstallingLoader {
+            loader: new_loader.clone(),
+        },
+    );
+
+    let result = install_loader_robust(
+        &app,
+        &new_loader,
+        &mc_version,
+        loader_version.as_deref(),
+        &instance_id,
+    )
+    .await;
+
+    let success = result.is_ok();
+    match &result {
+        Ok((_, actual_version)) => {
+            instance.loader = Some(new_loader.clone());
+            instance.loader_version = Some(actual_version.clone());
+            instance.mc_version = Some(mc_version.clone());
+            instance.state = InstanceState::Ready;
+        }
+        // The old loader is untouched, so the instance is still usable as before
+        Err(_) => instance.state = previous_state,
+    }
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+
+    let _ = app.emit(
+        "loader-installed",
+        LoaderInstalled {
+            instance_id: instance_id.clone(),
+            project_id: new_loader.clone(),
+            version_id: instance.loader_version.clone().unwrap_or_default(),
+            success,
+        },
+    );
+    let _ = app.emit(
+        "loader-install-finished",
+        crate::loader::LoaderInstallFinished {
+            instance_id: instance_id.clone(),
+            success,
+        },
+    );
+    let (_, actual_version) = result?;
+
+    // Only drop the previous derived version if no other instance still launches it
+    if delete_old_version.unwrap_or(false) && old_version_id != instance.version {
+        let in_use = list_instances(app.clone())
+            .await?
+            .iter()
+            .any(|i| i.launch_version_id() == old_version_id);
+        let old_dir = versions_root(&app)?.join(&old_version_id);
+        if !in_use && old_dir.exists() {
+            let _ = fs::remove_dir_all(&old_dir);
+        }
+    }
+
+    let mods_dir = instance_dir(&app, &instance_id)?
+        .join(".minecraft")
+        .join("mods");
+    let mut incompatible_mods = Vec::new();
+    if let Ok(entries) = fs::read_dir(&mods_dir) {
+        for path in entries.flatten().map(|e| e.path()) {
+            let name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("")
+                .to_string();
+            if !name.ends_with(".jar") {
+                continue;
+            }
+            let mod_loader = read_mod_metadata(&path).and_then(|info| info.loader);
+            if let Some(mod_loader) = mod_loader {
+                if !mod_loader_compatible(&mod_loader, &new_loader) {
+                    incompatible_mods.push(name);
+                }
+            }
+        }
+    }
+    incompatible_mods.sort();
+
+    Ok(LoaderChange {
+        loader: new_loader,
+        loader_version: actual_version,
+        incompatible_mods,
+    })
+}
+
 // Robust loader installation with proper error handling and verification
 async fn install_loader_robust(
     app: &AppHandle,
     loader_type: &str,
     mc_version: &str,
     requested_version: Option<&str>,
-    _instance_id: &str,
+    instance_id: &str,
 ) -> Result<(String, String), String> {
     // Get available loader versions
     let versions =
@@ -1853,8 +4425,11 @@ async fn install_loader_robust(
         versions[0].clone()
     };
 
-    let _ = app.emit(
-        "loader-install-progress",
+    crate::loader::emit_loader_progress(
+        app,
+        instance_id,
+        "installing",
+        20,
         format!("Installing {} {}", loader_type, target_version),
     );
 
@@ -1878,26 +4453,34 @@ async fn install_loader_robust(
 
     let verification_success = match loader_type {
         "fabric" => crate::loader::fabric_installed(&minecraft_root, mc_version, &actual_version),
+        "quilt" => crate::loader::quilt_installed(&minecraft_root, mc_version, &actual_version),
         _ => crate::loader::loader_verification(&minecraft_root, loader_type),
     };
 
     if !verification_success {
         // Try alternative versions if verification failed
-        let _ = app.emit(
-            "loader-install-progress",
+        crate::loader::emit_loader_progress(
+            app,
+            instance_id,
+            "retrying",
+            50,
             format!(
                 "Verification failed, trying alternative {} versions",
                 loader_type
             ),
         );
 
-        for alt_version in &versions {
+        for (i, alt_version) in versions.iter().enumerate() {
             if alt_version == &actual_version {
                 continue;
             }
 
-            let _ = app.emit(
-                "loader-install-progress",
+            // Alternatives share the 50-80 span so the bar never moves backwards
+            crate::loader::emit_loader_progress(
+                app,
+                instance_id,
+                "installing",
+                (50 + i * 30 / versions.len()) as u8,
                 format!("Trying {} {}", loader_type, alt_version),
             );
 
@@ -1916,12 +4499,20 @@ async fn install_loader_robust(
                             mc_version,
                             &alt_actual_version,
                         ),
+                        "quilt" => crate::loader::quilt_installed(
+                            &minecraft_root,
+                            mc_version,
+                            &alt_actual_version,
+                        ),
                         _ => crate::loader::loader_verification(&minecraft_root, loader_type),
                     };
 
                     if alt_success {
-                        let _ = app.emit(
-                            "loader-install-progress",
+                        crate::loader::emit_loader_progress(
+                            app,
+                            instance_id,
+                            "verifying",
+                            80,
                             format!(
                                 "Successfully installed {} {}",
                                 loader_type, alt_actual_version
@@ -1936,8 +4527,11 @@ async fn install_loader_robust(
 
         // If all versions failed verification, but we got a successful install_loader call,
         // let's be more lenient and just warn instead of failing completely
-        let _ = app.emit(
-            "loader-install-progress",
+        crate::loader::emit_loader_progress(
+            app,
+            instance_id,
+            "verifying",
+            80,
             format!(
                 "Warning: Could not verify {} installation, but installation appeared successful",
                 loader_type
@@ -1945,8 +4539,11 @@ async fn install_loader_robust(
         );
 
         // Return success anyway - the install_loader function succeeded, verification might just be overly strict
-        let _ = app.emit(
-            "loader-install-progress",
+        crate::loader::emit_loader_progress(
+            app,
+            instance_id,
+            "done",
+            100,
             format!(
                 "Proceeding with {} {} installation",
                 loader_type, actual_version
@@ -1955,24 +4552,18 @@ async fn install_loader_robust(
         return Ok((derived_id, actual_version));
     }
 
-    let _ = app.emit(
-        "loader-install-progress",
+    crate::loader::emit_loader_progress(
+        app,
+        instance_id,
+        "done",
+        100,
         format!("Successfully verified {} {}", loader_type, actual_version),
     );
     Ok((derived_id, actual_version))
 }
 
-#[tauri::command]
-pub async fn install_modrinth_mod(
-    app: AppHandle,
-    instance_id: String,
-    project_id: String,
-    version_id: Option<String>,
-) -> Result<(), String> {
-    let root = instance_dir(&app, &instance_id)?;
-    let meta_text = fs::read_to_string(root.join("instance.json")).map_err(|e| e.to_string())?;
-    let instance: Instance = serde_json::from_str(&meta_text).map_err(|e| e.to_string())?;
-
+/// Minecraft version and Modrinth loader an instance's mods have to match.
+fn instance_mod_target(instance: &Instance) -> (&str, crate::modrinth::ModLoader) {
     let mc_version = instance
         .mc_version
         .as_deref()
@@ -1986,36 +4577,390 @@ pub async fn install_modrinth_mod(
         "neoforge" => crate::modrinth::ModLoader::NeoForge,
         _ => crate::modrinth::ModLoader::Fabric,
     };
+    (mc_version, loader)
+}
+
+/// Fetch a version the user picked and check it fits the instance.
+async fn get_compatible_version(
+    instance: &Instance,
+    version_id: &str,
+) -> Result<crate::modrinth::ModrinthVersion, String> {
+    let v = crate::modrinth::get_version(version_id).await?;
+    check_mod_compatible(instance, &v)?;
+    Ok(v)
+}
+
+/// Error unless the version supports the instance's Minecraft version and loader.
+fn check_mod_compatible(
+    instance: &Instance,
+    v: &crate::modrinth::ModrinthVersion,
+) -> Result<(), String> {
+    let (mc_version, loader) = instance_mod_target(instance);
+    let compatible = v.game_versions.iter().any(|gv| gv == mc_version)
+        && v.loaders.iter().any(|l| l == loader.as_str());
+    if !compatible {
+        return Err(format!(
+            "Version {} is not compatible with Minecraft {} and loader {}",
+            v.id,
+            mc_version,
+            instance.loader.as_deref().unwrap_or("fabric")
+        ));
+    }
+    Ok(())
+}
+
+/// Modrinth project ids of the jars in an instance's mods folder, disabled ones
+/// included, found by file hash. Best effort: a failed lookup gives an empty set.
+async fn installed_mod_projects(root: &Path) -> HashSet<String> {
+    let mods_dir = root.join(".minecraft").join("mods");
+    let hashes = tokio::task::spawn_blocking(move || {
+        let Ok(entries) = fs::read_dir(&mods_dir) else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|path| {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                path.is_file() && (name.ends_with(".jar") || name.ends_with(".jar.disabled"))
+            })
+            .filter_map(|path| crate::download::sha1_file(&path).ok())
+            .collect::<Vec<_>>()
+    })
+    .await
+    .unwrap_or_default();
+    if hashes.is_empty() {
+        return HashSet::new();
+    }
+    match crate::modrinth::versions_for_hashes(&hashes).await {
+        Ok(versions) => versions.into_values().map(|v| v.project_id).collect(),
+        Err(e) => {
+            log::warn!("Could not identify installed mods: {}", e);
+            HashSet::new()
+        }
+    }
+}
+
+/// Download one of a version's files into the instance's mods folder.
+async fn download_mod_file(
+    root: &Path,
+    file: &crate::modrinth::ModrinthFile,
+) -> Result<(), String> {
+    let mods_dir = root.join(".minecraft").join("mods");
+    fs::create_dir_all(&mods_dir).map_err(|e| e.to_string())?;
+
+    let target = resolve_within(&mods_dir, &file.filename)?;
+    crate::download::download_with_retry(
+        &file.url,
+        &target,
+        file.expected_hash().as_ref(),
+        MOD_DOWNLOAD_ATTEMPTS,
+    )
+    .await
+    .map_err(|e| format!("Failed to download {}: {}", file.filename, e))
+}
+
+#[tauri::command]
+pub async fn install_modrinth_mod(
+    app: AppHandle,
+    instance_id: String,
+    project_id: String,
+    version_id: Option<String>,
+) -> Result<(), String> {
+    let root = instance_dir(&app, &instance_id)?;
+    let instance = read_instance(&root.join("instance.json"))?;
 
     let version: crate::modrinth::ModrinthVersion = if let Some(vid) = version_id {
-        let v = crate::modrinth::get_version(&vid).await?;
+        let v = get_compatible_version(&instance, &vid).await?;
         if v.project_id != project_id {
             return Err(format!(
                 "Version {} does not belong to project {}",
                 vid, project_id
             ));
         }
-        let compatible = v.game_versions.iter().any(|gv| gv == mc_version)
-            && v.loaders.iter().any(|l| l == loader.as_str());
-        if !compatible {
-            return Err(format!(
-                "Version {} is not compatible with Minecraft {} and loader {}",
-                vid, mc_version, loader_str
-            ));
-        }
         v
     } else {
+        let (mc_version, loader) = instance_mod_target(&instance);
         crate::modrinth::resolve_mod_version(&project_id, mc_version, loader).await?
     };
 
+    let file = crate::modrinth::select_primary_file(&version)?;
+    download_mod_file(&root, file).await
+}
+
+/// Install one named file from a version, for projects that publish several
+/// (separate client/server jars, bundled sub-mods). `install_modrinth_mod`
+/// picks the primary file on its own; this is for when the user chose another.
+#[tauri::command]
+pub async fn install_modrinth_file(
+    app: AppHandle,
+    instance_id: String,
+    version_id: String,
+    filename: String,
+) -> Result<(), String> {
+    let root = instance_dir(&app, &instance_id)?;
+    let instance = read_instance(&root.join("instance.json"))?;
+
+    let version = get_compatible_version(&instance, &version_id).await?;
+    let file = version
+        .files
+        .iter()
+        .find(|f| f.filename == filename)
+        .ok_or_else(|| format!("Version {} has no file named {}", version_id, filename))?;
+    download_mod_file(&root, file).await
+}
+
+/// Tries at a mod jar before giving up; a corrupt transfer counts as a failed try.
+const MOD_DOWNLOAD_ATTEMPTS: u32 = 3;
+
+/// How many mod jars `install_mods` downloads at once
+const MOD_INSTALL_CONCURRENCY: usize = 4;
+
+#[derive(Serialize, Clone)]
+pub struct ModsInstallProgress {
+    pub done: usize,
+    pub total: usize,
+    /// File name of the mod that just finished
+    pub current: String,
+}
+
+#[derive(Serialize)]
+pub struct ModInstallResult {
+    pub version_id: String,
+    pub project_id: Option<String>,
+    pub file_name: Option<String>,
+    /// Pulled in as a required dependency rather than asked for
+    pub dependency: bool,
+    pub error: Option<String>,
+}
+
+impl ModInstallResult {
+    fn failed(
+        version_id: String,
+        project_id: Option<String>,
+        dependency: bool,
+        error: String,
+    ) -> Self {
+        ModInstallResult {
+            version_id,
+            project_id,
+            file_name: None,
+            dependency,
+            error: Some(error),
+        }
+    }
+}
+
+/// Install several Modrinth versions into an instance in one go, along with their
+/// required dependencies. One mod failing doesn't stop the others; every requested
+/// version and added dependency gets an entry in the returned list.
+#[tauri::command]
+pub async fn install_mods(
+    app: AppHandle,
+    instance_id: String,
+    version_ids: Vec<String>,
+) -> Result<Vec<ModInstallResult>, String> {
+    let root = instance_dir(&app, &instance_id)?;
+    let instance = read_instance(&root.join("instance.json"))?;
+    let (mc_version, loader) = instance_mod_target(&instance);
+
+    let mut results = Vec::new();
+    let mut plan: Vec<(crate::modrinth::ModrinthVersion, bool)> = Vec::new();
+    let mut projects: HashSet<String> = HashSet::new();
+
+    let requested = crate::modrinth::get_versions_batch(&version_ids).await?;
+    for vid in &version_ids {
+        let Some(v) = requested.iter().find(|v| &v.id == vid) else {
+            results.push(ModInstallResult::failed(
+                vid.clone(),
+                None,
+                false,
+                "Version not found".to_string(),
+            ));
+            continue;
+        };
+        if let Err(e) = check_mod_compatible(&instance, v) {
+            results.push(ModInstallResult::failed(
+                vid.clone(),
+                Some(v.project_id.clone()),
+                false,
+                e,
+            ));
+        } else if projects.insert(v.project_id.clone()) {
+            plan.push((v.clone(), false));
+        }
+    }
+
+    // Mods already in the folder (enabled or not) satisfy a dependency as they are;
+    // a second jar of the same mod would stop the game from starting
+    let installed = installed_mod_projects(&root).await;
+
+    // Walk required dependencies breadth-first; a project already in the plan
+    // (asked for, or pulled in by an earlier mod) is never added twice
+    let mut next = 0;
+    while next < plan.len() {
+        let deps: Vec<crate::modrinth::ModrinthDependency> = plan[next]
+            .0
+            .dependencies
+            .iter()
+            .filter(|d| d.dependency_type == "required")
+            .cloned()
+            .collect();
+        next += 1;
+        for dep in deps {
+            if dep
+                .project_id
+                .as_ref()
+                .is_some_and(|p| projects.contains(p) || installed.contains(p))
+            {
+                continue;
+            }
+            let resolved = match (&dep.version_id, &dep.project_id) {
+                (Some(vid), _) => crate::modrinth::get_version(vid).await,
+                (None, Some(pid)) => {
+                    crate::modrinth::resolve_mod_version(pid, mc_version, loader).await
+                }
+                (None, None) => continue,
+            };
+            match resolved {
+                Ok(v) => {
+                    if !installed.contains(&v.project_id) && projects.insert(v.project_id.clone()) {
+                        plan.push((v, true));
+                    }
+                }
+                Err(e) => {
+                    if let Some(pid) = &dep.project_id {
+                        projects.insert(pid.clone());
+                    }
+                    results.push(ModInstallResult::failed(
+                        dep.version_id.clone().unwrap_or_default(),
+                        dep.project_id.clone(),
+                        true,
+                        format!("Could not resolve dependency: {}", e),
+                    ));
+                }
+            }
+        }
+    }
+
+    let total = plan.len();
+    let mut pending = plan.into_iter();
+    let spawn = |(version, dependency): (crate::modrinth::ModrinthVersion, bool)| {
+        let root = &root;
+        async move {
+            let outcome = match crate::modrinth::select_primary_file(&version) {
+                Ok(file) => download_mod_file(root, file)
+                    .await
+                    .map(|_| file.filename.clone()),
+                Err(e) => Err(e),
+            };
+            let (file_name, error) = match outcome {
+                Ok(name) => (Some(name), None),
+                Err(e) => (None, Some(e)),
+            };
+            ModInstallResult {
+                version_id: version.id,
+                project_id: Some(version.project_id),
+                file_name,
+                dependency,
+                error,
+            }
+        }
+    };
+
+    let mut in_flight = FuturesUnordered::new();
+    for entry in pending.by_ref().take(MOD_INSTALL_CONCURRENCY) {
+        in_flight.push(spawn(entry));
+    }
+    let mut done = 0;
+    while let Some(result) = in_flight.next().await {
+        if let Some(entry) = pending.next() {
+            in_flight.push(spawn(entry));
+        }
+        done += 1;
+        let _ = app.emit(
+            "mods-install-progress",
+            ModsInstallProgress {
+                done,
+                total,
+                current: result
+                    .file_name
+                    .clone()
+                    .unwrap_or_else(|| result.version_id.clone()),
+            },
+        );
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+/// Fabric API's Modrinth project id (slug `fabric-api`)
+const FABRIC_API_PROJECT: &str = "P7dR8mSH";
+
+#[derive(Serialize)]
+pub struct FabricApiStatus {
+    /// `true` if it was already in the mods folder, `false` if this call added it
+    pub already_installed: bool,
+    pub file_name: String,
+    pub version: String,
+}
+
+/// Make sure a Fabric instance has Fabric API, installing the newest build for its
+/// Minecraft version when no jar in the mods folder provides it.
+#[tauri::command]
+pub async fn ensure_fabric_api(
+    app: AppHandle,
+    instance_id: String,
+) -> Result<FabricApiStatus, String> {
+    let root = instance_dir(&app, &instance_id)?;
+    let instance = read_instance(&root.join("instance.json"))?;
+    if !instance
+        .loader
+        .as_deref()
+        .is_some_and(|l| l.eq_ignore_ascii_case("fabric"))
+    {
+        return Err("Fabric API is only for Fabric instances".to_string());
+    }
+
     let mods_dir = root.join(".minecraft").join("mods");
-    fs::create_dir_all(&mods_dir).map_err(|e| e.to_string())?;
+    if let Ok(entries) = fs::read_dir(&mods_dir) {
+        for path in entries.flatten().map(|e| e.path()) {
+            if path.extension() != Some(std::ffi::OsStr::new("jar")) {
+                continue;
+            }
+            let Some(info) = read_mod_metadata(&path) else {
+                continue;
+            };
+            // Releases before 0.19 (1.14) used the id `fabric`
+            if matches!(info.id.as_deref(), Some("fabric-api") | Some("fabric")) {
+                return Ok(FabricApiStatus {
+                    already_installed: true,
+                    file_name: path
+                        .file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_default(),
+                    version: info.version,
+                });
+            }
+        }
+    }
 
+    let mc_version = instance.mc_version.as_deref().unwrap_or(&instance.version);
+    let version = crate::modrinth::resolve_mod_version(
+        FABRIC_API_PROJECT,
+        mc_version,
+        crate::modrinth::ModLoader::Fabric,
+    )
+    .await?;
     let file = crate::modrinth::select_primary_file(&version)?;
-    let target = mods_dir.join(&file.filename);
-    crate::download::download_to_file(&file.url, &target).await?;
+    download_mod_file(&root, file).await?;
 
-    Ok(())
+    Ok(FabricApiStatus {
+        already_installed: false,
+        file_name: file.filename.clone(),
+        version: version.version_number.clone(),
+    })
 }
 
 // --- Mods / Screenshots / Worlds / Servers (instance managers) ---
@@ -2024,6 +4969,7 @@ pub async fn install_modrinth_mod(
 pub struct ModFileEntry {
     pub name: String,
     pub size_bytes: u64,
+    pub info: ModInfo,
 }
 
 #[tauri::command]
@@ -2046,13 +4992,230 @@ pub async fn list_instance_mods(
                 .unwrap_or("")
                 .to_string();
             let size_bytes = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
-            entries.push(ModFileEntry { name, size_bytes });
+            let info = extract_mod_info(&path).await?;
+            entries.push(ModFileEntry {
+                name,
+                size_bytes,
+                info,
+            });
         }
     }
     entries.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
     Ok(entries)
 }
 
+#[derive(Serialize, Clone)]
+pub struct ModConflictFile {
+    pub file_name: String,
+    pub version: String,
+    pub sha1: String,
+    /// Unix seconds
+    pub modified: u64,
+}
+
+#[derive(Serialize)]
+pub struct ModConflict {
+    /// "duplicate_id" (same mod id, different jars) or "identical" (same SHA1)
+    pub kind: String,
+    pub mod_id: Option<String>,
+    pub name: String,
+    /// Newest first; `files[0]` is the one to keep
+    pub files: Vec<ModConflictFile>,
+}
+
+/// Find mods that are installed more than once: jars sharing a mod id, and byte-identical
+/// copies. Disabled jars don't load, so they're ignored.
+#[tauri::command]
+pub async fn detect_mod_conflicts(
+    app: AppHandle,
+    instance_id: String,
+) -> Result<Vec<ModConflict>, String> {
+    let mods_dir = instance_dir(&app, &instance_id)?
+        .join(".minecraft")
+        .join("mods");
+    if !mods_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut mods: Vec<(ModInfo, ModConflictFile)> = Vec::new();
+    for path in fs::read_dir(&mods_dir)
+        .map_err(|e| e.to_string())?
+        .flatten()
+        .map(|e| e.path())
+    {
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("")
+            .to_string();
+        if !path.is_file() || !file_name.ends_with(".jar") {
+            continue;
+        }
+        let info = extract_mod_info(&path).await?;
+        let modified = fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let sha1 = crate::download::sha1_file(&path).map_err(|e| e.to_string())?;
+        let file = ModConflictFile {
+            file_name,
+            version: info.version.clone(),
+            sha1,
+            modified,
+        };
+        mods.push((info, file));
+    }
+
+    let newest_first = |files: &mut Vec<ModConflictFile>| {
+        files.sort_by(|a, b| {
+            crate::version_cmp::compare(&b.version, &a.version).then(b.modified.cmp(&a.modified))
+        });
+    };
+    let mut conflicts = Vec::new();
+
+    let mut by_sha1: HashMap<&str, Vec<&(ModInfo, ModConflictFile)>> = HashMap::new();
+    for m in &mods {
+        by_sha1.entry(m.1.sha1.as_str()).or_default().push(m);
+    }
+    for group in by_sha1.values().filter(|g| g.len() > 1) {
+        let mut files: Vec<ModConflictFile> = group.iter().map(|m| m.1.clone()).collect();
+        newest_first(&mut files);
+        conflicts.push(ModConflict {
+            kind: "identical".to_string(),
+            mod_id: group[0].0.id.clone(),
+            name: group[0].0.name.clone(),
+            files,
+        });
+    }
+
+    let mut by_id: HashMap<&str, Vec<&(ModInfo, ModConflictFile)>> = HashMap::new();
+    for m in &mods {
+        if let Some(id) = m.0.id.as_deref() {
+            by_id.entry(id).or_default().push(m);
+        }
+    }
+    for (id, group) in by_id.iter().filter(|(_, g)| g.len() > 1) {
+        // All copies byte-identical: already reported above
+        if group.iter().all(|m| m.1.sha1 == group[0].1.sha1) {
+            continue;
+        }
+        let mut files: Vec<ModConflictFile> = group.iter().map(|m| m.1.clone()).collect();
+        newest_first(&mut files);
+        conflicts.push(ModConflict {
+            kind: "duplicate_id".to_string(),
+            mod_id: Some(id.to_string()),
+            name: group[0].0.name.clone(),
+            files,
+        });
+    }
+
+    conflicts.sort_by_key(|c| c.name.to_lowercase());
+    Ok(conflicts)
+}
+
+/// Mods that render or enable shader packs. OptiFine has no mod metadata, so it's matched by file name.
+const SHADER_MODS: &[&str] = &[
+    "iris",
+    "oculus",
+    "sodium",
+    "embeddium",
+    "optifine",
+    "optifabric",
+];
+
+#[derive(Serialize)]
+pub struct ShaderMod {
+    pub id: String,
+    pub file_name: String,
+    pub version: String,
+}
+
+#[derive(Serialize)]
+pub struct ShaderSupport {
+    /// Iris, Oculus or OptiFine is installed and enabled
+    pub shader_capable: bool,
+    pub installed: Vec<ShaderMod>,
+    /// Modrinth project to offer when no shader loader is present ("iris" or "oculus")
+    pub suggested_project: Option<String>,
+    /// Its newest version for this instance's game version and loader, if there is one
+    pub suggested_version_id: Option<String>,
+}
+
+/// Report whether the instance can run shader packs, and which shader mod to install if not.
+#[tauri::command]
+pub async fn get_shader_support(
+    app: AppHandle,
+    instance_id: String,
+) -> Result<ShaderSupport, String> {
+    let root = instance_dir(&app, &instance_id)?;
+    let instance = read_instance(&root.join("instance.json"))?;
+
+    let mut installed = Vec::new();
+    if let Ok(entries) = fs::read_dir(root.join(".minecraft").join("mods")) {
+        for path in entries.flatten().map(|e| e.path()) {
+            let file_name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("")
+                .to_string();
+            if !file_name.ends_with(".jar") {
+                continue;
+            }
+            let info = read_mod_metadata(&path);
+            let lower = file_name.to_lowercase();
+            let id = info
+                .as_ref()
+                .and_then(|i| i.id.clone())
+                .filter(|id| SHADER_MODS.contains(&id.as_str()))
+                .or_else(|| {
+                    ["optifine", "optifabric"]
+                        .into_iter()
+                        .find(|name| lower.contains(name))
+                        .map(str::to_string)
+                });
+            if let Some(id) = id {
+                installed.push(ShaderMod {
+                    id,
+                    file_name,
+                    version: info.map(|i| i.version).unwrap_or_default(),
+                });
+            }
+        }
+    }
+
+    let shader_capable = installed
+        .iter()
+        .any(|m| matches!(m.id.as_str(), "iris" | "oculus" | "optifine"));
+    let loader = instance.loader.as_deref().map(str::to_lowercase);
+    let suggested_project = match loader.as_deref() {
+        _ if shader_capable => None,
+        Some("fabric") | Some("quilt") | Some("neoforge") => Some("iris"),
+        Some("forge") => Some("oculus"),
+        _ => None,
+    };
+
+    let mut suggested_version_id = None;
+    if let (Some(project), Some(loader)) = (suggested_project, loader.as_deref()) {
+        let mc_version = instance.mc_version.as_deref().unwrap_or(&instance.version);
+        // Best-effort: offline or no build for this version just means no suggestion
+        suggested_version_id =
+            crate::modrinth::get_filtered_project_versions(project, mc_version, loader)
+                .await
+                .ok()
+                .and_then(|v| v.into_iter().next())
+                .map(|v| v.id);
+    }
+
+    Ok(ShaderSupport {
+        shader_capable,
+        installed,
+        suggested_project: suggested_project.map(str::to_string),
+        suggested_version_id,
+    })
+}
+
 #[tauri::command]
 pub async fn remove_mod(
     app: AppHandle,
@@ -2075,6 +5238,22 @@ pub async fn remove_mod(
 pub struct ScreenshotEntry {
     pub name: String,
     pub path: String,
+    pub size_bytes: u64,
+    pub modified: u64,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+/// Read width/height from a PNG's IHDR chunk without decoding the image.
+fn png_dimensions(path: &std::path::Path) -> Option<(u32, u32)> {
+    let mut header = [0u8; 24];
+    fs::File::open(path).ok()?.read_exact(&mut header).ok()?;
+    if &header[..8] != b"\x89PNG\r\n\x1a\n" || &header[12..16] != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(header[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(header[20..24].try_into().ok()?);
+    Some((width, height))
 }
 
 #[tauri::command]
@@ -2100,28 +5279,115 @@ pub async fn list_instance_screenshots(
                 .unwrap_or("")
                 .to_string();
             let path_str = path.to_string_lossy().to_string();
+            let meta = entry.metadata().ok();
+            let size_bytes = meta.as_ref().map(|m| m.len()).unwrap_or(0);
+            let modified = meta
+                .and_then(|m| m.modified().ok())
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let dims = png_dimensions(&path);
             entries.push(ScreenshotEntry {
                 name,
                 path: path_str,
+                size_bytes,
+                modified,
+                width: dims.map(|d| d.0),
+                height: dims.map(|d| d.1),
             });
         }
     }
-    entries.sort_by(|a, b| b.name.cmp(&a.name));
+    // Newest first; names are timestamps too, so use them to break ties
+    entries.sort_by(|a, b| b.modified.cmp(&a.modified).then(b.name.cmp(&a.name)));
     Ok(entries)
 }
 
+#[tauri::command]
+pub async fn delete_screenshot(
+    app: AppHandle,
+    instance_id: String,
+    filename: String,
+) -> Result<(), String> {
+    let root = instance_dir(&app, &instance_id)?;
+    let screenshots_dir = root.join(".minecraft").join("screenshots");
+    let path = screenshots_dir.join(&filename);
+    if filename.contains('/') || filename.contains('\\') || !path.starts_with(&screenshots_dir) {
+        return Err("Invalid path".to_string());
+    }
+    if path.exists() {
+        fs::remove_file(&path).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
 #[derive(serde::Serialize)]
-pub struct WorldEntry {
+pub struct WorldInfo {
     pub name: String,
     pub folder: String,
     pub path: String,
+    /// Unix milliseconds, from level.dat
+    pub last_played: Option<i64>,
+    /// "survival", "creative", "adventure", "spectator" or "hardcore"
+    pub game_mode: Option<String>,
+    pub icon: Option<String>,
+}
+
+fn game_mode_name(game_type: i64, hardcore: bool) -> Option<String> {
+    if hardcore {
+        return Some("hardcore".to_string());
+    }
+    let name = match game_type {
+        0 => "survival",
+        1 => "creative",
+        2 => "adventure",
+        3 => "spectator",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+fn read_world_info(path: &std::path::Path) -> WorldInfo {
+    let folder = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("")
+        .to_string();
+    let icon = path.join("icon.png");
+    let mut info = WorldInfo {
+        name: folder.clone(),
+        folder,
+        path: path.to_string_lossy().to_string(),
+        last_played: None,
+        game_mode: None,
+        icon: icon.is_file().then(|| icon.to_string_lossy().to_string()),
+    };
+
+    // An unreadable level.dat still lists the folder, just without details
+    let level = crate::nbt::read_file(&path.join("level.dat"));
+    if let Some(data) = level.as_ref().ok().and_then(|t| t.get("Data")) {
+        if let Some(name) = data.get("LevelName").and_then(|t| t.as_str()) {
+            if !name.trim().is_empty() {
+                info.name = name.to_string();
+            }
+        }
+        info.last_played = data.get("LastPlayed").and_then(|t| t.as_i64());
+        let hardcore = data
+            .get("hardcore")
+            .and_then(|t| t.as_i64())
+            .is_some_and(|v| v != 0);
+        info.game_mode = data
+            .get("GameType")
+            .and_then(|t| t.as_i64())
+            .and_then(|g| game_mode_name(g, hardcore));
+    }
+    info
 }
 
 #[tauri::command]
 pub async fn list_instance_worlds(
     app: AppHandle,
     instance_id: String,
-) -> Result<Vec<WorldEntry>, String> {
+) -> Result<Vec<WorldInfo>, String> {
     let root = instance_dir(&app, &instance_id)?;
     let saves_dir = root.join(".minecraft").join("saves");
     if !saves_dir.exists() {
@@ -2134,22 +5400,323 @@ pub async fn list_instance_worlds(
     {
         let path = entry.path();
         if path.is_dir() {
-            let folder = path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("")
-                .to_string();
-            entries.push(WorldEntry {
-                name: folder.clone(),
-                folder: folder.clone(),
-                path: path.to_string_lossy().to_string(),
-            });
+            entries.push(read_world_info(&path));
         }
     }
-    entries.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    // Most recently played first; worlds without level.dat info go last, by name
+    entries.sort_by(|a, b| {
+        b.last_played
+            .cmp(&a.last_played)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
     Ok(entries)
 }
 
+/// Totals over every player's `saves/<world>/stats/<uuid>.json`.
+#[derive(Serialize, Default)]
+pub struct WorldStats {
+    pub world: String,
+    /// Stats files read; 0 for a world nobody has played long enough to save stats
+    pub players: u32,
+    pub play_time_ticks: u64,
+    /// Walking and sprinting, in centimetres
+    pub distance_walked_cm: u64,
+    pub mobs_killed: u64,
+    pub deaths: u64,
+}
+
+/// A custom stat from a stats file: 1.13+ nests them under `stats."minecraft:custom"`,
+/// older versions keep flat `stat.*` keys. The first of `keys` present wins.
+fn custom_stat(json: &serde_json::Value, keys: &[&str], legacy: &str) -> u64 {
+    let custom = &json["stats"]["minecraft:custom"];
+    keys.iter()
+        .find_map(|k| custom[format!("minecraft:{}", k)].as_u64())
+        .or_else(|| json[legacy].as_u64())
+        .unwrap_or(0)
+}
+
+/// A world is a single folder under `saves/`; `.` and `..` would name `saves/` itself
+/// or the game directory.
+fn validate_world_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+        return Err(format!("Invalid world folder name: {}", name));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_world_stats(
+    app: AppHandle,
+    instance_id: String,
+    world_name: String,
+) -> Result<WorldStats, String> {
+    validate_world_name(&world_name)?;
+    let world_dir = instance_dir(&app, &instance_id)?
+        .join(".minecraft")
+        .join("saves")
+        .join(&world_name);
+    if !world_dir.is_dir() {
+        return Err(format!("World not found: {}", world_name));
+    }
+
+    let mut stats = WorldStats {
+        world: world_name,
+        ..Default::default()
+    };
+    let Ok(entries) = fs::read_dir(world_dir.join("stats")) else {
+        return Ok(stats);
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let Some(json) = fs::read_to_string(&path)
+            .ok()
+            .and_then(|t| serde_json::from_str::<serde_json::Value>(&t).ok())
+        else {
+            log::warn!("Skipping unreadable stats file {}", path.display());
+            continue;
+        };
+        stats.players += 1;
+        // Renamed from play_one_minute in 1.17; it always counted ticks
+        stats.play_time_ticks += custom_stat(
+            &json,
+            &["play_time", "play_one_minute"],
+            "stat.playOneMinute",
+        );
+        stats.distance_walked_cm += custom_stat(&json, &["walk_one_cm"], "stat.walkOneCm")
+            + custom_stat(&json, &["sprint_one_cm"], "stat.sprintOneCm");
+        stats.mobs_killed += custom_stat(&json, &["mob_kills"], "stat.mobKills");
+        stats.deaths += custom_stat(&json, &["deaths"], "stat.deaths");
+    }
+    Ok(stats)
+}
+
+// --- World Backups ---
+
+#[derive(serde::Serialize, Clone)]
+pub struct WorldBackup {
+    pub file_name: String,
+    pub world: String,
+    pub size_bytes: u64,
+    /// Unix seconds
+    pub created: u64,
+}
+
+fn ensure_not_running(
+    process_state: &State<'_, ChildProcessState>,
+    instance_id: &str,
+) -> Result<(), String> {
+    if child_running(process_state, instance_id) {
+        return Err("Close the game before backing up or restoring worlds".to_string());
+    }
+    Ok(())
+}
+
+/// Zip everything under `dir` as `<prefix><rel>/<name>`, counting files in `files`.
+/// `exclude` gets each entry's `/`-separated path relative to the top `dir` and whether
+/// it is a directory; an excluded directory is skipped with everything in it.
+fn zip_dir(
+    zip: &mut zip::ZipWriter<fs::File>,
+    dir: &Path,
+    rel: &str,
+    prefix: &str,
+    exclude: Option<&dyn Fn(&str, bool) -> bool>,
+    files: &mut u32,
+) -> Result<(), String> {
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    for entry in fs::read_dir(dir).map_err(|e| e.to_string())?.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        let rel = if rel.is_empty() {
+            name
+        } else {
+            format!("{}/{}", rel, name)
+        };
+        let is_dir = path.is_dir();
+        if exclude.is_some_and(|exclude| exclude(&rel, is_dir)) {
+            continue;
+        }
+        if is_dir {
+            zip.add_directory(format!("{}{}", prefix, rel), options)
+                .map_err(|e| e.to_string())?;
+            zip_dir(zip, &path, &rel, prefix, exclude, files)?;
+        } else {
+            zip.start_file(format!("{}{}", prefix, rel), options)
+                .map_err(|e| e.to_string())?;
+            let mut f = fs::File::open(&path).map_err(|e| e.to_string())?;
+            std::io::copy(&mut f, zip).map_err(|e| e.to_string())?;
+            *files += 1;
+        }
+    }
+    Ok(())
+}
+
+/// Zip `.minecraft/saves/<world>` into `backups/<world>-<timestamp>.zip` in the instance dir.
+#[tauri::command]
+pub async fn backup_world(
+    app: AppHandle,
+    instance_id: String,
+    world_name: String,
+    process_state: State<'_, ChildProcessState>,
+) -> Result<WorldBackup, String> {
+    ensure_not_running(&process_state, &instance_id)?;
+
+    let root = instance_dir(&app, &instance_id)?;
+    validate_world_name(&world_name)?;
+    let world_dir = root.join(".minecraft").join("saves").join(&world_name);
+    if !world_dir.is_dir() {
+        return Err(format!("World not found: {}", world_name));
+    }
+
+    let backups_dir = root.join("backups");
+    fs::create_dir_all(&backups_dir).map_err(|e| e.to_string())?;
+    let file_name = format!(
+        "{}-{}.zip",
+        world_name,
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    let backup_path = backups_dir.join(&file_name);
+
+    let file = fs::File::create(&backup_path).map_err(|e| e.to_string())?;
+    let mut zip = zip::ZipWriter::new(file);
+    // session.lock is held by the game and meaningless in a backup
+    let skip_lock =
+        |rel: &str, is_dir: bool| !is_dir && rel.rsplit('/').next() == Some("session.lock");
+    let result = zip_dir(
+        &mut zip,
+        &world_dir,
+        "",
+        &format!("{}/", world_name),
+        Some(&skip_lock),
+        &mut 0,
+    )
+    .and_then(|_| zip.finish().map(|_| ()).map_err(|e| e.to_string()));
+    if let Err(e) = result {
+        let _ = fs::remove_file(&backup_path);
+        return Err(format!("Failed to back up {}: {}", world_name, e));
+    }
+
+    Ok(WorldBackup {
+        file_name,
+        world: world_name,
+        size_bytes: fs::metadata(&backup_path).map(|m| m.len()).unwrap_or(0),
+        created: chrono::Utc::now().timestamp() as u64,
+    })
+}
+
+#[tauri::command]
+pub async fn list_world_backups(
+    app: AppHandle,
+    instance_id: String,
+) -> Result<Vec<WorldBackup>, String> {
+    let backups_dir = instance_dir(&app, &instance_id)?.join("backups");
+    if !backups_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(backups_dir)
+        .map_err(|e| e.to_string())?
+        .flatten()
+    {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some(stem) = file_name.strip_suffix(".zip") else {
+            continue;
+        };
+        let meta = match entry.metadata() {
+            Ok(m) if m.is_file() => m,
+            _ => continue,
+        };
+        // <world>-YYYYmmdd-HHMMSS
+        let world = stem.rsplitn(3, '-').nth(2).unwrap_or(stem).to_string();
+        let created = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        backups.push(WorldBackup {
+            file_name,
+            world,
+            size_bytes: meta.len(),
+            created,
+        });
+    }
+    backups.sort_by_key(|b| std::cmp::Reverse(b.created));
+    Ok(backups)
+}
+
+/// Extract a backup into saves under a fresh folder name, never over an existing world.
+/// Returns the folder name the world was restored to.
+#[tauri::command]
+pub async fn restore_world_backup(
+    app: AppHandle,
+    instance_id: String,
+    backup_file: String,
+    process_state: State<'_, ChildProcessState>,
+) -> Result<String, String> {
+    ensure_not_running(&process_state, &instance_id)?;
+
+    let root = instance_dir(&app, &instance_id)?;
+    if backup_file.contains(['/', '\\']) || backup_file.contains("..") {
+        return Err("Invalid backup file name".to_string());
+    }
+    let backup_path = root.join("backups").join(&backup_file);
+    if !backup_path.is_file() {
+        return Err(format!("Backup not found: {}", backup_file));
+    }
+
+    let file = fs::File::open(&backup_path).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+
+    // Backups hold a single top-level world folder
+    let world = archive
+        .file_names()
+        .next()
+        .and_then(|n| n.split('/').next())
+        .filter(|n| !n.is_empty())
+        .ok_or("Backup is empty")?
+        .to_string();
+
+    let saves_dir = root.join(".minecraft").join("saves");
+    fs::create_dir_all(&saves_dir).map_err(|e| e.to_string())?;
+    let target_name = (1..=9999)
+        .map(|n| {
+            if n == 1 {
+                format!("{} (restored)", world)
+            } else {
+                format!("{} (restored {})", world, n)
+            }
+        })
+        .find(|name| !saves_dir.join(name).exists())
+        .ok_or("Could not find a free folder name to restore into")?;
+    let target = saves_dir.join(&target_name);
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(|e| e.to_string())?;
+        let Some(rel) = entry.enclosed_name() else {
+            continue;
+        };
+        let Ok(rel) = rel.strip_prefix(&world) else {
+            continue;
+        };
+        let out = target.join(rel);
+        if entry.is_dir() {
+            fs::create_dir_all(&out).map_err(|e| e.to_string())?;
+        } else {
+            if let Some(parent) = out.parent() {
+                fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            let mut f = fs::File::create(&out).map_err(|e| e.to_string())?;
+            std::io::copy(&mut entry, &mut f).map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(target_name)
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
 pub struct ServerEntry {
     pub name: String,
@@ -2157,14 +5724,122 @@ pub struct ServerEntry {
     pub icon: Option<String>,
 }
 
-#[tauri::command]
-pub async fn list_instance_servers(
-    _app: AppHandle,
-    instance_id: String,
-) -> Result<Vec<ServerEntry>, String> {
-    let _ = instance_id;
-    // servers.dat is NBT format; full parser can be added later
-    Ok(Vec::new())
+#[tauri::command]
+pub async fn list_instance_servers(
+    app: AppHandle,
+    instance_id: String,
+) -> Result<Vec<ServerEntry>, String> {
+    let root = instance_dir(&app, &instance_id)?;
+    let path = root.join(".minecraft").join("servers.dat");
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let nbt = crate::nbt::read_file(&path)?;
+    let servers = nbt
+        .get("servers")
+        .and_then(|t| t.as_list())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|s| {
+            Some(ServerEntry {
+                name: s
+                    .get("name")
+                    .and_then(|t| t.as_str())
+                    .unwrap_or("Minecraft Server")
+                    .to_string(),
+                ip: s.get("ip")?.as_str()?.to_string(),
+                // Base64 PNG, as stored by the game
+                icon: s.get("icon").and_then(|t| t.as_str()).map(str::to_string),
+            })
+        })
+        .collect();
+    Ok(servers)
+}
+
+/// 1.13 (17w43a) started prefixing user packs with `file/` in options.txt.
+fn uses_pack_file_prefix(mc_version: &str) -> bool {
+    if let Some(v) = crate::java::parse_version(mc_version) {
+        v >= (1, 13, 0)
+    } else if let Some(week) = crate::java::parse_snapshot(mc_version) {
+        week >= (17, 43)
+    } else {
+        true
+    }
+}
+
+/// Make `pack_filenames` (in resourcepacks/, lowest priority first) the enabled resource
+/// packs in options.txt. Built-in and mod-provided packs such as "vanilla" stay enabled.
+#[tauri::command]
+pub async fn set_active_resourcepacks(
+    app: AppHandle,
+    instance_id: String,
+    pack_filenames: Vec<String>,
+) -> Result<(), String> {
+    let meta_path = instance_meta_path(&app, &instance_id)?;
+    let instance = read_instance(&meta_path)?;
+    let mc_version = instance.mc_version.as_deref().unwrap_or(&instance.version);
+
+    let game_dir = instance_dir(&app, &instance_id)?.join(".minecraft");
+    for name in &pack_filenames {
+        if name.contains(['/', '\\']) || !game_dir.join("resourcepacks").join(name).exists() {
+            return Err(format!("Resource pack not found: {}", name));
+        }
+    }
+
+    let options_path = game_dir.join("options.txt");
+    let options = if options_path.exists() {
+        fs::read_to_string(&options_path).map_err(|e| e.to_string())?
+    } else {
+        String::new()
+    };
+    let current: Vec<String> = options
+        .lines()
+        .find_map(|l| l.strip_prefix("resourcePacks:"))
+        .and_then(|v| serde_json::from_str(v).ok())
+        .unwrap_or_default();
+
+    // Keep non-file packs (vanilla, fabric, programmer_art...) below the user's packs
+    let prefix = uses_pack_file_prefix(mc_version);
+    let is_user_pack = |p: &str| {
+        if prefix {
+            p.starts_with("file/")
+        } else {
+            p != "vanilla" && game_dir.join("resourcepacks").join(p).exists()
+        }
+    };
+    let mut packs: Vec<String> = current.into_iter().filter(|p| !is_user_pack(p)).collect();
+    if !packs.iter().any(|p| p == "vanilla") {
+        packs.insert(0, "vanilla".to_string());
+    }
+    packs.extend(pack_filenames.iter().map(|name| {
+        if prefix {
+            format!("file/{}", name)
+        } else {
+            name.clone()
+        }
+    }));
+
+    let line = format!(
+        "resourcePacks:{}",
+        serde_json::to_string(&packs).map_err(|e| e.to_string())?
+    );
+    let mut replaced = false;
+    let mut lines: Vec<String> = options
+        .lines()
+        .map(|l| {
+            if l.starts_with("resourcePacks:") {
+                replaced = true;
+                line.clone()
+            } else {
+                l.to_string()
+            }
+        })
+        .collect();
+    if !replaced {
+        lines.push(line);
+    }
+    fs::write(&options_path, lines.join("\n") + "\n").map_err(|e| e.to_string())?;
+    Ok(())
 }
 
 #[tauri::command]
@@ -2234,6 +5909,405 @@ pub async fn open_path(path: String) -> Result<(), String> {
     }
     Ok(())
 }
+
+/// Open the OS file manager at `path`'s folder with the file selected.
+/// Where selection isn't supported, opens the containing folder instead.
+#[tauri::command]
+pub async fn reveal_in_explorer(path: String) -> Result<(), String> {
+    let path_buf = PathBuf::from(&path);
+    if !path_buf.exists() {
+        return Err(format!("Path does not exist: {}", path));
+    }
+    let parent = path_buf
+        .parent()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.clone());
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        // explorer does its own parsing of /select, so pass it through unescaped
+        if Command::new("explorer")
+            .raw_arg(format!("/select,\"{}\"", path))
+            .spawn()
+            .is_ok()
+        {
+            return Ok(());
+        }
+    }
+    #[cfg(target_os = "macos")]
+    {
+        if Command::new("open").arg("-R").arg(&path).spawn().is_ok() {
+            return Ok(());
+        }
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        // Most desktop file managers (Nautilus, Dolphin, Nemo, Thunar) implement FileManager1
+        if let Ok(uri) = reqwest::Url::from_file_path(&path_buf) {
+            let shown = Command::new("dbus-send")
+                .args([
+                    "--session",
+                    "--print-reply",
+                    "--dest=org.freedesktop.FileManager1",
+                    "--type=method_call",
+                    "/org/freedesktop/FileManager1",
+                    "org.freedesktop.FileManager1.ShowItems",
+                ])
+                .arg(format!("array:string:{}", uri))
+                .arg("string:")
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .map(|s| s.success())
+                .unwrap_or(false);
+            if shown {
+                return Ok(());
+            }
+        }
+    }
+
+    open_path(parent).await
+}
+
+// --- Instance Export ---
+
+/// What `export_instance` bundles from `.minecraft`.
+#[derive(serde::Deserialize)]
+#[serde(default)]
+pub struct ExportOptions {
+    pub include_saves: bool,
+    pub include_configs: bool,
+    pub include_resourcepacks: bool,
+    /// Extra globs matched against paths relative to `.minecraft`, on top of
+    /// `DEFAULT_EXPORT_EXCLUDES` and the instance's `.minecraft/.exportignore`
+    pub exclude_globs: Vec<String>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            include_saves: false,
+            include_configs: true,
+            include_resourcepacks: true,
+            exclude_globs: Vec::new(),
+        }
+    }
+}
+
+/// Logs, crash reports and caches the game or loaders rebuild on their own.
+const DEFAULT_EXPORT_EXCLUDES: &[&str] = &[
+    "/logs/",
+    "/crash-reports/",
+    ".cache/",
+    "/.fabric/",
+    "/.quilt/",
+    "/.mixin.out/",
+    "/webcache*/",
+    "/debug/",
+    "session.lock",
+    "/.exportignore",
+];
+
+#[derive(Serialize)]
+pub struct ExportSummary {
+    pub path: String,
+    pub files: u32,
+    pub size_bytes: u64,
+}
+
+/// Glob match on `/`-separated paths: `*` and `?` stay within one segment, `**` spans any.
+fn glob_match(pattern: &[u8], path: &[u8]) -> bool {
+    match pattern {
+        [] => path.is_empty(),
+        [b'*', b'*', rest @ ..] => {
+            // `**/x` also matches `x` at the top level
+            let rest = rest.strip_prefix(b"/").unwrap_or(rest);
+            (0..=path.len())
+                .filter(|&i| i == 0 || path[i - 1] == b'/')
+                .any(|i| glob_match(rest, &path[i..]))
+        }
+        [b'*', rest @ ..] => {
+            let segment = path.iter().position(|&c| c == b'/').unwrap_or(path.len());
+            (0..=segment).any(|i| glob_match(rest, &path[i..]))
+        }
+        [b'?', rest @ ..] => {
+            matches!(path, [c, tail @ ..] if *c != b'/' && glob_match(rest, tail))
+        }
+        [c, rest @ ..] => matches!(path, [p, tail @ ..] if p == c && glob_match(rest, tail)),
+    }
+}
+
+/// gitignore-style: a trailing `/` only matches directories, a pattern with any other
+/// `/` (leading included) is anchored to the instance root, and one without matches the
+/// file or directory name at any depth.
+fn export_excluded(rel: &str, is_dir: bool, globs: &[String]) -> bool {
+    let name = rel.rsplit('/').next().unwrap_or(rel);
+    globs.iter().any(|glob| {
+        let (glob, dir_only) = match glob.strip_suffix('/') {
+            Some(g) => (g, true),
+            None => (glob.as_str(), false),
+        };
+        let anchored = glob.contains('/');
+        let glob = glob.trim_start_matches('/');
+        if glob.is_empty() || (dir_only && !is_dir) {
+            return false;
+        }
+        if anchored {
+            glob_match(glob.as_bytes(), rel.as_bytes())
+        } else {
+            glob_match(glob.as_bytes(), name.as_bytes())
+        }
+    })
+}
+
+/// The `.mrpack` `dependencies` key for a loader.
+fn mrpack_loader_key(loader: &str) -> Option<&'static str> {
+    match loader {
+        "fabric" => Some("fabric-loader"),
+        "quilt" => Some("quilt-loader"),
+        "forge" => Some("forge"),
+        "neoforge" => Some("neoforge"),
+        _ => None,
+    }
+}
+
+/// Export an instance to `dest_path` as `format` "zip" (instance.json plus `.minecraft/`)
+/// or "mrpack" (everything under `overrides/`). `options` defaults to configs and resource
+/// packs without saves; logs, crash reports and caches are always left out.
+#[tauri::command]
+pub async fn export_instance(
+    app: AppHandle,
+    instance_id: String,
+    dest_path: String,
+    format: String,
+    options: Option<ExportOptions>,
+) -> Result<ExportSummary, String> {
+    let options = options.unwrap_or_default();
+    let root = instance_dir(&app, &instance_id)?;
+    let instance = read_instance(&root.join("instance.json"))?;
+    let mc_dir = root.join(".minecraft");
+
+    let mut excludes: Vec<String> = DEFAULT_EXPORT_EXCLUDES
+        .iter()
+        .map(|g| g.to_string())
+        .collect();
+    for (included, dir) in [
+        (options.include_saves, "/saves/"),
+        (options.include_configs, "/config/"),
+        (options.include_resourcepacks, "/resourcepacks/"),
+    ] {
+        if !included {
+            excludes.push(dir.to_string());
+        }
+    }
+    if let Ok(text) = fs::read_to_string(mc_dir.join(".exportignore")) {
+        excludes.extend(
+            text.lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .map(str::to_string),
+        );
+    }
+    excludes.extend(options.exclude_globs);
+
+    let dest = PathBuf::from(&dest_path);
+    let file = fs::File::create(&dest).map_err(|e| e.to_string())?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    let mut files = 0;
+    let result = (|| -> Result<(), String> {
+        let prefix = match format.as_str() {
+            "zip" => {
+                let meta = serde_json::to_string_pretty(&instance).map_err(|e| e.to_string())?;
+                zip.start_file("instance.json", options)
+                    .map_err(|e| e.to_string())?;
+                std::io::Write::write_all(&mut zip, meta.as_bytes()).map_err(|e| e.to_string())?;
+                ".minecraft/"
+            }
+            "mrpack" => {
+                let mc_version = instance.mc_version.as_deref().unwrap_or(&instance.version);
+                let mut dependencies = serde_json::Map::new();
+                dependencies.insert("minecraft".to_string(), mc_version.into());
+                if let (Some(key), Some(version)) = (
+                    instance.loader.as_deref().and_then(mrpack_loader_key),
+                    instance.loader_version.as_deref(),
+                ) {
+                    dependencies.insert(key.to_string(), version.into());
+                }
+                let index = serde_json::json!({
+                    "formatVersion": 1,
+                    "game": "minecraft",
+                    "versionId": "1.0.0",
+                    "name": instance.name,
+                    "files": [],
+                    "dependencies": dependencies,
+                });
+                let index = serde_json::to_string_pretty(&index).map_err(|e| e.to_string())?;
+                zip.start_file("modrinth.index.json", options)
+                    .map_err(|e| e.to_string())?;
+                std::io::Write::write_all(&mut zip, index.as_bytes()).map_err(|e| e.to_string())?;
+                "overrides/"
+            }
+            other => return Err(format!("Unknown export format: {}", other)),
+        };
+        if mc_dir.is_dir() {
+            let exclude = |rel: &str, is_dir: bool| export_excluded(rel, is_dir, &excludes);
+            zip_dir(&mut zip, &mc_dir, "", prefix, Some(&exclude), &mut files)?;
+        }
+        zip.finish().map(|_| ()).map_err(|e| e.to_string())
+    })();
+    if let Err(e) = result {
+        let _ = fs::remove_file(&dest);
+        return Err(format!("Failed to export {}: {}", instance.name, e));
+    }
+
+    log::info!(
+        "Exported {} ({} files) to {}",
+        instance.name,
+        files,
+        dest_path
+    );
+    Ok(ExportSummary {
+        path: dest_path,
+        files,
+        size_bytes: fs::metadata(&dest).map(|m| m.len()).unwrap_or(0),
+    })
+}
+
+// --- Mod Configs ---
+
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+    Properties,
+    Other,
+}
+
+fn config_format(path: &Path) -> ConfigFormat {
+    match path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .as_deref()
+    {
+        Some("toml") => ConfigFormat::Toml,
+        Some("json") | Some("json5") => ConfigFormat::Json,
+        Some("properties") => ConfigFormat::Properties,
+        _ => ConfigFormat::Other,
+    }
+}
+
+#[derive(Serialize)]
+pub struct ConfigFileEntry {
+    /// Relative to `.minecraft/config`, always `/`-separated
+    pub path: String,
+    pub format: ConfigFormat,
+    pub size_bytes: u64,
+    pub modified: u64,
+}
+
+// Config files are small; anything bigger isn't something to edit in a text box
+const MAX_CONFIG_FILE_BYTES: u64 = 4 * 1024 * 1024;
+
+fn config_dir(app: &AppHandle, instance_id: &str) -> Result<PathBuf, String> {
+    Ok(instance_dir(app, instance_id)?
+        .join(".minecraft")
+        .join("config"))
+}
+
+fn collect_config_files(dir: &Path, base: &Path, out: &mut Vec<ConfigFileEntry>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if meta.is_dir() {
+            collect_config_files(&path, base, out);
+            continue;
+        }
+        let Ok(rel) = path.strip_prefix(base) else {
+            continue;
+        };
+        let modified = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        out.push(ConfigFileEntry {
+            path: rel
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+            format: config_format(&path),
+            size_bytes: meta.len(),
+            modified,
+        });
+    }
+}
+
+/// Every file under the instance's `.minecraft/config`, sorted by path.
+#[tauri::command]
+pub async fn list_instance_configs(
+    app: AppHandle,
+    instance_id: String,
+) -> Result<Vec<ConfigFileEntry>, String> {
+    let dir = config_dir(&app, &instance_id)?;
+    let mut files = Vec::new();
+    collect_config_files(&dir, &dir, &mut files);
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+#[tauri::command]
+pub async fn read_config_file(
+    app: AppHandle,
+    instance_id: String,
+    path: String,
+) -> Result<String, String> {
+    let target = resolve_within(&config_dir(&app, &instance_id)?, &path)?;
+    let size = fs::metadata(&target).map_err(|e| e.to_string())?.len();
+    if size > MAX_CONFIG_FILE_BYTES {
+        return Err(format!("{} is too large to edit ({} bytes)", path, size));
+    }
+    fs::read_to_string(&target).map_err(|e| format!("Cannot read {}: {}", path, e))
+}
+
+/// Write a config file, creating it if needed. TOML and plain JSON are parsed first
+/// so a typo is reported here rather than as a crash on the next launch.
+#[tauri::command]
+pub async fn write_config_file(
+    app: AppHandle,
+    instance_id: String,
+    path: String,
+    contents: String,
+) -> Result<(), String> {
+    let target = resolve_within(&config_dir(&app, &instance_id)?, &path)?;
+    match config_format(&target) {
+        ConfigFormat::Toml => {
+            toml::from_str::<toml::Value>(&contents)
+                .map_err(|e| format!("Invalid TOML in {}: {}", path, e))?;
+        }
+        // JSON5 allows comments and trailing commas, so only strict .json is checked
+        ConfigFormat::Json if target.extension().is_some_and(|e| e == "json") => {
+            serde_json::from_str::<serde_json::Value>(&contents)
+                .map_err(|e| format!("Invalid JSON in {}: {}", path, e))?;
+        }
+        _ => {}
+    }
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(&target, contents).map_err(|e| format!("Cannot write {}: {}", path, e))
+}
+
 // --- Crash Detection & Log Management ---
 
 #[derive(serde::Serialize)]
@@ -2393,33 +6467,185 @@ pub async fn get_last_launch_log(app: AppHandle, instance_id: String) -> Result<
     }
 }
 
+/// Most of `latest.log` returned by one `tail_instance_log` call; the rest comes on the next poll.
+const MAX_LOG_TAIL_BYTES: u64 = 1024 * 1024;
+
+#[derive(Serialize)]
+pub struct LogTail {
+    pub lines: Vec<String>,
+    /// Pass back as `from_offset` on the next call
+    pub offset: u64,
+    /// The log was shorter than `from_offset` (the game restarted), so reading began again at 0
+    pub reset: bool,
+}
+
+/// Complete lines of the game's own `.minecraft/logs/latest.log` written since
+/// `from_offset`, so the UI can re-attach to a game the launcher no longer owns (after a
+/// launcher restart or `close_on_launch`). A trailing partial line is left for the next poll.
 #[tauri::command]
-pub async fn clear_instance_logs(app: AppHandle, instance_id: String) -> Result<(), String> {
-    let root = instance_dir(&app, &instance_id)?;
-    let logs_dir = root.join("logs");
+pub async fn tail_instance_log(
+    app: AppHandle,
+    instance_id: String,
+    from_offset: u64,
+) -> Result<LogTail, String> {
+    let path = instance_dir(&app, &instance_id)?
+        .join(".minecraft")
+        .join("logs")
+        .join("latest.log");
+    let Ok(mut file) = fs::File::open(&path) else {
+        return Ok(LogTail {
+            lines: Vec::new(),
+            offset: 0,
+            reset: from_offset > 0,
+        });
+    };
+    let len = file.metadata().map_err(|e| e.to_string())?.len();
+    let reset = from_offset > len;
+    let start = if reset { 0 } else { from_offset };
 
-    if logs_dir.exists() {
-        // Clear crash reports
-        let crash_reports = logs_dir.join("crash-reports");
-        if crash_reports.exists() {
-            fs::remove_dir_all(&crash_reports).map_err(|e| e.to_string())?;
-            fs::create_dir_all(&crash_reports).map_err(|e| e.to_string())?;
-        }
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| e.to_string())?;
+    let mut buf = Vec::new();
+    file.take(MAX_LOG_TAIL_BYTES)
+        .read_to_end(&mut buf)
+        .map_err(|e| e.to_string())?;
+
+    // A single line longer than the whole chunk is returned as-is rather than never
+    let consumed = match buf.iter().rposition(|&b| b == b'\n') {
+        Some(i) => i + 1,
+        None if buf.len() as u64 == MAX_LOG_TAIL_BYTES => buf.len(),
+        None => 0,
+    };
+    let lines = String::from_utf8_lossy(&buf[..consumed])
+        .lines()
+        .map(str::to_string)
+        .collect();
+    Ok(LogTail {
+        lines,
+        offset: start + consumed as u64,
+        reset,
+    })
+}
+
+/// Files and bytes removed by a cleanup command.
+#[derive(Serialize, Default)]
+pub struct ClearedFiles {
+    pub files: u64,
+    pub bytes: u64,
+}
 
-        // Clear latest.log
-        let latest_log = logs_dir.join("latest.log");
-        if latest_log.exists() {
-            fs::remove_file(&latest_log).map_err(|e| e.to_string())?;
+/// Delete files directly in `dir` that pass `filter` and, if `cutoff` is set,
+/// were last modified before it.
+fn remove_files_in(
+    dir: &std::path::Path,
+    filter: impl Fn(&std::path::Path) -> bool,
+    cutoff: Option<std::time::SystemTime>,
+    cleared: &mut ClearedFiles,
+) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if !meta.is_file() || !filter(&path) {
+            continue;
+        }
+        if let (Some(cutoff), Ok(modified)) = (cutoff, meta.modified()) {
+            if modified >= cutoff {
+                continue;
+            }
+        }
+        if fs::remove_file(&path).is_ok() {
+            cleared.files += 1;
+            cleared.bytes += meta.len();
         }
     }
+}
 
-    // Clear last launch log
-    let last_launch = root.join("last_launch.log");
-    if last_launch.exists() {
-        fs::remove_file(&last_launch).map_err(|e| e.to_string())?;
+/// Delete an instance's game logs, crash reports and launch log. With
+/// `older_than_days`, only files untouched for that long are removed.
+#[tauri::command]
+pub async fn clear_instance_logs(
+    app: AppHandle,
+    instance_id: String,
+    older_than_days: Option<u32>,
+) -> Result<ClearedFiles, String> {
+    let root = instance_dir(&app, &instance_id)?;
+    let cutoff = older_than_days.map(|days| {
+        std::time::SystemTime::now() - std::time::Duration::from_secs(u64::from(days) * 86_400)
+    });
+    let is_log = |p: &std::path::Path| {
+        let name = p
+            .file_name()
+            .map(|n| n.to_string_lossy())
+            .unwrap_or_default();
+        name.ends_with(".log") || name.ends_with(".log.gz")
+    };
+
+    let mut cleared = ClearedFiles::default();
+    // Launcher-side logs live next to instance.json, the game's own under .minecraft
+    for base in [root.clone(), root.join(".minecraft")] {
+        remove_files_in(&base.join("logs"), is_log, cutoff, &mut cleared);
+        remove_files_in(
+            &base.join("logs").join("crash-reports"),
+            |_| true,
+            cutoff,
+            &mut cleared,
+        );
+        remove_files_in(&base.join("crash-reports"), |_| true, cutoff, &mut cleared);
     }
+    remove_files_in(
+        &root,
+        |p| p.ends_with("last_launch.log"),
+        cutoff,
+        &mut cleared,
+    );
 
-    Ok(())
+    Ok(cleared)
+}
+
+/// Empty the Modrinth icon cache and remove leftover Java downloads and loader
+/// installer logs. Everything removed here is fetched or recreated on demand.
+#[tauri::command]
+pub async fn clear_all_caches(app: AppHandle) -> Result<ClearedFiles, String> {
+    let mut cleared = ClearedFiles::default();
+
+    let icon_cache = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| e.to_string())?
+        .join("icon_cache");
+    remove_files_in(&icon_cache, |_| true, None, &mut cleared);
+
+    // Adoptium archives (e.g. OpenJDK21U-jre_x64_windows_hotspot_21.0.5_11.zip) left by
+    // an interrupted Java download, including their .part files
+    remove_files_in(
+        &std::env::temp_dir(),
+        |p| {
+            let name = p
+                .file_name()
+                .map(|n| n.to_string_lossy())
+                .unwrap_or_default();
+            name.starts_with("OpenJDK")
+                && name.contains("-jre_")
+                && (name.ends_with(".zip") || name.ends_with(".zip.part"))
+        },
+        None,
+        &mut cleared,
+    );
+
+    // Forge/NeoForge installers write `<installer>.jar.log` into their working dir
+    remove_files_in(
+        &minecraft_root(&app)?,
+        |p| p.to_string_lossy().ends_with(".jar.log"),
+        None,
+        &mut cleared,
+    );
+
+    Ok(cleared)
 }
 
 // --- Mod Update Detection ---
@@ -2431,6 +6657,8 @@ pub struct ModUpdateInfo {
     pub latest_version: String,
     pub project_id: String,
     pub update_available: bool,
+    /// Release notes of `latest_version`, when Modrinth has any
+    pub changelog: Option<String>,
 }
 
 #[tauri::command]
@@ -2439,8 +6667,7 @@ pub async fn check_mod_updates(
     instance_id: String,
 ) -> Result<Vec<ModUpdateInfo>, String> {
     let root = instance_dir(&app, &instance_id)?;
-    let meta_text = fs::read_to_string(root.join("instance.json")).map_err(|e| e.to_string())?;
-    let instance: Instance = serde_json::from_str(&meta_text).map_err(|e| e.to_string())?;
+    let instance = read_instance(&root.join("instance.json"))?;
 
     let mc_version = instance
         .mc_version
@@ -2461,8 +6688,7 @@ pub async fn check_mod_updates(
         return Ok(Vec::new());
     }
 
-    let mut update_info = Vec::new();
-
+    let mut candidates = Vec::new();
     for entry in fs::read_dir(mods_dir).map_err(|e| e.to_string())?.flatten() {
         let path = entry.path();
         if path.is_file() && path.extension().map_or(false, |ext| ext == "jar") {
@@ -2472,109 +6698,569 @@ pub async fn check_mod_updates(
                 .unwrap_or("")
                 .to_string();
 
-            // Try to extract mod info from filename or jar metadata
-            if let Ok(mod_info) = extract_mod_info(&path).await {
-                if let Some(project_id) = mod_info.project_id {
-                    // Check for updates on Modrinth
-                    match crate::modrinth::resolve_mod_version(&project_id, mc_version, loader)
-                        .await
-                    {
-                        Ok(latest_version) => {
-                            let update_available =
-                                mod_info.version != latest_version.version_number;
-                            update_info.push(ModUpdateInfo {
-                                filename,
-                                current_version: mod_info.version,
-                                latest_version: latest_version.version_number,
-                                project_id,
-                                update_available,
-                            });
-                        }
-                        Err(_) => {
-                            // Mod not found on Modrinth or other error
-                            update_info.push(ModUpdateInfo {
-                                filename,
-                                current_version: mod_info.version,
-                                latest_version: "Unknown".to_string(),
-                                project_id,
-                                update_available: false,
-                            });
-                        }
-                    }
+            // Try to extract mod info from filename or jar metadata
+            if let Ok(mod_info) = extract_mod_info(&path).await {
+                if let Some(project_id) = mod_info.project_id {
+                    candidates.push((filename, mod_info.version, project_id));
+                }
+            }
+        }
+    }
+
+    // All lookups go out together; the Modrinth module caps how many are in flight
+    let mut lookups: FuturesUnordered<_> = candidates
+        .into_iter()
+        .map(|(filename, current_version, project_id)| async move {
+            let latest =
+                crate::modrinth::resolve_mod_version(&project_id, mc_version, loader).await;
+            match latest {
+                Ok(latest_version) => ModUpdateInfo {
+                    // Mod metadata and Modrinth often spell versions differently, so anything
+                    // not clearly older than what's installed counts as an update
+                    update_available: current_version != latest_version.version_number
+                        && !crate::version_cmp::is_newer(
+                            &current_version,
+                            &latest_version.version_number,
+                        ),
+                    filename,
+                    current_version,
+                    latest_version: latest_version.version_number,
+                    project_id,
+                    changelog: latest_version.changelog,
+                },
+                // Mod not found on Modrinth or other error
+                Err(_) => ModUpdateInfo {
+                    filename,
+                    current_version,
+                    latest_version: "Unknown".to_string(),
+                    project_id,
+                    update_available: false,
+                    changelog: None,
+                },
+            }
+        })
+        .collect();
+
+    let mut update_info = Vec::new();
+    while let Some(info) = lookups.next().await {
+        update_info.push(info);
+    }
+    update_info.sort_by(|a, b| a.filename.cmp(&b.filename));
+
+    Ok(update_info)
+}
+
+#[derive(Serialize, Clone)]
+pub struct UpdatedMod {
+    pub old_file: String,
+    pub new_file: String,
+    pub version: String,
+}
+
+#[derive(Serialize, Clone)]
+pub struct FailedModUpdate {
+    pub file: String,
+    pub error: String,
+}
+
+#[derive(Serialize, Clone, Default)]
+pub struct ModsUpdateSummary {
+    pub updated: Vec<UpdatedMod>,
+    /// Up to date, or not found on Modrinth
+    pub skipped: Vec<String>,
+    pub failed: Vec<FailedModUpdate>,
+}
+
+/// Update every mod Modrinth recognizes (by file hash) to its newest version for
+/// the instance's Minecraft version and loader. Disabled mods stay disabled.
+/// Emits `mods-update-progress` as each download finishes.
+#[tauri::command]
+pub async fn update_all_mods(
+    app: AppHandle,
+    process_state: State<'_, ChildProcessState>,
+    instance_id: String,
+) -> Result<ModsUpdateSummary, String> {
+    if child_running(&process_state, &instance_id) {
+        return Err("Close the game before updating its mods".to_string());
+    }
+    let root = instance_dir(&app, &instance_id)?;
+    let instance = read_instance(&root.join("instance.json"))?;
+    // Curated packs are pinned precisely because their mod set is tested as a whole
+    instance.ensure_unpinned("updating all of its mods")?;
+    update_instance_mods(&root, &instance, true, |progress| {
+        let _ = app.emit("mods-update-progress", progress);
+    })
+    .await
+}
+
+/// A mod jar with a newer Modrinth version for the instance.
+struct PendingModUpdate {
+    name: String,
+    path: PathBuf,
+    version: String,
+    file: crate::modrinth::ModrinthFile,
+}
+
+/// Look up every mod jar by hash and list those with a newer version. The summary
+/// holds the mods that are up to date, unknown, or couldn't be read.
+/// `include_disabled` decides whether disabled jars are looked at too.
+async fn find_mod_updates(
+    root: &Path,
+    instance: &Instance,
+    include_disabled: bool,
+) -> Result<(Vec<PendingModUpdate>, ModsUpdateSummary), String> {
+    let (mc_version, loader) = instance_mod_target(instance);
+
+    let mods_dir = root.join(".minecraft").join("mods");
+    let mut summary = ModsUpdateSummary::default();
+    let Ok(entries) = fs::read_dir(&mods_dir) else {
+        return Ok((Vec::new(), summary));
+    };
+    let jars: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
+        .filter(|(name, path)| {
+            path.is_file()
+                && (name.ends_with(".jar") || (include_disabled && name.ends_with(".jar.disabled")))
+        })
+        .collect();
+
+    // Hashing a large modpack's jars is real work; keep it off the async runtime
+    let hashed = tokio::task::spawn_blocking(move || {
+        jars.into_iter()
+            .map(|(name, path)| {
+                let hash = crate::download::sha1_file(&path).map_err(|e| e.to_string());
+                (name, path, hash)
+            })
+            .collect::<Vec<_>>()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let mut known = Vec::new();
+    for (name, path, hash) in hashed {
+        match hash {
+            Ok(hash) => known.push((name, path, hash)),
+            Err(error) => summary.failed.push(FailedModUpdate { file: name, error }),
+        }
+    }
+    let hashes: Vec<String> = known.iter().map(|(_, _, h)| h.clone()).collect();
+    let latest = crate::modrinth::latest_versions_for_hashes(&hashes, mc_version, loader).await?;
+
+    let mut pending = Vec::new();
+    for (name, path, hash) in known {
+        let Some(version) = latest.get(&hash) else {
+            summary.skipped.push(name);
+            continue;
+        };
+        let Ok(file) = crate::modrinth::select_primary_file(version) else {
+            summary.skipped.push(name);
+            continue;
+        };
+        if file
+            .hashes
+            .get("sha1")
+            .is_some_and(|h| h.eq_ignore_ascii_case(&hash))
+        {
+            summary.skipped.push(name);
+            continue;
+        }
+        pending.push(PendingModUpdate {
+            name,
+            path,
+            version: version.version_number.clone(),
+            file: file.clone(),
+        });
+    }
+
+    Ok((pending, summary))
+}
+
+/// The work behind `update_all_mods`: download every update `find_mod_updates`
+/// reports, calling `on_progress` as each one finishes.
+async fn update_instance_mods(
+    root: &Path,
+    instance: &Instance,
+    include_disabled: bool,
+    on_progress: impl Fn(ModsInstallProgress),
+) -> Result<ModsUpdateSummary, String> {
+    let (pending, mut summary) = find_mod_updates(root, instance, include_disabled).await?;
+    let mods_dir = root.join(".minecraft").join("mods");
+
+    let total = pending.len();
+    let mut pending = pending.into_iter();
+    let spawn = |update: PendingModUpdate| {
+        let mods_dir = mods_dir.clone();
+        let PendingModUpdate {
+            name,
+            path: old_path,
+            version,
+            file,
+        } = update;
+        async move {
+            let new_file = if name.ends_with(".disabled") {
+                format!("{}.disabled", file.filename)
+            } else {
+                file.filename.clone()
+            };
+            let target = mods_dir.join(&new_file);
+            let result = async {
+                if !target.starts_with(&mods_dir) || file.filename.contains(['/', '\\']) {
+                    return Err(format!("Refusing file name {}", file.filename));
+                }
+                // Verified under a name the loaders ignore, so a bad download never
+                // touches the working jar, even when the new file has the same name
+                let staging = mods_dir.join(format!("{}.update", new_file));
+                crate::download::download_with_retry(
+                    &file.url,
+                    &staging,
+                    file.expected_hash().as_ref(),
+                    MOD_DOWNLOAD_ATTEMPTS,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+                if let Err(e) = fs::rename(&staging, &target) {
+                    let _ = fs::remove_file(&staging);
+                    return Err(e.to_string());
+                }
+                if old_path != target {
+                    fs::remove_file(&old_path).map_err(|e| e.to_string())?;
                 }
+                Ok(())
             }
+            .await;
+            match result {
+                Ok(()) => Ok(UpdatedMod {
+                    old_file: name,
+                    new_file,
+                    version,
+                }),
+                Err(error) => Err(FailedModUpdate { file: name, error }),
+            }
+        }
+    };
+
+    let mut in_flight = FuturesUnordered::new();
+    for entry in pending.by_ref().take(MOD_INSTALL_CONCURRENCY) {
+        in_flight.push(spawn(entry));
+    }
+    let mut done = 0;
+    while let Some(result) = in_flight.next().await {
+        if let Some(entry) = pending.next() {
+            in_flight.push(spawn(entry));
+        }
+        done += 1;
+        let current = match &result {
+            Ok(updated) => updated.new_file.clone(),
+            Err(failed) => failed.file.clone(),
+        };
+        on_progress(ModsInstallProgress {
+            done,
+            total,
+            current,
+        });
+        match result {
+            Ok(updated) => summary.updated.push(updated),
+            Err(failed) => summary.failed.push(failed),
         }
     }
 
-    Ok(update_info)
+    Ok(summary)
 }
 
-#[derive(Debug)]
-struct ModInfo {
+/// Metadata read from a mod jar's `fabric.mod.json`, `quilt.mod.json` or
+/// `META-INF/mods.toml`, or guessed from the filename when none is present.
+#[derive(Debug, Serialize, Clone)]
+pub struct ModInfo {
+    pub id: Option<String>,
+    pub name: String,
     pub version: String,
+    pub description: Option<String>,
+    pub depends: Vec<String>,
+    /// "fabric", "quilt" or "forge"; `None` when guessed from the filename
+    pub loader: Option<String>,
     pub project_id: Option<String>,
-    pub name: String,
+    /// Path of the icon inside the jar
+    pub icon: Option<String>,
 }
 
-async fn extract_mod_info(jar_path: &std::path::Path) -> Result<ModInfo, String> {
-    // Try to read fabric.mod.json from the jar file
-    let file = std::fs::File::open(jar_path).map_err(|e| e.to_string())?;
-    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+fn read_zip_entry(archive: &mut zip::ZipArchive<fs::File>, name: &str) -> Option<String> {
+    let mut entry = archive.by_name(name).ok()?;
+    let mut contents = String::new();
+    entry.read_to_string(&mut contents).ok()?;
+    Some(contents)
+}
 
-    // Look for fabric.mod.json
-    if let Ok(mut fabric_mod_file) = archive.by_name("fabric.mod.json") {
-        let mut contents = String::new();
-        fabric_mod_file
-            .read_to_string(&mut contents)
-            .map_err(|e| e.to_string())?;
+fn fabric_mod_info(json: &serde_json::Value) -> ModInfo {
+    let str_field = |v: &serde_json::Value| v.as_str().map(|s| s.to_string());
+    // "icon" is either a path or a map of size -> path; take the largest
+    let icon = match &json["icon"] {
+        serde_json::Value::Object(sizes) => sizes
+            .iter()
+            .max_by_key(|(size, _)| size.parse::<u32>().unwrap_or(0))
+            .and_then(|(_, path)| str_field(path)),
+        other => str_field(other),
+    };
+    ModInfo {
+        id: str_field(&json["id"]),
+        name: str_field(&json["name"])
+            .or_else(|| str_field(&json["id"]))
+            .unwrap_or_else(|| "unknown".to_string()),
+        version: str_field(&json["version"]).unwrap_or_else(|| "unknown".to_string()),
+        description: str_field(&json["description"]),
+        depends: json["depends"]
+            .as_object()
+            .map(|d| d.keys().cloned().collect())
+            .unwrap_or_default(),
+        loader: Some("fabric".to_string()),
+        project_id: str_field(&json["custom"]["modrinth"]["project-id"]),
+        icon,
+    }
+}
 
-        if let Ok(fabric_mod_json) = serde_json::from_str::<serde_json::Value>(&contents) {
-            let version = fabric_mod_json["version"]
-                .as_str()
-                .unwrap_or("unknown")
-                .to_string();
-            let name = fabric_mod_json["name"]
-                .as_str()
-                .unwrap_or("unknown")
-                .to_string();
+fn quilt_mod_info(json: &serde_json::Value) -> ModInfo {
+    let ql = &json["quilt_loader"];
+    let meta = &ql["metadata"];
+    let str_field = |v: &serde_json::Value| v.as_str().map(|s| s.to_string());
+    ModInfo {
+        id: str_field(&ql["id"]),
+        name: str_field(&meta["name"])
+            .or_else(|| str_field(&ql["id"]))
+            .unwrap_or_else(|| "unknown".to_string()),
+        version: str_field(&ql["version"]).unwrap_or_else(|| "unknown".to_string()),
+        description: str_field(&meta["description"]),
+        // Entries are either "modid" or { "id": "modid", ... }
+        depends: ql["depends"]
+            .as_array()
+            .map(|deps| {
+                deps.iter()
+                    .filter_map(|d| str_field(d).or_else(|| str_field(&d["id"])))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        loader: Some("quilt".to_string()),
+        project_id: None,
+        icon: str_field(&meta["icon"]),
+    }
+}
 
-            // Try to find Modrinth project ID in custom fields
-            let project_id = fabric_mod_json["custom"]["modrinth"]["project-id"]
-                .as_str()
-                .map(|s| s.to_string());
+fn forge_mod_info(toml_text: &str, manifest: Option<&str>) -> Option<ModInfo> {
+    let doc: toml::Value = toml::from_str(toml_text).ok()?;
+    let first = doc.get("mods")?.as_array()?.first()?;
+    let str_field = |v: Option<&toml::Value>| v.and_then(|v| v.as_str()).map(|s| s.to_string());
+
+    let id = str_field(first.get("modId"));
+    // "${file.jarVersion}" is filled in from the jar manifest at runtime
+    let version = str_field(first.get("version"))
+        .filter(|v| !v.starts_with("${"))
+        .or_else(|| {
+            manifest?
+                .lines()
+                .find_map(|l| l.strip_prefix("Implementation-Version:"))
+                .map(|v| v.trim().to_string())
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+    let depends = id
+        .as_ref()
+        .and_then(|id| doc.get("dependencies")?.get(id)?.as_array())
+        .map(|deps| {
+            deps.iter()
+                .filter_map(|d| str_field(d.get("modId")))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(ModInfo {
+        name: str_field(first.get("displayName"))
+            .or_else(|| id.clone())
+            .unwrap_or_else(|| "unknown".to_string()),
+        version,
+        description: str_field(first.get("description")).map(|d| d.trim().to_string()),
+        depends,
+        loader: Some("forge".to_string()),
+        project_id: None,
+        icon: str_field(first.get("logoFile")).or_else(|| str_field(doc.get("logoFile"))),
+        id,
+    })
+}
 
-            return Ok(ModInfo {
-                version,
-                project_id,
-                name,
-            });
+/// Read what metadata a jar carries, without falling back to the filename.
+fn read_mod_metadata(jar_path: &std::path::Path) -> Option<ModInfo> {
+    let file = fs::File::open(jar_path).ok()?;
+    let mut archive = zip::ZipArchive::new(file).ok()?;
+
+    if let Some(text) = read_zip_entry(&mut archive, "fabric.mod.json") {
+        // Some mods ship raw newlines inside strings; serde_json rejects those
+        if let Ok(json) = serde_json::from_str(&text.replace(['\n', '\r'], " ")) {
+            return Some(fabric_mod_info(&json));
+        }
+    }
+    if let Some(text) = read_zip_entry(&mut archive, "quilt.mod.json") {
+        if let Ok(json) = serde_json::from_str(&text) {
+            return Some(quilt_mod_info(&json));
+        }
+    }
+    for name in ["META-INF/mods.toml", "META-INF/neoforge.mods.toml"] {
+        if let Some(text) = read_zip_entry(&mut archive, name) {
+            let manifest = read_zip_entry(&mut archive, "META-INF/MANIFEST.MF");
+            if let Some(info) = forge_mod_info(&text, manifest.as_deref()) {
+                return Some(info);
+            }
         }
     }
+    None
+}
+
+/// Raw bytes of a mod's icon (the `icon` path from its metadata), for the mod list.
+#[tauri::command]
+pub async fn get_mod_icon(
+    app: AppHandle,
+    instance_id: String,
+    filename: String,
+) -> Result<Vec<u8>, String> {
+    let mods_dir = instance_dir(&app, &instance_id)?
+        .join(".minecraft")
+        .join("mods");
+    let jar_path = mods_dir.join(&filename);
+    if !jar_path.starts_with(&mods_dir) || filename.contains("..") {
+        return Err("Invalid path".to_string());
+    }
+
+    let icon = read_mod_metadata(&jar_path)
+        .and_then(|info| info.icon)
+        .ok_or("Mod has no icon")?;
+    let file = fs::File::open(&jar_path).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+    let mut entry = archive
+        .by_name(icon.trim_start_matches('/'))
+        .map_err(|e| e.to_string())?;
+    let mut bytes = Vec::new();
+    entry.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+    Ok(bytes)
+}
+
+async fn extract_mod_info(jar_path: &std::path::Path) -> Result<ModInfo, String> {
+    if let Some(info) = read_mod_metadata(jar_path) {
+        return Ok(info);
+    }
 
     // Fallback: try to parse from filename
-    let filename = jar_path
-        .file_stem()
+    let file_name = jar_path
+        .file_name()
         .and_then(|s| s.to_str())
         .unwrap_or("unknown");
+    let filename = file_name
+        .trim_end_matches(".disabled")
+        .trim_end_matches(".jar");
 
     // Common patterns: modname-version.jar, modname_version.jar
     let parts: Vec<&str> = filename.split(&['-', '_'][..]).collect();
-    if parts.len() >= 2 {
-        let version = parts.last().unwrap_or(&"unknown").to_string();
-        let name = parts[..parts.len() - 1].join("-");
-
-        Ok(ModInfo {
-            version,
-            project_id: None,
-            name,
-        })
+    let (name, version) = if parts.len() >= 2 {
+        (
+            parts[..parts.len() - 1].join("-"),
+            parts.last().unwrap_or(&"unknown").to_string(),
+        )
     } else {
-        Ok(ModInfo {
-            version: "unknown".to_string(),
-            project_id: None,
-            name: filename.to_string(),
-        })
+        (filename.to_string(), "unknown".to_string())
+    };
+
+    Ok(ModInfo {
+        id: None,
+        name,
+        version,
+        description: None,
+        depends: Vec::new(),
+        loader: None,
+        project_id: None,
+        icon: None,
+    })
+}
+
+/// Copy a jar from anywhere on disk into `mods_dir`, after checking it is a zip with a
+/// fabric, quilt or forge descriptor.
+fn import_local_mod(mods_dir: &Path, source: &Path) -> Result<ModInfo, String> {
+    let file_name = source
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| format!("Invalid path: {}", source.display()))?
+        .to_string();
+    if !file_name.to_lowercase().ends_with(".jar") {
+        return Err(format!("{} is not a .jar file", file_name));
+    }
+
+    let mut magic = [0u8; 4];
+    fs::File::open(source)
+        .and_then(|mut f| f.read_exact(&mut magic))
+        .map_err(|e| format!("Cannot read {}: {}", file_name, e))?;
+    if &magic != b"PK\x03\x04" {
+        return Err(format!("{} is not a jar (not a zip archive)", file_name));
+    }
+    let info = read_mod_metadata(source).ok_or_else(|| {
+        format!(
+            "{} has no fabric.mod.json, quilt.mod.json or mods.toml; it is not a mod",
+            file_name
+        )
+    })?;
+
+    let dest = mods_dir.join(&file_name);
+    if dest.exists() || mods_dir.join(format!("{}.disabled", file_name)).exists() {
+        return Err(format!("{} is already in the mods folder", file_name));
     }
+    fs::create_dir_all(mods_dir).map_err(|e| e.to_string())?;
+    fs::copy(source, &dest).map_err(|e| format!("Failed to copy {}: {}", file_name, e))?;
+    log::info!(
+        "Imported local mod {} into {}",
+        file_name,
+        mods_dir.display()
+    );
+    Ok(info)
+}
+
+/// Add a jar that isn't on Modrinth (a private mod or dev build) to an instance.
+#[tauri::command]
+pub async fn add_local_mod(
+    app: AppHandle,
+    instance_id: String,
+    source_jar_path: String,
+) -> Result<ModInfo, String> {
+    let mods_dir = instance_dir(&app, &instance_id)?
+        .join(".minecraft")
+        .join("mods");
+    import_local_mod(&mods_dir, Path::new(&source_jar_path))
+}
+
+#[derive(Serialize)]
+pub struct LocalModResult {
+    pub path: String,
+    pub info: Option<ModInfo>,
+    pub error: Option<String>,
+}
+
+/// `add_local_mod` for several jars (e.g. a drag-and-drop); one result per path, in order.
+#[tauri::command]
+pub async fn add_local_mods(
+    app: AppHandle,
+    instance_id: String,
+    paths: Vec<String>,
+) -> Result<Vec<LocalModResult>, String> {
+    let mods_dir = instance_dir(&app, &instance_id)?
+        .join(".minecraft")
+        .join("mods");
+    Ok(paths
+        .into_iter()
+        .map(|path| match import_local_mod(&mods_dir, Path::new(&path)) {
+            Ok(info) => LocalModResult {
+                path,
+                info: Some(info),
+                error: None,
+            },
+            Err(e) => LocalModResult {
+                path,
+                info: None,
+                error: Some(e),
+            },
+        })
+        .collect())
 }
 
 // --- Mod Enable/Disable ---
@@ -2627,20 +7313,14 @@ pub struct CleanupInfo {
     pub orphaned_libraries: Vec<String>,
     pub cache_size_mb: u64,
     pub total_cleanup_mb: u64,
+    /// Set when assets live in a shared folder, which asset cleanup doesn't touch
+    pub shared_assets_dir: Option<String>,
 }
 
 #[tauri::command]
 pub async fn get_cleanup_info(app: AppHandle) -> Result<CleanupInfo, String> {
-    let base = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("minecraft");
-    let instances_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("instances");
+    let base = minecraft_root(&app)?;
+    let instances_dir = instances_root(&app)?;
 
     // Get all instances to see which versions are in use
     let mut used_versions = std::collections::HashSet::new();
@@ -2651,12 +7331,10 @@ pub async fn get_cleanup_info(app: AppHandle) -> Result<CleanupInfo, String> {
         {
             if entry.path().is_dir() {
                 let instance_json = entry.path().join("instance.json");
-                if let Ok(content) = fs::read_to_string(&instance_json) {
-                    if let Ok(instance) = serde_json::from_str::<Instance>(&content) {
-                        used_versions.insert(instance.version.clone());
-                        if let Some(mc_version) = instance.mc_version {
-                            used_versions.insert(mc_version);
-                        }
+                if let Ok(instance) = read_instance(&instance_json) {
+                    used_versions.insert(instance.version.clone());
+                    if let Some(mc_version) = instance.mc_version {
+                        used_versions.insert(mc_version);
                     }
                 }
             }
@@ -2681,7 +7359,7 @@ pub async fn get_cleanup_info(app: AppHandle) -> Result<CleanupInfo, String> {
     }
 
     // Calculate cache sizes (simplified)
-    let assets_dir = base.join("assets");
+    let assets_dir = assets_root(&app)?;
     let cache_size_mb = if assets_dir.exists() {
         calculate_dir_size(&assets_dir)? / 1024 / 1024
     } else {
@@ -2689,7 +7367,12 @@ pub async fn get_cleanup_info(app: AppHandle) -> Result<CleanupInfo, String> {
     };
 
     // Estimate cleanup size
-    let mut total_cleanup_mb = cache_size_mb;
+    let shared_assets_dir = shared_assets_dir(&app)?;
+    let mut total_cleanup_mb = if shared_assets_dir.is_some() {
+        0
+    } else {
+        cache_size_mb
+    };
     for version in &unused_versions {
         let version_dir = versions_dir.join(version);
         if version_dir.exists() {
@@ -2702,17 +7385,14 @@ pub async fn get_cleanup_info(app: AppHandle) -> Result<CleanupInfo, String> {
         orphaned_libraries: Vec::new(), // TODO: implement library orphan detection
         cache_size_mb,
         total_cleanup_mb,
+        shared_assets_dir: shared_assets_dir.map(|d| d.to_string_lossy().to_string()),
     })
 }
 
 #[tauri::command]
 pub async fn cleanup_unused_versions(app: AppHandle) -> Result<Vec<String>, String> {
     let cleanup_info = get_cleanup_info(app.clone()).await?;
-    let base = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("minecraft");
+    let base = minecraft_root(&app)?;
     let versions_dir = base.join("versions");
 
     let mut cleaned = Vec::new();
@@ -2728,14 +7408,76 @@ pub async fn cleanup_unused_versions(app: AppHandle) -> Result<Vec<String>, Stri
     Ok(cleaned)
 }
 
+/// A folder under `versions/` and the instances that depend on it.
+#[derive(Serialize)]
+pub struct InstalledVersion {
+    pub version_id: String,
+    /// A loader profile that inherits from a vanilla version
+    pub is_loader_derived: bool,
+    pub size_bytes: u64,
+    /// Instances launching this version, or a loader profile inheriting from it
+    pub instance_ids: Vec<String>,
+}
+
+/// Every installed version with its consumers, for managing storage. Loader instances
+/// count against both their derived profile and the vanilla version beneath it.
+#[tauri::command]
+pub async fn list_installed_versions(app: AppHandle) -> Result<Vec<InstalledVersion>, String> {
+    let versions_dir = versions_root(&app)?;
+
+    let mut consumers: HashMap<String, Vec<String>> = HashMap::new();
+    for instance in list_instances(app.clone()).await? {
+        let version_id = instance.launch_version_id();
+        let mc_version = instance
+            .mc_version
+            .clone()
+            .unwrap_or(instance.version.clone());
+        if mc_version != version_id {
+            consumers
+                .entry(mc_version)
+                .or_default()
+                .push(instance.id.clone());
+        }
+        consumers.entry(version_id).or_default().push(instance.id);
+    }
+
+    let mut versions = Vec::new();
+    let Ok(entries) = fs::read_dir(&versions_dir) else {
+        return Ok(versions);
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let version_id = entry.file_name().to_string_lossy().to_string();
+        // Fall back to the naming scheme when the JSON is missing or broken
+        let is_loader_derived = fs::read_to_string(path.join(format!("{}.json", version_id)))
+            .ok()
+            .and_then(|text| serde_json::from_str::<serde_json::Value>(&text).ok())
+            .map(|json| json.get("inheritsFrom").is_some_and(|p| p.is_string()))
+            .unwrap_or_else(|| version_id.contains("-loader-"));
+        versions.push(InstalledVersion {
+            size_bytes: calculate_dir_size(&path)?,
+            instance_ids: consumers.remove(&version_id).unwrap_or_default(),
+            is_loader_derived,
+            version_id,
+        });
+    }
+
+    // Vanilla versions first, newest first within each group
+    versions.sort_by(|a, b| {
+        a.is_loader_derived
+            .cmp(&b.is_loader_derived)
+            .then_with(|| crate::version_cmp::compare(&b.version_id, &a.version_id))
+    });
+    Ok(versions)
+}
+
 #[tauri::command]
 pub async fn clear_asset_cache(app: AppHandle) -> Result<u64, String> {
-    let base = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("minecraft");
-    let assets_dir = base.join("assets");
+    refuse_shared_assets(&app)?;
+    let assets_dir = assets_root(&app)?;
 
     if !assets_dir.exists() {
         return Ok(0);
@@ -2753,22 +7495,305 @@ pub async fn clear_asset_cache(app: AppHandle) -> Result<u64, String> {
     Ok(size_before / 1024 / 1024) // Return MB cleared
 }
 
+/// What `prune_asset_indexes` removed, with index files and objects counted apart.
+#[derive(Serialize, Default)]
+pub struct AssetPruneSummary {
+    pub removed_indexes: Vec<String>,
+    pub indexes: ClearedFiles,
+    pub objects: ClearedFiles,
+    /// Set when objects were left alone because an installed version's index is missing
+    /// or couldn't be determined
+    pub objects_skipped: Option<String>,
+}
+
+/// Asset index ids referenced by every version JSON in `versions_dir`, following
+/// `inheritsFrom` for profiles that leave `assetIndex` to their parent. Versions whose
+/// index can't be worked out (unreadable JSON, missing parent) go into `unknown`.
+fn referenced_asset_indexes(
+    versions_dir: &Path,
+    global_versions: &Path,
+    used: &mut std::collections::HashSet<String>,
+    unknown: &mut Vec<String>,
+) {
+    let Ok(entries) = fs::read_dir(versions_dir) else {
+        return;
+    };
+    for entry in entries.flatten().filter(|e| e.path().is_dir()) {
+        let version_id = entry.file_name().to_string_lossy().to_string();
+        let mut json_path = entry.path().join(format!("{}.json", version_id));
+        let mut found = false;
+        // A short chain is all loaders produce; the bound guards against cycles
+        for _ in 0..4 {
+            let Some(json) = fs::read_to_string(&json_path)
+                .ok()
+                .and_then(|text| serde_json::from_str::<serde_json::Value>(&text).ok())
+            else {
+                break;
+            };
+            if let Some(index) = json["assetIndex"]["id"]
+                .as_str()
+                .or(json["assets"].as_str())
+            {
+                used.insert(index.to_string());
+                found = true;
+                break;
+            }
+            let Some(parent) = json["inheritsFrom"].as_str() else {
+                break;
+            };
+            json_path = global_versions
+                .join(parent)
+                .join(format!("{}.json", parent));
+        }
+        if !found {
+            unknown.push(version_id);
+        }
+    }
+}
+
+/// Remove `assets/indexes/*.json` that no installed version (global or instance-private)
+/// points at, keeping the newest `keep_unused_asset_indexes` of them, then remove objects
+/// no remaining index lists. Objects are shared by hash, so one referenced by any kept
+/// index is never deleted.
+#[tauri::command]
+pub async fn prune_asset_indexes(app: AppHandle) -> Result<AssetPruneSummary, String> {
+    refuse_shared_assets(&app)?;
+    let settings = crate::settings::get_settings(app.clone()).unwrap_or_default();
+    let global_versions = versions_root(&app)?;
+
+    let mut used = std::collections::HashSet::new();
+    let mut unknown = Vec::new();
+    referenced_asset_indexes(&global_versions, &global_versions, &mut used, &mut unknown);
+    if let Ok(instances) = fs::read_dir(instances_root(&app)?) {
+        for entry in instances.flatten() {
+            referenced_asset_indexes(
+                &entry.path().join("versions"),
+                &global_versions,
+                &mut used,
+                &mut unknown,
+            );
+        }
+    }
+
+    let summary = prune_assets(
+        &assets_root(&app)?,
+        &used,
+        &unknown,
+        settings.keep_unused_asset_indexes as usize,
+    )?;
+    log::info!(
+        "Pruned {} asset indexes ({} bytes) and {} objects ({} bytes)",
+        summary.indexes.files,
+        summary.indexes.bytes,
+        summary.objects.files,
+        summary.objects.bytes
+    );
+    Ok(summary)
+}
+
+/// The work behind `prune_asset_indexes` for the indexes in `used`, with `unknown`
+/// naming versions whose index couldn't be determined.
+fn prune_assets(
+    assets_dir: &Path,
+    used: &std::collections::HashSet<String>,
+    unknown: &[String],
+    keep: usize,
+) -> Result<AssetPruneSummary, String> {
+    let indexes_dir = assets_dir.join("indexes");
+    let objects_dir = assets_dir.join("objects");
+
+    let mut summary = AssetPruneSummary::default();
+    let mut unused = Vec::new();
+    let mut kept = Vec::new();
+    if let Ok(entries) = fs::read_dir(&indexes_dir) {
+        for path in entries.flatten().map(|e| e.path()) {
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let id = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if used.contains(&id) {
+                kept.push(path);
+            } else {
+                let modified = fs::metadata(&path)
+                    .and_then(|m| m.modified())
+                    .unwrap_or(std::time::UNIX_EPOCH);
+                unused.push((modified, id, path));
+            }
+        }
+    }
+    unused.sort_by_key(|u| std::cmp::Reverse(u.0));
+    for (i, (_, id, path)) in unused.into_iter().enumerate() {
+        if i < keep {
+            kept.push(path);
+            continue;
+        }
+        let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        fs::remove_file(&path).map_err(|e| format!("Failed to remove index {}: {}", id, e))?;
+        summary.indexes.files += 1;
+        summary.indexes.bytes += size;
+        summary.removed_indexes.push(id);
+    }
+
+    // Without every live index we can't tell which objects are still needed
+    if let Some(version) = unknown.first() {
+        summary.objects_skipped = Some(format!(
+            "The asset index of installed version {} could not be determined",
+            version
+        ));
+        return Ok(summary);
+    }
+    if let Some(missing) = used
+        .iter()
+        .find(|id| !indexes_dir.join(format!("{}.json", id)).exists())
+    {
+        summary.objects_skipped = Some(format!(
+            "Asset index {} is used by an installed version but missing on disk",
+            missing
+        ));
+        return Ok(summary);
+    }
+    let mut live_hashes = std::collections::HashSet::new();
+    for path in &kept {
+        let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let index: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| format!("Unreadable asset index {}: {}", path.display(), e))?;
+        if let Some(objects) = index["objects"].as_object() {
+            live_hashes.extend(
+                objects
+                    .values()
+                    .filter_map(|o| o["hash"].as_str().map(|h| h.to_string())),
+            );
+        }
+    }
+    if let Ok(buckets) = fs::read_dir(&objects_dir) {
+        for bucket in buckets.flatten().filter(|b| b.path().is_dir()) {
+            remove_files_in(
+                &bucket.path(),
+                |p| {
+                    p.file_name()
+                        .is_some_and(|n| !live_hashes.contains(n.to_string_lossy().as_ref()))
+                },
+                None,
+                &mut summary.objects,
+            );
+        }
+    }
+    Ok(summary)
+}
+
+/// Recursive size of everything under `dir`. Symlinks (e.g. imported saves) aren't
+/// followed, so data living elsewhere isn't counted here.
 fn calculate_dir_size(dir: &std::path::Path) -> Result<u64, String> {
     let mut size = 0;
 
     if dir.is_dir() {
         for entry in fs::read_dir(dir).map_err(|e| e.to_string())?.flatten() {
-            let path = entry.path();
-            if path.is_dir() {
-                size += calculate_dir_size(&path)?;
-            } else {
-                size += fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                size += calculate_dir_size(&entry.path())?;
+            } else if file_type.is_file() {
+                size += entry.metadata().map(|m| m.len()).unwrap_or(0);
             }
         }
     }
 
     Ok(size)
 }
+
+#[derive(Serialize)]
+pub struct InstanceSize {
+    pub instance_id: String,
+    /// Everything in the instance's own folder, in bytes
+    pub total: u64,
+    pub mods: u64,
+    pub saves: u64,
+    pub resourcepacks: u64,
+    /// This instance's share of version jars and libraries it uses, split evenly between
+    /// the instances using the same version. Assets are shared by all and not included.
+    pub shared_versions_estimate: u64,
+}
+
+fn instance_size(
+    app: &AppHandle,
+    instance: &Instance,
+    instances: &[Instance],
+) -> Result<InstanceSize, String> {
+    let root = instance_dir(app, &instance.id)?;
+    let game_dir = root.join(".minecraft");
+
+    let version_id = instance.launch_version_id();
+    let mc_version = instance
+        .mc_version
+        .clone()
+        .unwrap_or(instance.version.clone());
+    let sharers = |matches: &dyn Fn(&Instance) -> bool| {
+        instances.iter().filter(|i| matches(i)).count().max(1) as u64
+    };
+    let versions = versions_root(app)?;
+
+    let version_users = sharers(&|i| i.launch_version_id() == version_id);
+    let mut version_bytes = calculate_dir_size(&versions.join(&version_id))?;
+    let libraries = minecraft_root(app)?.join("libraries");
+    if let Ok(text) = fs::read_to_string(
+        versions
+            .join(&version_id)
+            .join(format!("{version_id}.json")),
+    ) {
+        if let Ok(version) = serde_json::from_str::<VersionJson>(&text) {
+            version_bytes += version
+                .libraries
+                .iter()
+                .filter_map(|lib| lib.downloads.artifact.as_ref())
+                .filter_map(|a| fs::metadata(libraries.join(&a.path)).ok())
+                .map(|m| m.len())
+                .sum::<u64>();
+        }
+    }
+    let mut shared = version_bytes / version_users;
+    if version_id != mc_version {
+        // The vanilla version a loader profile inherits from is shared more widely
+        let vanilla_users =
+            sharers(&|i| i.mc_version.as_ref().unwrap_or(&i.version) == &mc_version);
+        shared += calculate_dir_size(&versions.join(&mc_version))? / vanilla_users;
+    }
+
+    Ok(InstanceSize {
+        instance_id: instance.id.clone(),
+        total: calculate_dir_size(&root)?,
+        mods: calculate_dir_size(&game_dir.join("mods"))?,
+        saves: calculate_dir_size(&game_dir.join("saves"))?,
+        resourcepacks: calculate_dir_size(&game_dir.join("resourcepacks"))?,
+        shared_versions_estimate: shared,
+    })
+}
+
+#[tauri::command]
+pub async fn get_instance_size(
+    app: AppHandle,
+    instance_id: String,
+) -> Result<InstanceSize, String> {
+    let instances = list_instances(app.clone()).await?;
+    let instance = instances
+        .iter()
+        .find(|i| i.id == instance_id)
+        .ok_or("Instance not found")?;
+    instance_size(&app, instance, &instances)
+}
+
+/// Sizes for every instance, so the instance list can be sorted by disk usage.
+#[tauri::command]
+pub async fn list_instance_sizes(app: AppHandle) -> Result<Vec<InstanceSize>, String> {
+    let instances = list_instances(app.clone()).await?;
+    instances
+        .iter()
+        .map(|i| instance_size(&app, i, &instances))
+        .collect()
+}
 // --- System Information ---
 
 #[derive(serde::Serialize)]
@@ -2808,7 +7833,166 @@ pub async fn get_system_info(app: AppHandle) -> Result<SystemInfo, String> {
         version: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
         java_version,
         java_path,
-        total_memory: None, // Could be implemented with system info crate
+        total_memory: crate::settings::total_memory_bytes(),
         launcher_version: env!("CARGO_PKG_VERSION").to_string(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_loader_version_strips_only_mc_suffixes() {
+        for (version, normalized) in [
+            ("0.15.0", "0.15.0"),
+            ("0.15.0+build.1", "0.15.0+build.1"),
+            ("0.141.2+1.21.11", "0.141.2"),
+        ] {
+            assert_eq!(normalize_loader_version(version), normalized, "{}", version);
+        }
+    }
+
+    #[test]
+    fn glob_match_cases() {
+        for (pattern, path, expected) in [
+            ("config", "config", true),
+            ("config", "config/foo", false),
+            ("*.log", "latest.log", true),
+            ("*.log", "logs/latest.log", false),
+            ("webcache*", "webcache2", true),
+            ("c?nfig", "config", true),
+            ("**/saves", "saves", true),
+            ("**/saves", "config/foo/saves", true),
+            ("config/*.json", "config/a.json", true),
+            ("config/*.json", "config/a/b.json", false),
+        ] {
+            assert_eq!(
+                glob_match(pattern.as_bytes(), path.as_bytes()),
+                expected,
+                "{} vs {}",
+                pattern,
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn export_excludes_anchored_and_unanchored() {
+        let globs = |g: &[&str]| g.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        for (patterns, rel, is_dir, expected) in [
+            // Anchored: only at the instance root
+            (globs(&["/config/"]), "config", true, true),
+            (globs(&["/saves/"]), "config/foo/saves", true, false),
+            (globs(&["config/foo"]), "config/foo", true, true),
+            (globs(&["config/foo"]), "mods/config/foo", true, false),
+            // Unanchored: the name at any depth
+            (
+                globs(&["session.lock"]),
+                "saves/world/session.lock",
+                false,
+                true,
+            ),
+            (globs(&["*.bak"]), "config/a/settings.bak", false, true),
+            // A trailing `/` only matches directories
+            (globs(&["/logs/"]), "logs", false, false),
+            (globs(&["/logs/"]), "logs", true, true),
+        ] {
+            assert_eq!(
+                export_excluded(rel, is_dir, &patterns),
+                expected,
+                "{:?} vs {}",
+                patterns,
+                rel
+            );
+        }
+    }
+
+    /// An assets dir with indexes `kept` and `old` sharing one object, plus one object each.
+    fn asset_fixture(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tauri-mc-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        for (index, hashes) in [("kept", ["aa01", "bb01"]), ("old", ["aa01", "cc01"])] {
+            let objects: serde_json::Map<_, _> = hashes
+                .iter()
+                .map(|h| {
+                    (
+                        format!("file-{}", h),
+                        serde_json::json!({ "hash": h, "size": 1 }),
+                    )
+                })
+                .collect();
+            fs::create_dir_all(dir.join("indexes")).unwrap();
+            fs::write(
+                dir.join("indexes").join(format!("{}.json", index)),
+                serde_json::json!({ "objects": objects }).to_string(),
+            )
+            .unwrap();
+            for hash in hashes {
+                let bucket = dir.join("objects").join(&hash[..2]);
+                fs::create_dir_all(&bucket).unwrap();
+                fs::write(bucket.join(hash), b"x").unwrap();
+            }
+        }
+        dir
+    }
+
+    fn object_exists(dir: &Path, hash: &str) -> bool {
+        dir.join("objects").join(&hash[..2]).join(hash).exists()
+    }
+
+    #[test]
+    fn prune_keeps_objects_shared_with_a_kept_index() {
+        let dir = asset_fixture("prune-shared");
+        let used = HashSet::from(["kept".to_string()]);
+        let summary = prune_assets(&dir, &used, &[], 0).unwrap();
+
+        assert_eq!(summary.removed_indexes, vec!["old".to_string()]);
+        assert!(object_exists(&dir, "aa01"));
+        assert!(object_exists(&dir, "bb01"));
+        assert!(!object_exists(&dir, "cc01"));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn prune_leaves_objects_when_a_version_is_unknown() {
+        let dir = asset_fixture("prune-unknown");
+        let used = HashSet::from(["kept".to_string()]);
+        let summary = prune_assets(&dir, &used, &["1.20.4".to_string()], 0).unwrap();
+
+        assert!(summary.objects_skipped.is_some());
+        assert_eq!(summary.objects.files, 0);
+        assert!(object_exists(&dir, "cc01"));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn referenced_indexes_reports_unresolvable_versions() {
+        let dir = std::env::temp_dir().join(format!(
+            "tauri-mc-{}-referenced-indexes",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        for (id, json) in [
+            ("1.20.4", r#"{"assetIndex":{"id":"12"}}"#),
+            (
+                "fabric-loader-0.15.11-1.20.4",
+                r#"{"inheritsFrom":"1.20.4"}"#,
+            ),
+            ("fabric-loader-0.15.11-1.19", r#"{"inheritsFrom":"1.19"}"#),
+            ("broken", "{"),
+        ] {
+            fs::create_dir_all(dir.join(id)).unwrap();
+            fs::write(dir.join(id).join(format!("{}.json", id)), json).unwrap();
+        }
+
+        let mut used = HashSet::new();
+        let mut unknown = Vec::new();
+        referenced_asset_indexes(&dir, &dir, &mut used, &mut unknown);
+        unknown.sort();
+
+        assert_eq!(used, HashSet::from(["12".to_string()]));
+        assert_eq!(unknown, vec!["broken", "fabric-loader-0.15.11-1.19"]);
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -1,28 +1,301 @@
+use crate::error::AppError;
+use futures_util::StreamExt;
+use sha1::{Digest, Sha1};
+use sha2::{Sha256, Sha512};
 use std::fs;
+use std::io::Read;
 use std::path::Path;
+use tokio::io::AsyncWriteExt;
 
-pub async fn download_to_file(url: &str, path: &Path) -> Result<(), String> {
+/// Map a non-success HTTP status to an error, keeping a snippet of the body for context.
+fn http_error(status: reqwest::StatusCode, body: &str) -> AppError {
+    let snippet: String = body.chars().take(200).collect();
+    let message = format!("HTTP {} response: {}", status.as_u16(), snippet);
+    if status == reqwest::StatusCode::NOT_FOUND {
+        AppError::NotFound(message)
+    } else {
+        AppError::Network(message)
+    }
+}
+
+pub async fn download_to_file(url: &str, path: &Path) -> Result<(), AppError> {
     if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        fs::create_dir_all(parent)?;
     }
 
-    let bytes = reqwest::get(url)
-        .await
-        .map_err(|e| e.to_string())?
-        .bytes()
-        .await
-        .map_err(|e| e.to_string())?;
+    let res = crate::http::client().get(url).send().await?;
+    let status = res.status();
+    if !status.is_success() {
+        let body = res.text().await.unwrap_or_default();
+        return Err(http_error(status, &body));
+    }
 
-    fs::write(path, bytes).map_err(|e| e.to_string())?;
+    // Stream into a side file so large downloads aren't buffered and a dropped
+    // connection never leaves a truncated file at `path`
+    let part = path.with_file_name(format!(
+        "{}.part",
+        path.file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("download")
+    ));
+    let result: Result<(), AppError> = async {
+        let mut file = tokio::fs::File::create(&part).await?;
+        let mut stream = res.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            file.write_all(&chunk?).await?;
+        }
+        file.flush().await?;
+        Ok(())
+    }
+    .await;
+    if let Err(e) = result {
+        let _ = fs::remove_file(&part);
+        return Err(e);
+    }
+    fs::rename(&part, path)?;
     Ok(())
 }
-pub async fn download_text(url: &str) -> Result<String, String> {
-    let res = reqwest::get(url).await.map_err(|e| e.to_string())?;
+
+/// `download_verified` with up to `attempts` tries and exponential backoff between them.
+/// A hash mismatch counts as a failed try, since a bad transfer is worth fetching again.
+pub async fn download_with_retry(
+    url: &str,
+    path: &Path,
+    expected: Option<&FileHash>,
+    attempts: u32,
+) -> Result<(), AppError> {
+    let mut attempt = 1;
+    loop {
+        match download_verified(url, path, expected).await {
+            Ok(()) => return Ok(()),
+            // A missing file won't appear by asking again
+            Err(e @ AppError::NotFound(_)) => return Err(e),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(e) => {
+                let wait = std::time::Duration::from_secs(2u64.pow(attempt));
+                log::warn!(
+                    "Download of {} failed ({}), retrying in {}s",
+                    url,
+                    e,
+                    wait.as_secs()
+                );
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+fn repo_url(repo: &str, path: &str) -> String {
+    format!(
+        "{}/{}",
+        repo.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    )
+}
+
+/// Find the first repo in `repos` that serves the Maven `path` and return its full URL.
+pub async fn resolve_maven_artifact(path: &str, repos: &[String]) -> Option<String> {
+    let client = crate::http::client();
+    for repo in repos {
+        let url = repo_url(repo, path);
+        match client.head(&url).send().await {
+            Ok(res) if res.status().is_success() => {
+                log::debug!("[maven] resolved {} from {}", path, repo);
+                return Some(url);
+            }
+            _ => continue,
+        }
+    }
+    None
+}
+
+/// Download a Maven `path` from the first repo in `repos` that has it. Returns the URL used.
+pub async fn download_from_repos(
+    path: &str,
+    target: &Path,
+    repos: &[String],
+) -> Result<String, AppError> {
+    let mut last_err = AppError::NotFound(format!("{} not found in any Maven repo", path));
+    for repo in repos {
+        let url = repo_url(repo, path);
+        match download_to_file(&url, target).await {
+            Ok(()) => {
+                log::debug!("[maven] downloaded {} from {}", path, repo);
+                return Ok(url);
+            }
+            Err(e) => last_err = e,
+        }
+    }
+    Err(last_err)
+}
+
+pub async fn download_text(url: &str) -> Result<String, AppError> {
+    let res = crate::http::client().get(url).send().await?;
     let status = res.status();
-    let text = res.text().await.map_err(|e| e.to_string())?;
+    let text = res.text().await?;
     if !status.is_success() {
-        let snippet: String = text.chars().take(200).collect();
-        return Err(format!("HTTP {} response: {}", status.as_u16(), snippet));
+        return Err(http_error(status, &text));
     }
     Ok(text)
 }
+
+/// Hex-encoded digest of a file on disk, read in chunks so large jars aren't buffered whole.
+fn hash_file<D: Digest>(path: &Path) -> Result<String, AppError> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = D::new();
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Hex-encoded SHA1 of a file on disk.
+pub fn sha1_file(path: &Path) -> Result<String, AppError> {
+    hash_file::<Sha1>(path)
+}
+
+/// Hex-encoded SHA-256 of a file on disk.
+pub fn sha256_file(path: &Path) -> Result<String, AppError> {
+    hash_file::<Sha256>(path)
+}
+
+/// Hex-encoded SHA-512 of a file on disk.
+pub fn sha512_file(path: &Path) -> Result<String, AppError> {
+    hash_file::<Sha512>(path)
+}
+
+/// The hash a download is expected to have. Mojang metadata gives SHA1,
+/// Modrinth gives SHA512 (and sometimes only that).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileHash {
+    Sha1(String),
+    Sha512(String),
+}
+
+impl FileHash {
+    /// Pick the strongest hash from a Modrinth-style `algorithm -> hex` map.
+    pub fn from_hashes(hashes: &std::collections::HashMap<String, String>) -> Option<Self> {
+        hashes
+            .get("sha512")
+            .map(|h| FileHash::Sha512(h.clone()))
+            .or_else(|| hashes.get("sha1").map(|h| FileHash::Sha1(h.clone())))
+    }
+
+    fn algorithm(&self) -> &'static str {
+        match self {
+            FileHash::Sha1(_) => "sha1",
+            FileHash::Sha512(_) => "sha512",
+        }
+    }
+
+    fn expected(&self) -> &str {
+        match self {
+            FileHash::Sha1(h) | FileHash::Sha512(h) => h,
+        }
+    }
+}
+
+/// Check a file on disk against `expected`. An empty hash means "unknown" and passes.
+pub fn verify_hash(path: &Path, expected: &FileHash) -> Result<(), AppError> {
+    if expected.expected().is_empty() {
+        return Ok(());
+    }
+    let actual = match expected {
+        FileHash::Sha1(_) => sha1_file(path)?,
+        FileHash::Sha512(_) => sha512_file(path)?,
+    };
+    if !actual.eq_ignore_ascii_case(expected.expected()) {
+        return Err(AppError::Verification(format!(
+            "{} mismatch for {} (expected {}, got {})",
+            expected.algorithm(),
+            path.display(),
+            expected.expected(),
+            actual
+        )));
+    }
+    Ok(())
+}
+
+/// `download_to_file`, then check the result against `expected` if one is known.
+/// A file that fails the check is removed rather than left in place looking installed.
+pub async fn download_verified(
+    url: &str,
+    path: &Path,
+    expected: Option<&FileHash>,
+) -> Result<(), AppError> {
+    download_to_file(url, path).await?;
+    if let Some(expected) = expected {
+        if let Err(e) = verify_hash(path, expected) {
+            let _ = fs::remove_file(path);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// Check a downloaded file against its expected size and SHA1.
+/// An empty `sha1` or a zero `size` means "unknown" and is not checked.
+pub fn verify_file(path: &Path, sha1: &str, size: u64) -> Result<(), AppError> {
+    let actual_size = fs::metadata(path)?.len();
+    if size != 0 && actual_size != size {
+        return Err(AppError::Verification(format!(
+            "size mismatch for {} (expected {}, got {})",
+            path.display(),
+            size,
+            actual_size
+        )));
+    }
+    verify_hash(path, &FileHash::Sha1(sha1.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// SHA1 of the five bytes `hello`
+    const HELLO_SHA1: &str = "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d";
+
+    fn temp_file(name: &str, contents: &[u8]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("tauri-mc-{}-{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn verify_file_accepts_matching_sha1_and_size() {
+        let path = temp_file("verify-ok", b"hello");
+        assert!(verify_file(&path, HELLO_SHA1, 5).is_ok());
+        // Hex case doesn't matter
+        assert!(verify_file(&path, &HELLO_SHA1.to_uppercase(), 5).is_ok());
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn verify_file_rejects_size_mismatch() {
+        let path = temp_file("verify-size", b"hello");
+        let err = verify_file(&path, HELLO_SHA1, 6).unwrap_err();
+        assert!(matches!(err, AppError::Verification(_)));
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn verify_file_rejects_sha1_mismatch() {
+        let path = temp_file("verify-hash", b"hellp");
+        let err = verify_file(&path, HELLO_SHA1, 5).unwrap_err();
+        assert!(matches!(err, AppError::Verification(_)));
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn verify_file_skips_unknown_sha1_and_size() {
+        let path = temp_file("verify-unknown", b"anything");
+        assert!(verify_file(&path, "", 0).is_ok());
+        let _ = fs::remove_file(path);
+    }
+}
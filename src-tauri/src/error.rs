@@ -0,0 +1,91 @@
+use serde::Serialize;
+use std::fmt;
+
+/// Categorized error for the download/install/loader code paths.
+/// Serializes as `{ "kind": "...", "message": "..." }` so the frontend can react
+/// to the category instead of pattern-matching English strings.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum AppError {
+    Network(String),
+    Io(String),
+    Parse(String),
+    NotFound(String),
+    /// A Minecraft version id missing from Mojang's manifest, even after a refresh
+    /// (e.g. a typo, or a snapshot published moments ago)
+    UnknownVersion(String),
+    LoaderUnsupported(String),
+    Verification(String),
+    Other(String),
+}
+
+impl AppError {
+    pub fn message(&self) -> &str {
+        match self {
+            AppError::Network(m)
+            | AppError::Io(m)
+            | AppError::Parse(m)
+            | AppError::NotFound(m)
+            | AppError::UnknownVersion(m)
+            | AppError::LoaderUnsupported(m)
+            | AppError::Verification(m)
+            | AppError::Other(m) => m,
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<reqwest::Error> for AppError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_decode() {
+            AppError::Parse(e.to_string())
+        } else {
+            AppError::Network(e.to_string())
+        }
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(e: std::io::Error) -> Self {
+        AppError::Io(e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(e: serde_json::Error) -> Self {
+        AppError::Parse(e.to_string())
+    }
+}
+
+impl From<zip::result::ZipError> for AppError {
+    fn from(e: zip::result::ZipError) -> Self {
+        AppError::Parse(e.to_string())
+    }
+}
+
+// Most of the codebase still uses `Result<T, String>`; these keep `?` working
+// in both directions while the conversion is incremental.
+impl From<String> for AppError {
+    fn from(e: String) -> Self {
+        AppError::Other(e)
+    }
+}
+
+impl From<&str> for AppError {
+    fn from(e: &str) -> Self {
+        AppError::Other(e.to_string())
+    }
+}
+
+impl From<AppError> for String {
+    fn from(e: AppError) -> Self {
+        e.to_string()
+    }
+}
@@ -0,0 +1,79 @@
+//! The single place HTTP clients are built, so proxy and timeout settings reach every request.
+
+use crate::settings::Settings;
+use once_cell::sync::Lazy;
+use reqwest::{Client, NoProxy, Proxy};
+use std::sync::RwLock;
+use std::time::Duration;
+
+const USER_AGENT: &str = concat!("tauri-mc-launcher/", env!("CARGO_PKG_VERSION"));
+
+static CLIENT: Lazy<RwLock<Client>> =
+    Lazy::new(|| RwLock::new(build(&Settings::default()).expect("failed to build HTTP client")));
+
+/// The shared client. Cheap to call: clones share one connection pool.
+pub fn client() -> Client {
+    CLIENT.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Rebuild the shared client from `settings`; fails on a malformed proxy URL.
+pub fn configure(settings: &Settings) -> Result<(), String> {
+    let client = build(settings)?;
+    *CLIENT.write().unwrap_or_else(|e| e.into_inner()) = client;
+    Ok(())
+}
+
+/// A setting that was left blank counts as unset.
+fn configured(value: &Option<String>) -> Option<String> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+}
+
+fn from_env(names: &[&str]) -> Option<String> {
+    names
+        .iter()
+        .find_map(|name| std::env::var(name).ok())
+        .filter(|v| !v.trim().is_empty())
+}
+
+fn build(settings: &Settings) -> Result<Client, String> {
+    let mut builder = Client::builder()
+        .user_agent(USER_AGENT)
+        .http1_only() // 🔥 critical fix
+        .pool_max_idle_per_host(4)
+        .pool_idle_timeout(Duration::from_secs(30))
+        .tcp_keepalive(Duration::from_secs(30))
+        // A hung connection must surface as an error instead of stalling an install
+        .connect_timeout(Duration::from_secs(settings.connect_timeout_secs.max(1)));
+    if settings.request_timeout_secs > 0 {
+        builder = builder.timeout(Duration::from_secs(settings.request_timeout_secs));
+    }
+
+    let http = configured(&settings.http_proxy);
+    let https = configured(&settings.https_proxy);
+    let bypass = configured(&settings.no_proxy);
+    // With nothing configured reqwest's own detection applies (env vars, and the
+    // registry on Windows). Any explicit proxy turns that off, so the settings that
+    // were left unset fall back to the env vars by hand.
+    if http.is_some() || https.is_some() || bypass.is_some() {
+        let no_proxy = match bypass {
+            Some(list) => NoProxy::from_string(&list),
+            None => NoProxy::from_env(),
+        };
+        if let Some(url) = http.or_else(|| from_env(&["HTTP_PROXY", "http_proxy"])) {
+            let proxy =
+                Proxy::http(&url).map_err(|e| format!("Invalid HTTP proxy {}: {}", url, e))?;
+            builder = builder.proxy(proxy.no_proxy(no_proxy.clone()));
+        }
+        if let Some(url) = https.or_else(|| from_env(&["HTTPS_PROXY", "https_proxy"])) {
+            let proxy =
+                Proxy::https(&url).map_err(|e| format!("Invalid HTTPS proxy {}: {}", url, e))?;
+            builder = builder.proxy(proxy.no_proxy(no_proxy));
+        }
+    }
+
+    builder.build().map_err(|e| e.to_string())
+}
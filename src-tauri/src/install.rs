@@ -1,78 +1,285 @@
 use crate::{
     assets::{AssetIndexJson, AssetObject},
-    download::{download_text, download_to_file},
+    commands::{assets_root, minecraft_root},
+    download::{download_from_repos, download_text, download_with_retry, verify_file},
+    error::AppError,
+    instance::Instance,
     rules::rules_allow,
-    version::VersionJson,
+    settings::VerifyMode,
+    version::{Artifact, VersionJson},
 };
 
 use futures_util::stream::{FuturesUnordered, StreamExt};
-use once_cell::sync::Lazy;
-use reqwest::Client;
 
 use std::{
+    collections::HashMap,
     fs,
-    path::Path,
+    path::{Path, PathBuf},
     sync::{Arc, Mutex},
     time::{Duration, Instant},
 };
 
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Emitter};
 use tokio::{fs::File, io::AsyncWriteExt};
 
 const ASSET_BASE_URL: &str = "https://resources.download.minecraft.net";
 
+/// Machine-readable install step, emitted as `install-phase` so the UI can render
+/// (and translate) its own text. Serializes as `{ "phase": "installing_loader", "loader": "fabric" }`.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "phase", rename_all = "snake_case")]
+pub enum InstallPhase {
+    InstallingVanilla { mc_version: String },
+    DownloadingLibraries,
+    DownloadingAssets,
+    InstallingLoader { loader: String },
+    DownloadingMods,
+    ExtractingOverrides,
+    Verifying,
+    Done,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct InstallPhaseEvent {
+    /// `None` for steps shared between instances (libraries, assets)
+    pub instance_id: Option<String>,
+    #[serde(flatten)]
+    pub phase: InstallPhase,
+}
+
+pub fn emit_install_phase(app: &AppHandle, instance_id: Option<&str>, phase: InstallPhase) {
+    let _ = app.emit(
+        "install-phase",
+        InstallPhaseEvent {
+            instance_id: instance_id.map(str::to_string),
+            phase,
+        },
+    );
+}
+
+/// Payload of `instance-install-error`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct InstallFailed {
+    pub instance: Instance,
+    pub error: String,
+}
+
+/// Payload of `instance-install-finished`, fired once per install whatever the outcome.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct InstallFinished {
+    pub instance: Instance,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Report the end of an install. `instance` must already carry its final state.
+pub fn emit_install_finished(app: &AppHandle, instance: &Instance, error: Option<&str>) {
+    match error {
+        None => {
+            let _ = app.emit("instance-install-complete", instance);
+        }
+        Some(error) => {
+            let _ = app.emit(
+                "instance-install-error",
+                InstallFailed {
+                    instance: instance.clone(),
+                    error: error.to_string(),
+                },
+            );
+        }
+    }
+    let _ = app.emit(
+        "instance-install-finished",
+        InstallFinished {
+            instance: instance.clone(),
+            success: error.is_none(),
+            error: error.map(str::to_string),
+        },
+    );
+}
+
 // 🔒 KEEP THIS LOW ON WINDOWS
 const ASSET_CONCURRENCY: usize = 4;
 const ASSET_RETRIES: usize = 3;
 
-static HTTP_CLIENT: Lazy<Client> = Lazy::new(|| {
-    Client::builder()
-        .http1_only() // 🔥 critical fix
-        .pool_max_idle_per_host(4)
-        .pool_idle_timeout(Duration::from_secs(30))
-        .tcp_keepalive(Duration::from_secs(30))
-        .build()
-        .expect("failed to build HTTP client")
-});
+/* ------------------------------ Progress ------------------------------ */
 
-/* ----------------------------- Libraries ----------------------------- */
+/// Byte counts shared by everything one install downloads (client jar, libraries and
+/// assets), so those can run side by side and still report one `install-progress`.
+struct InstallProgress {
+    total_bytes: u64,
+    downloaded_bytes: u64,
+    start: Instant,
+}
+
+type SharedProgress = Arc<Mutex<InstallProgress>>;
 
-pub async fn install_libraries(app: &AppHandle, version: &VersionJson) -> Result<(), String> {
-    let base = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("minecraft")
-        .join("libraries");
+fn new_progress(total_bytes: u64) -> SharedProgress {
+    Arc::new(Mutex::new(InstallProgress {
+        total_bytes,
+        downloaded_bytes: 0,
+        start: Instant::now(),
+    }))
+}
+
+/// Payload of `install-progress`.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallProgressEvent {
+    pub downloaded_bytes: u64,
+    pub total_bytes: u64,
+    /// 0 to 100
+    pub percent: f64,
+    /// Bytes per second since the install started
+    pub speed: f64,
+    /// Seconds left at the current speed
+    pub eta: f64,
+}
+
+/// A file that was already on disk: nothing left to transfer for it.
+fn skip_bytes(progress: &SharedProgress, size: u64) {
+    let mut p = progress.lock().unwrap();
+    p.total_bytes = p.total_bytes.saturating_sub(size);
+}
+
+fn advance(app: &AppHandle, progress: &SharedProgress, bytes: u64) {
+    let event = {
+        let mut p = progress.lock().unwrap();
+        p.downloaded_bytes += bytes;
+
+        let elapsed = p.start.elapsed().as_secs_f64().max(0.001);
+        let speed = p.downloaded_bytes as f64 / elapsed;
+        // Remaining is measured across the whole install, not just this file
+        let remaining = p.total_bytes.saturating_sub(p.downloaded_bytes) as f64;
+        let percent = if p.total_bytes == 0 {
+            100.0
+        } else {
+            (p.downloaded_bytes as f64 / p.total_bytes as f64 * 100.0).min(100.0)
+        };
+        InstallProgressEvent {
+            downloaded_bytes: p.downloaded_bytes,
+            total_bytes: p.total_bytes,
+            percent,
+            speed,
+            eta: remaining / speed,
+        }
+    };
+    app.emit("install-progress", event).ok();
+}
+
+/* ---------------------------- Whole version --------------------------- */
+
+/// Client jar, libraries and assets for version `id`, downloaded side by side with one
+/// shared `install-progress`. They run one after another instead when `sequential_install`
+/// is set, or when the side-by-side run hits a network error (some Windows setups choke
+/// on the extra connections); whatever already finished is then skipped.
+pub async fn install_game_files(
+    app: &AppHandle,
+    id: &str,
+    version: &VersionJson,
+) -> Result<(), AppError> {
+    let (objects, assets) = fetch_asset_index(app, version).await?;
+    let total_bytes = version.downloads.client.size
+        + library_artifacts(version)
+            .iter()
+            .map(|(a, _)| a.size)
+            .sum::<u64>()
+        + assets.iter().map(|a| a.size).sum::<u64>();
+
+    let settings = crate::settings::get_settings(app.clone()).unwrap_or_default();
+    if !settings.sequential_install {
+        let progress = new_progress(total_bytes);
+        let result = tokio::try_join!(
+            download_client_jar(app, id, version, &progress),
+            download_libraries(app, version, &progress),
+            download_assets(app, &objects, assets.clone(), &progress),
+        );
+        match result {
+            Ok(_) => return Ok(()),
+            Err(AppError::Network(e)) => {
+                log::warn!(
+                    "Parallel install of {} failed ({}), retrying one part at a time",
+                    id,
+                    e
+                );
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    let progress = new_progress(total_bytes);
+    download_client_jar(app, id, version, &progress).await?;
+    download_libraries(app, version, &progress).await?;
+    download_assets(app, &objects, assets, &progress).await
+}
 
+/* ----------------------------- Libraries ----------------------------- */
+
+// Network errors and timeouts are retried; a 404 goes straight to the Maven fallbacks
+const LIBRARY_ATTEMPTS: u32 = 3;
+
+/// Library jars and this OS's natives the version needs. The flag marks main artifacts,
+/// which may be looked up in the fallback Maven repos.
+fn library_artifacts(version: &VersionJson) -> Vec<(&Artifact, bool)> {
+    let os_key = if cfg!(target_os = "windows") {
+        "windows"
+    } else if cfg!(target_os = "macos") {
+        "osx"
+    } else {
+        "linux"
+    };
+
+    let mut artifacts = Vec::new();
     for lib in &version.libraries {
         if !rules_allow(&lib.rules) {
             continue;
         }
-
         if let Some(artifact) = &lib.downloads.artifact {
-            let target = base.join(&artifact.path);
-            if !target.exists() {
-                download_to_file(&artifact.url, &target).await?;
-            }
+            artifacts.push((artifact, true));
+        }
+        if let Some(artifact) = lib
+            .natives
+            .get(os_key)
+            .and_then(|classifier| lib.downloads.classifiers.get(classifier))
+        {
+            artifacts.push((artifact, false));
         }
+    }
+    artifacts
+}
 
-        let os_key = if cfg!(target_os = "windows") {
-            "windows"
-        } else if cfg!(target_os = "macos") {
-            "osx"
-        } else {
-            "linux"
-        };
+pub async fn install_libraries(app: &AppHandle, version: &VersionJson) -> Result<(), AppError> {
+    let total_bytes = library_artifacts(version).iter().map(|(a, _)| a.size).sum();
+    download_libraries(app, version, &new_progress(total_bytes)).await
+}
 
-        if let Some(classifier) = lib.natives.get(os_key) {
-            if let Some(artifact) = lib.downloads.classifiers.get(classifier) {
-                let target = base.join(&artifact.path);
-                if !target.exists() {
-                    download_to_file(&artifact.url, &target).await?;
-                }
+async fn download_libraries(
+    app: &AppHandle,
+    version: &VersionJson,
+    progress: &SharedProgress,
+) -> Result<(), AppError> {
+    emit_install_phase(app, None, InstallPhase::DownloadingLibraries);
+    let base = minecraft_root(app)?.join("libraries");
+    let repos = crate::settings::get_settings(app.clone())
+        .unwrap_or_default()
+        .maven_repositories;
+
+    for (artifact, use_repos) in library_artifacts(version) {
+        let target = base.join(&artifact.path);
+        if target.exists() {
+            skip_bytes(progress, artifact.size);
+            continue;
+        }
+        match download_with_retry(&artifact.url, &target, None, LIBRARY_ATTEMPTS).await {
+            Ok(()) => {}
+            // Some loaders point at repos that don't carry every library; try the fallbacks
+            Err(AppError::NotFound(_)) if use_repos => {
+                download_from_repos(&artifact.path, &target, &repos).await?;
             }
+            Err(e) => return Err(e),
         }
+        advance(app, progress, artifact.size);
     }
 
     Ok(())
@@ -84,62 +291,138 @@ pub async fn install_client_jar(
     app: &AppHandle,
     id: &str,
     version: &VersionJson,
-) -> Result<(), String> {
-    let jar_path = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("minecraft")
+) -> Result<(), AppError> {
+    let progress = new_progress(version.downloads.client.size);
+    download_client_jar(app, id, version, &progress).await
+}
+
+async fn download_client_jar(
+    app: &AppHandle,
+    id: &str,
+    version: &VersionJson,
+    progress: &SharedProgress,
+) -> Result<(), AppError> {
+    let jar_path = minecraft_root(app)?
         .join("versions")
         .join(id)
         .join(format!("{id}.jar"));
 
+    let client = &version.downloads.client;
+
     if jar_path.exists() {
-        return Ok(());
+        if verify_file(&jar_path, &client.sha1, client.size).is_ok() {
+            skip_bytes(progress, client.size);
+            return Ok(());
+        }
+        log::warn!(
+            "Client jar {} is corrupt, re-downloading",
+            jar_path.display()
+        );
+        let _ = fs::remove_file(&jar_path);
+    }
+
+    // One retry on a bad download, then give up with the verification error
+    let mut last_err = String::new();
+    for attempt in 1..=2 {
+        download_with_retry(&client.url, &jar_path, None, LIBRARY_ATTEMPTS).await?;
+        match verify_file(&jar_path, &client.sha1, client.size) {
+            Ok(()) => {
+                advance(app, progress, client.size);
+                return Ok(());
+            }
+            Err(e) => {
+                let _ = fs::remove_file(&jar_path);
+                log::warn!("{} (download attempt {})", e, attempt);
+                last_err = e.to_string();
+            }
+        }
+    }
+
+    Err(AppError::Verification(format!(
+        "Client jar verification failed: {}",
+        last_err
+    )))
+}
+
+/// Give a loader-derived version its client jar by linking (or copying) the vanilla
+/// jar it inherits from, checked against vanilla's SHA1, instead of downloading it again.
+pub async fn install_derived_client_jar(
+    app: &AppHandle,
+    derived_id: &str,
+    vanilla_id: &str,
+    vanilla: &VersionJson,
+) -> Result<(), AppError> {
+    let versions = minecraft_root(app)?.join("versions");
+    let derived_jar = versions.join(derived_id).join(format!("{derived_id}.jar"));
+    let client = &vanilla.downloads.client;
+
+    if derived_jar.exists() {
+        if verify_file(&derived_jar, &client.sha1, client.size).is_ok() {
+            return Ok(());
+        }
+        let _ = fs::remove_file(&derived_jar);
     }
 
-    download_to_file(&version.downloads.client.url, &jar_path).await
+    install_client_jar(app, vanilla_id, vanilla).await?;
+    let vanilla_jar = versions.join(vanilla_id).join(format!("{vanilla_id}.jar"));
+    if let Some(parent) = derived_jar.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // A hard link costs no space; fall back to copying across volumes or on filesystems without links
+    if fs::hard_link(&vanilla_jar, &derived_jar).is_err() {
+        fs::copy(&vanilla_jar, &derived_jar)?;
+    }
+
+    if let Err(e) = verify_file(&derived_jar, &client.sha1, client.size) {
+        let _ = fs::remove_file(&derived_jar);
+        return Err(AppError::Verification(format!(
+            "Client jar for {} does not match vanilla {}: {}",
+            derived_id, vanilla_id, e
+        )));
+    }
+    Ok(())
 }
 
 /* ------------------------------ Assets -------------------------------- */
 
-struct AssetProgress {
-    downloaded_bytes: u64,
-    downloaded_files: u64,
-    start: Instant,
+pub async fn install_assets(app: &AppHandle, version: &VersionJson) -> Result<(), AppError> {
+    let (objects, assets) = fetch_asset_index(app, version).await?;
+    let progress = new_progress(assets.iter().map(|a| a.size).sum());
+    download_assets(app, &objects, assets, &progress).await
 }
 
-pub async fn install_assets(app: &AppHandle, version: &VersionJson) -> Result<(), String> {
-    let base = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("minecraft")
-        .join("assets");
+/// Save the version's asset index and return the objects folder plus what it lists.
+async fn fetch_asset_index(
+    app: &AppHandle,
+    version: &VersionJson,
+) -> Result<(PathBuf, Vec<AssetObject>), AppError> {
+    let base = assets_root(app)?;
 
     let indexes = base.join("indexes");
     let objects = base.join("objects");
 
-    fs::create_dir_all(&indexes).map_err(|e| e.to_string())?;
-    fs::create_dir_all(&objects).map_err(|e| e.to_string())?;
+    fs::create_dir_all(&indexes)?;
+    fs::create_dir_all(&objects)?;
 
     // Download asset index
-    let index_text = download_text(&version.assetIndex.url).await?;
+    let asset_index = version.asset_index()?;
+    let index_text = download_text(&asset_index.url).await?;
     fs::write(
-        indexes.join(format!("{}.json", version.assetIndex.id)),
+        indexes.join(format!("{}.json", asset_index.id)),
         &index_text,
-    )
-    .map_err(|e| e.to_string())?;
-
-    let index: AssetIndexJson = serde_json::from_str(&index_text).map_err(|e| e.to_string())?;
+    )?;
 
-    let assets: Vec<AssetObject> = index.objects.values().cloned().collect();
+    let index: AssetIndexJson = serde_json::from_str(&index_text)?;
+    Ok((objects, index.objects.values().cloned().collect()))
+}
 
-    let progress = Arc::new(Mutex::new(AssetProgress {
-        downloaded_bytes: 0,
-        downloaded_files: 0,
-        start: Instant::now(),
-    }));
+async fn download_assets(
+    app: &AppHandle,
+    objects: &Path,
+    assets: Vec<AssetObject>,
+    progress: &SharedProgress,
+) -> Result<(), AppError> {
+    emit_install_phase(app, None, InstallPhase::DownloadingAssets);
 
     let mut in_flight = FuturesUnordered::new();
     let mut iter = assets.into_iter();
@@ -148,7 +431,7 @@ pub async fn install_assets(app: &AppHandle, version: &VersionJson) -> Result<()
     for _ in 0..ASSET_CONCURRENCY {
         if let Some(obj) = iter.next() {
             in_flight.push(spawn_asset(
-                objects.clone(),
+                objects.to_path_buf(),
                 app.clone(),
                 progress.clone(),
                 obj,
@@ -161,7 +444,7 @@ pub async fn install_assets(app: &AppHandle, version: &VersionJson) -> Result<()
 
         if let Some(obj) = iter.next() {
             in_flight.push(spawn_asset(
-                objects.clone(),
+                objects.to_path_buf(),
                 app.clone(),
                 progress.clone(),
                 obj,
@@ -174,129 +457,363 @@ pub async fn install_assets(app: &AppHandle, version: &VersionJson) -> Result<()
 }
 
 fn spawn_asset(
-    objects_dir: std::path::PathBuf,
+    objects_dir: PathBuf,
     app: AppHandle,
-    progress: Arc<Mutex<AssetProgress>>,
+    progress: SharedProgress,
     obj: AssetObject,
-) -> impl std::future::Future<Output = Result<(), String>> {
+) -> impl std::future::Future<Output = Result<(), AppError>> {
     async move {
-        for attempt in 1..=ASSET_RETRIES {
-            match download_asset_once(&objects_dir, &app, progress.clone(), &obj).await {
-                Ok(()) => return Ok(()),
-                Err(e) if attempt < ASSET_RETRIES => {
-                    eprintln!(
-                        "Retrying asset {} (attempt {}/{})",
-                        obj.hash, attempt, ASSET_RETRIES
-                    );
-                    tokio::time::sleep(Duration::from_millis(500)).await;
-                }
-                Err(e) => return Err(e),
+        with_retries(&obj.hash, ASSET_RETRIES, Duration::from_millis(500), || {
+            download_asset_once(&objects_dir, &app, &progress, &obj)
+        })
+        .await
+    }
+}
+
+/// Run `attempt_fn` up to `attempts` times, stopping at the first success.
+/// Out of attempts, the last error is what the caller sees.
+async fn with_retries<F, Fut>(
+    what: &str,
+    attempts: usize,
+    delay: Duration,
+    mut attempt_fn: F,
+) -> Result<(), AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<(), AppError>>,
+{
+    let mut attempt = 1;
+    loop {
+        match attempt_fn().await {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(e) => {
+                log::warn!(
+                    "Asset {} failed ({}), retrying (attempt {}/{})",
+                    what,
+                    e,
+                    attempt,
+                    attempts
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
             }
         }
-        Ok(())
     }
 }
 
 /* ------------------------ Asset Downloader ---------------------------- */
 
+fn size_matches(path: &Path, size: u64) -> bool {
+    fs::metadata(path).map(|m| m.len() == size).unwrap_or(false)
+}
+
 async fn download_asset_once(
     objects_dir: &Path,
     app: &AppHandle,
-    progress: Arc<Mutex<AssetProgress>>,
+    progress: &SharedProgress,
     obj: &AssetObject,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let hash = &obj.hash;
     let subdir = &hash[..2];
     let target = objects_dir.join(subdir).join(hash);
 
     if target.exists() {
-        if fs::metadata(&target)
-            .map(|m| m.len() == obj.size)
-            .unwrap_or(false)
-        {
-            let mut p = progress.lock().unwrap();
-            p.downloaded_files += 1;
+        if size_matches(&target, obj.size) {
+            skip_bytes(progress, obj.size);
             return Ok(());
         }
         let _ = fs::remove_file(&target);
     }
 
-    fs::create_dir_all(target.parent().unwrap()).map_err(|e| e.to_string())?;
+    fs::create_dir_all(target.parent().unwrap())?;
 
     let url = format!("{ASSET_BASE_URL}/{subdir}/{hash}");
 
-    let response = HTTP_CLIENT
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| e.to_string())?;
+    let response = crate::http::client().get(&url).send().await?;
 
     if !response.status().is_success() {
-        return Err(format!("HTTP {} for {}", response.status(), url));
+        return Err(AppError::Network(format!(
+            "HTTP {} for {}",
+            response.status(),
+            url
+        )));
     }
 
     let mut stream = response.bytes_stream();
-    let mut file = File::create(&target).await.map_err(|e| e.to_string())?;
+    let mut file = File::create(&target).await?;
 
     while let Some(chunk) = stream.next().await {
         let chunk = match chunk {
             Ok(c) => c,
             Err(e) => {
                 let _ = fs::remove_file(&target);
-                return Err(e.to_string());
+                return Err(e.into());
             }
         };
 
         file.write_all(&chunk).await.map_err(|e| {
             let _ = fs::remove_file(&target);
-            e.to_string()
+            AppError::from(e)
         })?;
 
-        let (downloaded, speed, eta) = {
-            let mut p = progress.lock().unwrap();
-            p.downloaded_bytes += chunk.len() as u64;
-
-            let elapsed = p.start.elapsed().as_secs_f64().max(0.001);
-            let speed = p.downloaded_bytes as f64 / elapsed;
-            let remaining = obj.size.saturating_sub(p.downloaded_bytes) as f64;
-            let eta = remaining / speed;
-
-            (p.downloaded_bytes, speed, eta)
-        };
-
-        app.emit(
-            "asset_progress",
-            serde_json::json!({
-                "downloadedBytes": downloaded,
-                "totalBytes": obj.size,
-                "speed": speed,
-                "eta": eta
-            }),
-        )
-        .ok();
-        std::println!(
-            "Downloading asset {}: {}/{} bytes ({:.2} bytes/s, ETA {:.1}s)",
-            hash,
-            downloaded,
-            obj.size,
-            speed,
-            eta
-        );
+        advance(app, progress, chunk.len() as u64);
+        log::trace!("Downloading asset {}: {} bytes", hash, chunk.len());
     }
 
-    file.flush().await.map_err(|e| e.to_string())?;
+    file.flush().await?;
 
-    let size = fs::metadata(&target).map_err(|e| e.to_string())?.len();
+    let size = fs::metadata(&target)?.len();
     if size != obj.size {
         let _ = fs::remove_file(&target);
-        return Err(format!(
+        return Err(AppError::Verification(format!(
             "size mismatch for {} (expected {}, got {})",
             hash, obj.size, size
-        ));
+        )));
     }
 
-    let mut p = progress.lock().unwrap();
-    p.downloaded_files += 1;
+    Ok(())
+}
+
+/* ---------------------------- Legacy assets --------------------------- */
+
+/// Lay out a `legacy` (1.6 to 1.7.2) or `pre-1.6` asset index by file name, which those
+/// versions read instead of the hashed objects: under `virtual/<index>/` in the assets
+/// folder, or in `resources/` of the game dir. Returns that folder, or `None` for
+/// hash-only indexes. Files already in place are left alone.
+pub fn prepare_legacy_assets(
+    app: &AppHandle,
+    version: &VersionJson,
+    game_dir: &Path,
+) -> Result<Option<PathBuf>, AppError> {
+    let assets = assets_root(app)?;
+    let index_id = &version.asset_index()?.id;
+    let index_text = fs::read_to_string(assets.join("indexes").join(format!("{index_id}.json")))?;
+    let index: AssetIndexJson = serde_json::from_str(&index_text)?;
+
+    let target = if index.map_to_resources {
+        game_dir.join("resources")
+    } else if index.is_virtual {
+        assets.join("virtual").join(index_id)
+    } else {
+        return Ok(None);
+    };
+
+    let objects = assets.join("objects");
+    let mut placed = 0;
+    for (name, obj) in &index.objects {
+        let dest = crate::commands::resolve_within(&target, name)?;
+        if size_matches(&dest, obj.size) {
+            continue;
+        }
+        let source = objects.join(&obj.hash[..2]).join(&obj.hash);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let _ = fs::remove_file(&dest);
+        // Same volume as the objects for `virtual/`, so usually a free hard link
+        if fs::hard_link(&source, &dest).is_err() {
+            fs::copy(&source, &dest)?;
+        }
+        placed += 1;
+    }
+    if placed > 0 {
+        log::info!(
+            "Placed {} legacy asset(s) for index {} in {}",
+            placed,
+            index_id,
+            target.display()
+        );
+    }
+    Ok(Some(target))
+}
 
+/* -------------------------- Launch Integrity -------------------------- */
+
+// Hashing is CPU- and disk-bound, so this runs on blocking threads
+const VERIFY_CONCURRENCY: usize = 8;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AssetVerifyProgress {
+    pub checked: usize,
+    pub total: usize,
+}
+
+/// Check every object in `index` with up to `VERIFY_CONCURRENCY` workers, emitting
+/// `asset-verify-progress` as it goes. Returns the hashes of missing or corrupt objects.
+pub async fn verify_assets(
+    app: &AppHandle,
+    index: &AssetIndexJson,
+    objects: &Path,
+    mode: VerifyMode,
+) -> Vec<String> {
+    // Several asset names can share one object
+    let unique: HashMap<&str, u64> = index
+        .objects
+        .values()
+        .map(|obj| (obj.hash.as_str(), obj.size))
+        .collect();
+    let total = unique.len();
+    let mut pending = unique.into_iter();
+
+    let spawn = |(hash, size): (&str, u64)| {
+        let hash = hash.to_string();
+        let target = objects.join(&hash[..2]).join(&hash);
+        async move {
+            let expected = hash.clone();
+            let ok = tokio::task::spawn_blocking(move || match mode {
+                // Asset objects are named by their hash
+                VerifyMode::Full => {
+                    target.exists() && verify_file(&target, &expected, size).is_ok()
+                }
+                _ => size_matches(&target, size),
+            })
+            .await
+            .unwrap_or(false);
+            (hash, ok)
+        }
+    };
+
+    let mut in_flight = FuturesUnordered::new();
+    for obj in pending.by_ref().take(VERIFY_CONCURRENCY) {
+        in_flight.push(spawn(obj));
+    }
+
+    let mut corrupt = Vec::new();
+    let mut checked = 0;
+    while let Some((hash, ok)) = in_flight.next().await {
+        if let Some(obj) = pending.next() {
+            in_flight.push(spawn(obj));
+        }
+        if !ok {
+            corrupt.push(hash);
+        }
+        checked += 1;
+        if checked % 100 == 0 || checked == total {
+            let _ = app.emit(
+                "asset-verify-progress",
+                AssetVerifyProgress { checked, total },
+            );
+        }
+    }
+    corrupt
+}
+
+/// Pre-launch check of libraries and assets. Anything missing or damaged is
+/// deleted and re-downloaded. `Quick` compares sizes, `Full` also hashes.
+pub async fn verify_installation(
+    app: &AppHandle,
+    version: &VersionJson,
+    mode: VerifyMode,
+) -> Result<(), AppError> {
+    if mode == VerifyMode::None {
+        return Ok(());
+    }
+    emit_install_phase(app, None, InstallPhase::Verifying);
+
+    let root = minecraft_root(app)?;
+    let check = |path: &Path, sha1: &str, size: u64| -> bool {
+        if !path.exists() {
+            return false;
+        }
+        let sha1 = if mode == VerifyMode::Full { sha1 } else { "" };
+        verify_file(path, sha1, size).is_ok()
+    };
+
+    let libraries = root.join("libraries");
+    let mut libraries_bad = 0;
+    for lib in &version.libraries {
+        if !rules_allow(&lib.rules) {
+            continue;
+        }
+        let artifacts = lib
+            .downloads
+            .artifact
+            .iter()
+            .chain(lib.downloads.classifiers.values());
+        for artifact in artifacts {
+            let target = libraries.join(&artifact.path);
+            if !check(&target, &artifact.sha1, artifact.size) {
+                let _ = fs::remove_file(&target);
+                libraries_bad += 1;
+            }
+        }
+    }
+
+    let assets = assets_root(app)?;
+    let index_path = assets
+        .join("indexes")
+        .join(format!("{}.json", version.asset_index()?.id));
+    let mut assets_bad = 0;
+    match fs::read_to_string(&index_path)
+        .ok()
+        .and_then(|t| serde_json::from_str::<AssetIndexJson>(&t).ok())
+    {
+        Some(index) => {
+            let objects = assets.join("objects");
+            for hash in verify_assets(app, &index, &objects, mode).await {
+                let _ = fs::remove_file(objects.join(&hash[..2]).join(&hash));
+                assets_bad += 1;
+            }
+        }
+        None => assets_bad += 1,
+    }
+
+    log::info!(
+        "Launch verification ({:?}): {} library and {} asset problem(s)",
+        mode,
+        libraries_bad,
+        assets_bad
+    );
+
+    if libraries_bad > 0 {
+        install_libraries(app, version).await?;
+    }
+    if assets_bad > 0 {
+        install_assets(app, version).await?;
+    }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[tokio::test]
+    async fn retries_return_the_last_error() {
+        let calls = Cell::new(0);
+        let result = with_retries("abc", ASSET_RETRIES, Duration::ZERO, || {
+            calls.set(calls.get() + 1);
+            let n = calls.get();
+            async move { Err(AppError::Network(format!("failure {}", n))) }
+        })
+        .await;
+
+        assert_eq!(calls.get(), ASSET_RETRIES);
+        match result {
+            Err(AppError::Network(msg)) => assert_eq!(msg, format!("failure {}", ASSET_RETRIES)),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_stop_on_success() {
+        let calls = Cell::new(0);
+        let result = with_retries("abc", ASSET_RETRIES, Duration::ZERO, || {
+            calls.set(calls.get() + 1);
+            let ok = calls.get() == 2;
+            async move {
+                if ok {
+                    Ok(())
+                } else {
+                    Err(AppError::Network("flaky".to_string()))
+                }
+            }
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(calls.get(), 2);
+    }
+}
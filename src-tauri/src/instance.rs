@@ -1,7 +1,14 @@
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Bumped whenever the on-disk layout of instance.json changes.
+pub const INSTANCE_SCHEMA_VERSION: u32 = 1;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Instance {
+    /// Format of this file; 0 for files written before versioning existed
+    #[serde(default)]
+    pub schema_version: u32,
     pub id: String,
     pub name: String,
     pub version: String,
@@ -20,14 +27,60 @@ pub struct Instance {
     pub min_memory: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub java_args: Option<String>,
+    /// Extra game arguments appended after the standard ones (e.g. `--quickPlayMultiplayer host`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_args: Option<String>,
+    /// `host` or `host:port` to join right after the game starts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_connect_server: Option<String>,
+    /// Folder name under `saves/` to load straight into (1.20+ Quick Play)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_load_world: Option<String>,
     #[serde(default)]
     pub java_warning_ignored: bool,
+    /// Launch in demo mode (`--demo`), for players without an account
+    #[serde(default)]
+    pub demo: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub loader: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub loader_version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mc_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Folder the instance is listed under; unset instances are ungrouped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub launch_profiles: Vec<LaunchProfile>,
+    /// Prefer version files under the instance's own `versions/` over the shared ones,
+    /// so a patched jar or profile here doesn't leak into other instances
+    #[serde(default)]
+    pub private_versions: bool,
+    /// Refuse anything that would change the game or loader version (curated modpacks)
+    #[serde(default)]
+    pub pinned_version: bool,
+    /// Check or update enabled mods before each launch; unset follows the global setting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_update_mods_on_launch: Option<crate::settings::ModsUpdateOnLaunch>,
+}
+
+/// A named set of launch overrides (e.g. "low RAM"). Fields left unset fall back
+/// to the instance's own values; set ones replace them for that launch only.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct LaunchProfile {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_memory: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_memory: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub java_args: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_args: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub java_path_override: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -40,3 +93,110 @@ pub enum InstanceState {
     Crashed,
     Error,
 }
+
+impl Instance {
+    /// Fallback for fields an old or damaged instance.json can't supply.
+    fn placeholder(id: &str) -> Self {
+        Self {
+            schema_version: INSTANCE_SCHEMA_VERSION,
+            id: id.to_string(),
+            name: id.to_string(),
+            version: String::new(),
+            state: InstanceState::Ready,
+            created_at: 0,
+            last_played: None,
+            playtime_minutes: None,
+            last_crash: None,
+            java_path: None,
+            java_path_override: None,
+            max_memory: None,
+            min_memory: None,
+            java_args: None,
+            game_args: None,
+            auto_connect_server: None,
+            auto_load_world: None,
+            java_warning_ignored: false,
+            demo: false,
+            loader: None,
+            loader_version: None,
+            mc_version: None,
+            tags: Vec::new(),
+            group: None,
+            launch_profiles: Vec::new(),
+            private_versions: false,
+            pinned_version: false,
+            auto_update_mods_on_launch: None,
+        }
+    }
+
+    /// Error out if the instance is pinned; `action` completes "Unpin it before ...".
+    pub fn ensure_unpinned(&self, action: &str) -> Result<(), String> {
+        if self.pinned_version {
+            return Err(format!(
+                "{} is pinned to its current version. Unpin it before {}.",
+                self.name, action
+            ));
+        }
+        Ok(())
+    }
+
+    /// Version ID the instance launches with: the loader's derived version when a loader
+    /// is installed, otherwise the game version.
+    pub fn launch_version_id(&self) -> String {
+        match (&self.loader, &self.loader_version) {
+            (Some(loader), Some(loader_version)) => crate::loader::derived_version_id(
+                loader,
+                loader_version,
+                self.mc_version.as_deref().unwrap_or(&self.version),
+            ),
+            _ => self.version.clone(),
+        }
+    }
+}
+
+/// Load an instance.json, migrating it to the current schema if needed.
+pub fn read_instance(path: &Path) -> Result<Instance, String> {
+    // Only used if the id itself is unreadable; the folder name is the best guess left
+    let id = path
+        .parent()
+        .and_then(Path::file_name)
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    crate::migrate::load(path, INSTANCE_SCHEMA_VERSION, || Instance::placeholder(&id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fabric_instance(mc_version: Option<&str>) -> Instance {
+        Instance {
+            version: "1.20.4".to_string(),
+            loader: Some("fabric".to_string()),
+            loader_version: Some("0.15.11".to_string()),
+            mc_version: mc_version.map(str::to_string),
+            ..Instance::placeholder("test")
+        }
+    }
+
+    #[test]
+    fn launch_version_id_prefers_mc_version() {
+        let instance = fabric_instance(Some("1.20.1"));
+        assert_eq!(instance.launch_version_id(), "fabric-loader-0.15.11-1.20.1");
+    }
+
+    #[test]
+    fn launch_version_id_falls_back_to_version() {
+        let instance = fabric_instance(None);
+        assert_eq!(instance.launch_version_id(), "fabric-loader-0.15.11-1.20.4");
+    }
+
+    #[test]
+    fn launch_version_id_without_loader() {
+        let instance = Instance {
+            version: "1.20.4".to_string(),
+            ..Instance::placeholder("test")
+        };
+        assert_eq!(instance.launch_version_id(), "1.20.4");
+    }
+}
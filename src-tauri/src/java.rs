@@ -9,25 +9,41 @@ use tauri::{AppHandle, Manager};
 /// Determines the required Java version for a given Minecraft version
 pub fn get_required_java_version(mc_version: &str) -> u8 {
     // Parse version to determine Java requirement
+    // MC 1.20.5+ requires Java 21
     // MC 1.18+ requires Java 17
     // MC 1.17 requires Java 16
     // MC 1.12-1.16.5 requires Java 8
     // MC <1.12 requires Java 8
 
     if let Some(version_num) = parse_version(mc_version) {
-        if version_num >= (1, 18, 0) {
+        if version_num >= (1, 20, 5) {
+            return 21;
+        } else if version_num >= (1, 18, 0) {
             return 17;
         } else if version_num >= (1, 17, 0) {
             return 16;
         }
+    } else if let Some(week) = parse_snapshot(mc_version) {
+        // Weekly snapshots switched runtimes ahead of their release:
+        // 24w14a (1.20.5) -> 21, 21w37a (1.18) -> 17, 21w19a (1.17) -> 16
+        if week >= (24, 14) {
+            return 21;
+        } else if week >= (21, 37) {
+            return 17;
+        } else if week >= (21, 19) {
+            return 16;
+        }
     }
 
     // Default to Java 8 for older versions
     8
 }
 
-/// Parse Minecraft version string into (major, minor, patch) tuple
-fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+/// Parse Minecraft version string into (major, minor, patch) tuple.
+/// Pre-release and release-candidate suffixes are ignored, so `1.21-pre1` and
+/// `1.20.4-rc1` parse as the release they lead up to.
+pub(crate) fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let version = version.split(['-', ' ']).next().unwrap_or(version);
     let parts: Vec<&str> = version.split('.').collect();
 
     if parts.len() < 2 {
@@ -45,6 +61,19 @@ fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
     Some((major, minor, patch))
 }
 
+/// Parse a weekly snapshot ID like `23w31a` into (year, week).
+pub(crate) fn parse_snapshot(version: &str) -> Option<(u32, u32)> {
+    let (year, rest) = version.split_once('w')?;
+    if year.len() != 2 || rest.len() < 3 || !rest.is_ascii() {
+        return None;
+    }
+    let week = &rest[..2];
+    if !rest[2..].chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some((year.parse().ok()?, week.parse().ok()?))
+}
+
 /* ============================================================
  * Java Detection
  * ============================================================ */
@@ -128,6 +157,11 @@ pub fn find_system_java(required_version: u8) -> Result<Option<String>, String>
  * Java Download (Adoptium/Temurin)
  * ============================================================ */
 
+#[cfg(target_os = "windows")]
+const JAVA_BINARY: &str = "java.exe";
+#[cfg(not(target_os = "windows"))]
+const JAVA_BINARY: &str = "java";
+
 /// Download and install Java for the launcher
 pub async fn download_java(app: &AppHandle, version: u8) -> Result<String, String> {
     let java_dir = app
@@ -138,80 +172,133 @@ pub async fn download_java(app: &AppHandle, version: u8) -> Result<String, Strin
         .join(format!("jdk-{}", version));
 
     // Check if already downloaded
-    let java_exe = java_dir.join("bin").join("java.exe");
+    let java_exe = java_dir.join("bin").join(JAVA_BINARY);
     if java_exe.exists() {
         return Ok(java_exe.to_string_lossy().to_string());
     }
 
-    println!("📥 Downloading Java {} from Adoptium...", version);
+    log::info!("Downloading Java {} from Adoptium...", version);
 
-    // Adoptium API endpoint for Windows x64 JRE
+    // Adoptium assets API for Windows x64 JRE; unlike /binary it also gives the SHA-256
     let api_url = format!(
-        "https://api.adoptium.net/v3/binary/latest/{}/ga/windows/x64/jre/hotspot/normal/eclipse",
+        "https://api.adoptium.net/v3/assets/latest/{}/hotspot?architecture=x64&image_type=jre&os=windows&vendor=eclipse",
         version
     );
-
-    // Download the zip file
-    let response = reqwest::get(&api_url)
+    let text = crate::download::download_text(&api_url)
+        .await
+        .map_err(|e| format!("Failed to look up Java {}: {}", version, e))?;
+    let assets: Vec<AdoptiumAsset> =
+        serde_json::from_str(&text).map_err(|e| format!("Unexpected Adoptium response: {}", e))?;
+    let package = assets
+        .into_iter()
+        .map(|a| a.binary.package)
+        .find(|p| p.name.ends_with(".zip"))
+        .ok_or_else(|| format!("No Java {} JRE available from Adoptium", version))?;
+
+    let zip_path = std::env::temp_dir().join(&package.name);
+    let mut attempt = 1;
+    loop {
+        crate::download::download_with_retry(
+            &package.link,
+            &zip_path,
+            None,
+            JAVA_DOWNLOAD_ATTEMPTS,
+        )
         .await
         .map_err(|e| format!("Failed to download Java: {}", e))?;
-
-    if !response.status().is_success() {
-        return Err(format!(
-            "Failed to download Java: HTTP {}",
-            response.status()
-        ));
+        let actual = crate::download::sha256_file(&zip_path).map_err(|e| e.to_string())?;
+        if actual.eq_ignore_ascii_case(&package.checksum) {
+            break;
+        }
+        let _ = fs::remove_file(&zip_path);
+        if attempt >= JAVA_DOWNLOAD_ATTEMPTS {
+            return Err(format!(
+                "Java download is corrupt (SHA-256 {} != {})",
+                actual, package.checksum
+            ));
+        }
+        log::warn!("Java archive checksum mismatch, downloading again");
+        attempt += 1;
     }
 
-    let bytes = response
-        .bytes()
-        .await
-        .map_err(|e| format!("Failed to read Java download: {}", e))?;
-
-    // Save to temp file
-    let temp_dir = std::env::temp_dir();
-    let zip_path = temp_dir.join(format!("java-{}.zip", version));
-    fs::write(&zip_path, &bytes).map_err(|e| e.to_string())?;
+    log::info!("Extracting Java {}...", version);
 
-    println!("📦 Extracting Java {}...", version);
-
-    // Extract the zip file
-    extract_zip(&zip_path, java_dir.parent().unwrap())?;
+    // Extract into a staging dir so other installed runtimes next to it can't be mistaken for this one
+    let staging = java_dir
+        .parent()
+        .unwrap()
+        .join(format!(".extract-{}", version));
+    if staging.exists() {
+        fs::remove_dir_all(&staging).map_err(|e| e.to_string())?;
+    }
+    extract_zip(&zip_path, &staging)?;
 
     // Clean up zip file
     let _ = fs::remove_file(&zip_path);
 
-    // Find the actual extracted directory (Adoptium uses various naming schemes)
-    let extracted_root = java_dir.parent().unwrap();
-    for entry in fs::read_dir(extracted_root).map_err(|e| e.to_string())? {
-        let entry = entry.map_err(|e| e.to_string())?;
-        let path = entry.path();
-        if path.is_dir() {
-            let name = path.file_name().unwrap().to_string_lossy();
-            // Check for names like jdk-17.0.1+12 or jdk8u312-b07
-            if name.contains(&format!("jdk-{}", version))
-                || name.contains(&format!("jdk{}", version))
-                || name.contains(&format!("jre-{}", version))
-                || name.contains(&format!("jre{}", version))
-            {
-                // Rename to our expected directory name
-                if path != java_dir {
-                    if java_dir.exists() {
-                        fs::remove_dir_all(&java_dir).map_err(|e| e.to_string())?;
-                    }
-                    fs::rename(&path, &java_dir).map_err(|e| e.to_string())?;
-                }
-                break;
+    // Archives nest the runtime under differently named roots (jdk-17.0.1+12, zulu17..., graalvm-...,
+    // or Contents/Home on macOS), so look for the directory that actually holds bin/java.
+    let home = find_java_home(&staging, 3);
+    let result = match home {
+        Some(home) => {
+            if java_dir.exists() {
+                fs::remove_dir_all(&java_dir).map_err(|e| e.to_string())?;
             }
+            fs::rename(&home, &java_dir).map_err(|e| e.to_string())
         }
-    }
+        None => Err(format!(
+            "Java extraction failed: {} not found in archive",
+            JAVA_BINARY
+        )),
+    };
+    let _ = fs::remove_dir_all(&staging);
+    result?;
 
     if java_exe.exists() {
-        println!("✓ Java {} installed successfully", version);
+        log::info!("Java {} installed successfully", version);
         Ok(java_exe.to_string_lossy().to_string())
     } else {
-        Err(format!("Java extraction failed: java.exe not found"))
+        Err(format!("Java extraction failed: {} not found", JAVA_BINARY))
+    }
+}
+
+/// Tries for the Java archive, both for the download itself and for checksum mismatches.
+const JAVA_DOWNLOAD_ATTEMPTS: u32 = 3;
+
+#[derive(serde::Deserialize)]
+struct AdoptiumAsset {
+    binary: AdoptiumBinary,
+}
+
+#[derive(serde::Deserialize)]
+struct AdoptiumBinary {
+    package: AdoptiumPackage,
+}
+
+#[derive(serde::Deserialize)]
+struct AdoptiumPackage {
+    name: String,
+    link: String,
+    /// Hex SHA-256 of the archive
+    checksum: String,
+}
+
+/// Breadth-first search (up to `max_depth` levels) for a directory containing `bin/java`.
+fn find_java_home(root: &Path, max_depth: usize) -> Option<PathBuf> {
+    let mut level = vec![root.to_path_buf()];
+    for _ in 0..=max_depth {
+        let mut next = Vec::new();
+        for dir in level {
+            if dir.join("bin").join(JAVA_BINARY).is_file() {
+                return Some(dir);
+            }
+            if let Ok(entries) = fs::read_dir(&dir) {
+                next.extend(entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()));
+            }
+        }
+        level = next;
     }
+    None
 }
 
 /// Extract a zip file to a destination directory
@@ -242,13 +329,20 @@ fn extract_zip(zip_path: &PathBuf, dest: &Path) -> Result<(), String> {
  * ============================================================ */
 
 /// Ensure Java is available for the given Minecraft version
-pub async fn ensure_java(app: &AppHandle, mc_version: &str) -> Result<String, String> {
-    let required_version = get_required_java_version(mc_version);
+/// `declared` is the version JSON's `javaVersion.majorVersion`; the version-number
+/// heuristic is only used when it's absent.
+pub async fn ensure_java(
+    app: &AppHandle,
+    mc_version: &str,
+    declared: Option<u8>,
+) -> Result<String, String> {
+    let required_version = declared.unwrap_or_else(|| get_required_java_version(mc_version));
     let settings = crate::settings::get_settings(app.clone()).unwrap_or_default();
 
-    println!(
-        "🔍 Minecraft {} requires Java {}",
-        mc_version, required_version
+    log::info!(
+        "Minecraft {} requires Java {}",
+        mc_version,
+        required_version
     );
 
     // 1. Check global custom path first if provided and matches version
@@ -256,10 +350,10 @@ pub async fn ensure_java(app: &AppHandle, mc_version: &str) -> Result<String, St
         if PathBuf::from(global_path).exists() {
             if let Some(v) = get_java_major_version(global_path) {
                 if v == required_version {
-                    println!("✓ Using global custom Java: {}", global_path);
+                    log::info!("Using global custom Java: {}", global_path);
                     return Ok(global_path.clone());
                 } else {
-                    println!("⚠ Global custom Java version mismatch (found {}, need {}). Falling back to detection.", v, required_version);
+                    log::warn!("Global custom Java version mismatch (found {}, need {}). Falling back to detection.", v, required_version);
                 }
             }
         }
@@ -267,13 +361,13 @@ pub async fn ensure_java(app: &AppHandle, mc_version: &str) -> Result<String, St
 
     // 2. Try to find system Java
     if let Some(java_path) = find_system_java(required_version)? {
-        println!("✓ Found system Java at: {}", java_path);
+        log::info!("Found system Java at: {}", java_path);
         return Ok(java_path);
     }
 
     // 3. If not found, download it
-    println!(
-        "⚠ Java {} not found on system, downloading...",
+    log::info!(
+        "Java {} not found on system, downloading...",
         required_version
     );
     download_java(app, required_version).await
@@ -291,3 +385,63 @@ pub fn get_intended_java_path(app: &AppHandle, instance: &crate::instance::Insta
         .unwrap_or("java")
         .to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_java_for_releases() {
+        for (version, java) in [
+            ("1.8.9", 8),
+            ("1.16.5", 8),
+            ("1.17.1", 16),
+            ("1.18", 17),
+            ("1.20.4", 17),
+            ("1.20.5", 21),
+            ("1.21.4", 21),
+        ] {
+            assert_eq!(get_required_java_version(version), java, "{}", version);
+        }
+    }
+
+    #[test]
+    fn required_java_for_pre_releases() {
+        for (version, java) in [
+            ("1.21-pre1", 21),
+            ("1.20.5-rc1", 21),
+            ("1.20.4-rc1", 17),
+            ("1.18 Pre-release 1", 17),
+        ] {
+            assert_eq!(get_required_java_version(version), java, "{}", version);
+        }
+    }
+
+    #[test]
+    fn required_java_for_weekly_snapshots() {
+        for (snapshot, java) in [
+            ("20w45a", 8),
+            ("21w19a", 16),
+            ("21w37a", 17),
+            ("23w31a", 17),
+            ("24w14a", 21),
+            ("24w45a", 21),
+        ] {
+            assert_eq!(get_required_java_version(snapshot), java, "{}", snapshot);
+        }
+    }
+
+    #[test]
+    fn parse_version_strips_suffixes() {
+        assert_eq!(parse_version("1.21-pre1"), Some((1, 21, 0)));
+        assert_eq!(parse_version("1.20.4-rc1"), Some((1, 20, 4)));
+        assert_eq!(parse_version("23w31a"), None);
+    }
+
+    #[test]
+    fn parse_snapshot_rejects_releases() {
+        assert_eq!(parse_snapshot("23w31a"), Some((23, 31)));
+        assert_eq!(parse_snapshot("1.20.4"), None);
+        assert_eq!(parse_snapshot("23w3"), None);
+    }
+}
@@ -1,16 +1,48 @@
 use crate::version::VersionJson;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use tauri::AppHandle;
-use tauri::Manager;
 
-pub fn build_classpath(app: &AppHandle, id: &str, version: &VersionJson) -> Result<String, String> {
-    let base = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("minecraft");
+/// How many missing library paths are spelled out in the error before summarizing.
+const MISSING_LIBRARIES_SHOWN: usize = 10;
 
-    let mut entries: Vec<PathBuf> = Vec::new();
+/// Library jars `version` puts on the classpath on this OS, in order, each flagged
+/// `true` when it is a natives classifier jar (LWJGL unpacks those itself at startup).
+pub fn library_paths(
+    app: &AppHandle,
+    version: &VersionJson,
+) -> Result<Vec<(PathBuf, bool)>, String> {
+    let base = crate::commands::minecraft_root(app)?;
+
+    let mut entries: Vec<(PathBuf, bool)> = Vec::new();
+    // `group:artifact[:classifier]` -> (index in `entries`, version). Vanilla and loader
+    // profiles can both list a library; only the newest copy goes on the classpath.
+    let mut seen: HashMap<String, (usize, String)> = HashMap::new();
+    let mut push = |name: &str, path: PathBuf, native: bool| {
+        let parts: Vec<&str> = name.split(':').collect();
+        let (key, version) = match parts.as_slice() {
+            [group, artifact, version, rest @ ..] => (
+                format!("{}:{}:{}:{}", group, artifact, rest.join(":"), native),
+                version.to_string(),
+            ),
+            _ => {
+                entries.push((path, native));
+                return;
+            }
+        };
+        match seen.get_mut(&key) {
+            Some((index, seen_version)) => {
+                if crate::version_cmp::is_newer(&version, seen_version) {
+                    entries[*index] = (path, native);
+                    *seen_version = version;
+                }
+            }
+            None => {
+                seen.insert(key, (entries.len(), version));
+                entries.push((path, native));
+            }
+        }
+    };
 
     // Libraries
     let os_key = if cfg!(target_os = "windows") {
@@ -27,18 +59,58 @@ pub fn build_classpath(app: &AppHandle, id: &str, version: &VersionJson) -> Resu
         }
 
         if let Some(artifact) = &lib.downloads.artifact {
-            entries.push(base.join("libraries").join(&artifact.path));
+            push(
+                &lib.name,
+                base.join("libraries").join(&artifact.path),
+                false,
+            );
         }
 
         if let Some(classifier) = lib.natives.get(os_key) {
             if let Some(artifact) = lib.downloads.classifiers.get(classifier) {
-                entries.push(base.join("libraries").join(&artifact.path));
+                push(&lib.name, base.join("libraries").join(&artifact.path), true);
             }
         }
     }
+    Ok(entries)
+}
+
+/// Libraries of `version` followed by `client_jar`, which the caller resolves so an
+/// instance's private copy can stand in for the shared one.
+pub fn build_classpath(
+    app: &AppHandle,
+    version: &VersionJson,
+    client_jar: &Path,
+) -> Result<String, String> {
+    let mut entries: Vec<PathBuf> = library_paths(app, version)?
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect();
+
+    // A missing library only shows up as a ClassNotFoundException in-game, so fail here instead
+    let missing: Vec<String> = entries
+        .iter()
+        .filter(|p| !p.is_file())
+        .map(|p| p.to_string_lossy().to_string())
+        .collect();
+    if !missing.is_empty() {
+        let shown = missing.iter().take(MISSING_LIBRARIES_SHOWN).cloned();
+        let mut list = shown.collect::<Vec<_>>().join("\n  ");
+        if missing.len() > MISSING_LIBRARIES_SHOWN {
+            list.push_str(&format!(
+                "\n  ...and {} more",
+                missing.len() - MISSING_LIBRARIES_SHOWN
+            ));
+        }
+        return Err(format!(
+            "{} required libraries are missing (turn on file verification or reinstall the loader to fetch them):\n  {}",
+            missing.len(),
+            list
+        ));
+    }
 
     // Client jar LAST
-    entries.push(base.join("versions").join(id).join(format!("{}.jar", id)));
+    entries.push(client_jar.to_path_buf());
 
     let sep = if cfg!(windows) { ";" } else { ":" };
 
@@ -1,7 +1,10 @@
-use crate::instance::Instance;
+use crate::error::AppError;
 use serde::Serialize;
-use std::path::{Path, PathBuf};
-use tauri::AppHandle;
+use std::path::Path;
+use tauri::{AppHandle, Emitter};
+
+/// Search hits considered by `find_loader_candidates`.
+const CANDIDATE_HITS_PER_SEARCH: usize = 5;
 
 #[derive(Serialize, Clone)]
 pub struct LoaderCandidate {
@@ -20,89 +23,268 @@ pub struct LoaderInstalled {
     pub success: bool,
 }
 
-pub fn fabric_installed(minecraft_root: &Path, mc_version: &str, loader_version: &str) -> bool {
-    // The install_loader function creates derived version IDs in the format: fabric-loader-{loaderVersion}-{mcVersion}
-    let derived_version_id = format!("fabric-loader-{}-{}", loader_version, mc_version);
+/// Payload of `loader-install-progress`: a coarse phase with an approximate percentage.
+#[derive(Serialize, Clone)]
+pub struct LoaderInstallProgress {
+    pub instance_id: String,
+    pub phase: String,
+    pub percent: u8,
+    pub message: String,
+}
 
-    // Also check for alternative formats that might exist
-    let alt_version_id1 = format!("fabric-{}-{}", mc_version, loader_version);
-    let alt_version_id2 = format!("fabric-loader-{}-{}", mc_version, loader_version);
+#[derive(Serialize, Clone)]
+pub struct LoaderInstallFinished {
+    pub instance_id: String,
+    pub success: bool,
+}
 
-    let versions_dir = minecraft_root.join("versions");
+pub fn emit_loader_progress(
+    app: &AppHandle,
+    instance_id: &str,
+    phase: &str,
+    percent: u8,
+    message: impl Into<String>,
+) {
+    let _ = app.emit(
+        "loader-install-progress",
+        LoaderInstallProgress {
+            instance_id: instance_id.to_string(),
+            phase: phase.to_string(),
+            percent,
+            message: message.into(),
+        },
+    );
+}
 
-    // Check for the primary derived version ID format
-    let version_json_primary: PathBuf = versions_dir
-        .join(&derived_version_id)
-        .join(format!("{}.json", derived_version_id));
+/// Map a recognizable Fabric/Forge installer output line to (phase, percent).
+/// Phases only move forward, so callers should ignore results below the current percent.
+pub fn installer_phase(line: &str) -> Option<(&'static str, u8)> {
+    let l = line.to_lowercase();
+    if l.contains("successfully installed") || l.starts_with("done") {
+        Some(("done", 95))
+    } else if l.contains("creating profile") || l.contains("writing profile") {
+        Some(("finalizing", 85))
+    } else if l.contains("patching")
+        || l.contains("processor")
+        || l.starts_with("task:")
+        || l.contains("extracting")
+    {
+        Some(("patching", 60))
+    } else if l.contains("downloading") || l.contains("library") {
+        Some(("downloading_libraries", 30))
+    } else {
+        None
+    }
+}
 
-    // Check for alternative formats
-    let version_json_alt1: PathBuf = versions_dir
-        .join(&alt_version_id1)
-        .join(format!("{}.json", alt_version_id1));
+/// ID of the version a loader install creates under `versions/`:
+/// `<loader>-loader-<loaderVersion>-<mcVersion>`, e.g. `fabric-loader-0.15.11-1.20.4`.
+/// Everything that looks for or removes that version goes through here.
+pub fn derived_version_id(loader: &str, loader_version: &str, mc_version: &str) -> String {
+    format!("{}-loader-{}-{}", loader, loader_version, mc_version)
+}
 
-    let version_json_alt2: PathBuf = versions_dir
-        .join(&alt_version_id2)
-        .join(format!("{}.json", alt_version_id2));
+pub fn fabric_installed(minecraft_root: &Path, mc_version: &str, loader_version: &str) -> bool {
+    let version_id = derived_version_id("fabric", loader_version, mc_version);
+    let version_json = minecraft_root
+        .join("versions")
+        .join(&version_id)
+        .join(format!("{}.json", version_id));
+
+    if !version_json.exists() {
+        log::debug!(
+            "fabric_installed: No Fabric installation found for MC {} loader {}",
+            mc_version,
+            loader_version
+        );
+        log::debug!("  Checked: {}", version_json.display());
+        return false;
+    }
 
-    let exists =
-        version_json_primary.exists() || version_json_alt1.exists() || version_json_alt2.exists();
+    log::debug!(
+        "fabric_installed: Found Fabric installation for MC {} loader {}",
+        mc_version,
+        loader_version
+    );
+    loader_libraries_present(minecraft_root, &version_json, "fabric")
+}
 
-    if exists {
-        println!(
-            "fabric_installed: Found Fabric installation for MC {} loader {}",
-            mc_version, loader_version
+pub fn quilt_installed(minecraft_root: &Path, mc_version: &str, loader_version: &str) -> bool {
+    let version_id = derived_version_id("quilt", loader_version, mc_version);
+    let version_json = minecraft_root
+        .join("versions")
+        .join(&version_id)
+        .join(format!("{}.json", version_id));
+
+    if !version_json.exists() {
+        log::debug!(
+            "quilt_installed: No Quilt installation found for MC {} loader {}",
+            mc_version,
+            loader_version
         );
-        if version_json_primary.exists() {
-            println!("  Found: {}", version_json_primary.display());
-        }
-        if version_json_alt1.exists() {
-            println!("  Found: {}", version_json_alt1.display());
-        }
-        if version_json_alt2.exists() {
-            println!("  Found: {}", version_json_alt2.display());
-        }
-    } else {
-        println!(
-            "fabric_installed: No Fabric installation found for MC {} loader {}",
-            mc_version, loader_version
-        );
-        println!("  Checked: {}", version_json_primary.display());
-        println!("  Checked: {}", version_json_alt1.display());
-        println!("  Checked: {}", version_json_alt2.display());
-
-        // Debug: List what versions actually exist
-        if versions_dir.exists() {
-            println!("  Available versions in {}:", versions_dir.display());
-            if let Ok(entries) = std::fs::read_dir(&versions_dir) {
-                let mut found_any = false;
-                for entry in entries.flatten() {
-                    if let Ok(name) = entry.file_name().into_string() {
-                        println!("    - {}", name);
-                        found_any = true;
-                        if name.to_lowercase().contains("fabric") {
-                            println!("      ^ This contains 'fabric'");
-                        }
-                    }
+        log::debug!("  Checked: {}", version_json.display());
+        return false;
+    }
+
+    log::debug!(
+        "quilt_installed: Found Quilt installation for MC {} loader {}",
+        mc_version,
+        loader_version
+    );
+    loader_libraries_present(minecraft_root, &version_json, "quilt")
+}
+
+/// Libraries a loader-derived version cannot launch without, as `group:artifact` prefixes.
+/// Each inner slice is satisfied by any one of its entries (Quilt ships either
+/// Fabric's intermediary or its own hashed mappings).
+fn required_loader_libraries(loader_type: &str) -> &'static [&'static [&'static str]] {
+    match loader_type {
+        "fabric" => &[
+            &["net.fabricmc:fabric-loader"],
+            &["net.fabricmc:intermediary"],
+        ],
+        "quilt" => &[
+            &["org.quiltmc:quilt-loader"],
+            &["net.fabricmc:intermediary", "org.quiltmc:hashed"],
+        ],
+        _ => &[],
+    }
+}
+
+/// One required loader library as found (or not) on disk.
+#[derive(Serialize, Clone, Debug)]
+pub struct LoaderLibraryStatus {
+    /// Full coordinates from the version JSON, or the accepted prefixes when undeclared
+    pub name: String,
+    pub path: Option<String>,
+    pub present: bool,
+}
+
+/// Look up each of `loader_type`'s required libraries in a derived version JSON and
+/// check the declared file under `libraries/`. Fails only if the JSON is unreadable.
+fn check_loader_libraries(
+    minecraft_root: &Path,
+    version_json: &Path,
+    loader_type: &str,
+) -> Result<Vec<LoaderLibraryStatus>, String> {
+    let json: serde_json::Value = std::fs::read_to_string(version_json)
+        .ok()
+        .and_then(|t| serde_json::from_str(&t).ok())
+        .ok_or_else(|| format!("Could not parse {}", version_json.display()))?;
+
+    let libraries = json
+        .get("libraries")
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+    let libs_dir = minecraft_root.join("libraries");
+
+    Ok(required_loader_libraries(loader_type)
+        .iter()
+        .map(|group| {
+            let found = libraries.iter().find_map(|lib| {
+                let name = lib.get("name").and_then(|v| v.as_str())?;
+                if !group
+                    .iter()
+                    .any(|prefix| name.starts_with(&format!("{}:", prefix)))
+                {
+                    return None;
                 }
-                if !found_any {
-                    println!("    (no versions found)");
+                // Prefer the artifact path we recorded, fall back to the Maven layout
+                let rel = lib
+                    .get("downloads")
+                    .and_then(|d| d.get("artifact"))
+                    .and_then(|a| a.get("path"))
+                    .and_then(|p| p.as_str())
+                    .map(|p| p.to_string())
+                    .or_else(|| crate::commands::maven_coords_to_path(name))?;
+                Some((name.to_string(), libs_dir.join(rel)))
+            });
+            match found {
+                Some((name, path)) => LoaderLibraryStatus {
+                    name,
+                    present: std::fs::metadata(&path)
+                        .map(|m| m.len() > 0)
+                        .unwrap_or(false),
+                    path: Some(path.to_string_lossy().to_string()),
+                },
+                None => LoaderLibraryStatus {
+                    name: group.join(" or "),
+                    path: None,
+                    present: false,
+                },
+            }
+        })
+        .collect())
+}
+
+/// Confirm the loader jar and mappings referenced by a derived version JSON were
+/// actually downloaded under `libraries/`, not just declared.
+fn loader_libraries_present(minecraft_root: &Path, version_json: &Path, loader_type: &str) -> bool {
+    check_loader_libraries(minecraft_root, version_json, loader_type)
+        .is_ok_and(|libs| libs.iter().all(|l| l.present))
+}
+
+/// What `verify_loader` found for an instance. `missing` lists, in plain words,
+/// everything that would stop the loader from starting; empty means it should launch.
+#[derive(Serialize, Clone, Debug)]
+pub struct LoaderVerification {
+    pub loader: String,
+    pub version_id: String,
+    pub version_json: String,
+    pub version_json_present: bool,
+    /// `None` for loaders without a known library list (Forge, NeoForge)
+    pub loader_library: Option<LoaderLibraryStatus>,
+    /// Intermediary (or Quilt's hashed) mappings; `None` where not applicable
+    pub intermediary: Option<LoaderLibraryStatus>,
+    pub missing: Vec<String>,
+}
+
+/// Build the structured report behind `verify_loader` for one derived version.
+pub fn verify_loader_files(
+    minecraft_root: &Path,
+    version_json: &Path,
+    loader_type: &str,
+    version_id: &str,
+) -> LoaderVerification {
+    let loader_type = loader_type.to_lowercase();
+    let mut report = LoaderVerification {
+        loader: loader_type.clone(),
+        version_id: version_id.to_string(),
+        version_json: version_json.to_string_lossy().to_string(),
+        version_json_present: version_json.is_file(),
+        loader_library: None,
+        intermediary: None,
+        missing: Vec::new(),
+    };
+    if !report.version_json_present {
+        report
+            .missing
+            .push(format!("Version JSON for {}", version_id));
+        return report;
+    }
+
+    match check_loader_libraries(minecraft_root, version_json, &loader_type) {
+        Ok(libs) => {
+            for lib in &libs {
+                if !lib.present {
+                    report.missing.push(match &lib.path {
+                        Some(path) => format!("Library {} ({})", lib.name, path),
+                        None => format!("Version JSON does not declare {}", lib.name),
+                    });
                 }
-            } else {
-                println!("    (could not read versions directory)");
             }
-        } else {
-            println!(
-                "  Versions directory {} does not exist!",
-                versions_dir.display()
-            );
+            let mut libs = libs.into_iter();
+            report.loader_library = libs.next();
+            report.intermediary = libs.next();
         }
+        Err(e) => report.missing.push(e),
     }
-
-    exists
+    report
 }
 
 pub fn loader_verification(mc_dir: &std::path::Path, project_id: &str) -> bool {
-    println!(
+    log::debug!(
         "loader_verification: checking {} in {}",
         project_id,
         mc_dir.display()
@@ -122,17 +304,17 @@ pub fn loader_verification(mc_dir: &std::path::Path, project_id: &str) -> bool {
                     if project_lower.contains("fabric")
                         && (name_lower.contains("fabric-loader") || name_lower.contains("fabric"))
                     {
-                        println!("loader_verification: found fabric version folder: {}", name);
+                        log::debug!("loader_verification: found fabric version folder: {}", name);
                         return true;
                     }
                     if project_lower.contains("quilt")
                         && (name_lower.contains("quilt-loader") || name_lower.contains("quilt"))
                     {
-                        println!("loader_verification: found quilt version folder: {}", name);
+                        log::debug!("loader_verification: found quilt version folder: {}", name);
                         return true;
                     }
                     if project_lower.contains("forge") && name_lower.contains("forge") {
-                        println!("loader_verification: found forge version folder: {}", name);
+                        log::debug!("loader_verification: found forge version folder: {}", name);
                         return true;
                     }
                 }
@@ -166,19 +348,19 @@ pub fn loader_verification(mc_dir: &std::path::Path, project_id: &str) -> bool {
                 if project_lower.contains("fabric")
                     && (name_lower.contains("fabric-loader") || name_lower.contains("fabric-api"))
                 {
-                    println!("loader_verification: found fabric lib: {}", name);
+                    log::debug!("loader_verification: found fabric lib: {}", name);
                     found_loader_libs = true;
                     break;
                 }
                 if project_lower.contains("quilt")
                     && (name_lower.contains("quilt-loader") || name_lower.contains("quilt"))
                 {
-                    println!("loader_verification: found quilt lib: {}", name);
+                    log::debug!("loader_verification: found quilt lib: {}", name);
                     found_loader_libs = true;
                     break;
                 }
                 if project_lower.contains("forge") && name_lower.contains("forge") {
-                    println!("loader_verification: found forge lib: {}", name);
+                    log::debug!("loader_verification: found forge lib: {}", name);
                     found_loader_libs = true;
                     break;
                 }
@@ -190,42 +372,42 @@ pub fn loader_verification(mc_dir: &std::path::Path, project_id: &str) -> bool {
         }
     }
 
-    println!(
+    log::debug!(
         "loader_verification: no indicators found for {}",
         project_id
     );
     false
 }
 
-#[tauri::command]
-pub async fn get_loader_versions(
-    loader_type: String,
-    mc_version: String,
-    include_beta: bool,
-) -> Result<Vec<String>, String> {
-    println!(
-        "get_loader_versions: loader={} mc={} include_beta={}",
-        loader_type, mc_version, include_beta
-    );
+/// Fetch a loader's versions for `mc_version` from Fabric/Quilt meta, split into (stable, beta).
+async fn fetch_loader_versions(
+    loader_type: &str,
+    mc_version: &str,
+) -> Result<(Vec<String>, Vec<String>), AppError> {
     // List available loader versions from Fabric/Quilt meta endpoints
-    let list_url = match loader_type.as_str() {
+    let list_url = match loader_type {
         "fabric" => format!(
             "https://meta.fabricmc.net/v2/versions/loader/{}",
             mc_version
         ),
         "quilt" => format!("https://meta.quiltmc.org/v3/versions/loader/{}", mc_version),
-        other => return Err(format!("Unsupported loader type: {}", other)),
+        other => {
+            return Err(AppError::LoaderUnsupported(format!(
+                "Unsupported loader type: {}",
+                other
+            )))
+        }
     };
 
     let text = crate::download::download_text(&list_url).await?;
     let list_val: serde_json::Value = serde_json::from_str(&text).map_err(|e| {
         let snippet: String = text.chars().take(200).collect();
-        format!("{} - response (truncated): {}", e.to_string(), snippet)
+        AppError::Parse(format!("{} - response (truncated): {}", e, snippet))
     })?;
 
     let arr = list_val
         .as_array()
-        .ok_or("unexpected loader list response")?;
+        .ok_or_else(|| AppError::Parse("unexpected loader list response".to_string()))?;
     let mut stable: Vec<String> = Vec::new();
     let mut beta: Vec<String> = Vec::new();
 
@@ -281,6 +463,27 @@ pub async fn get_loader_versions(
         }
     }
 
+    // Meta lists are usually newest first already; don't rely on it
+    let newest_first = |a: &String, b: &String| crate::version_cmp::compare(b, a);
+    stable.sort_by(newest_first);
+    beta.sort_by(newest_first);
+    Ok((stable, beta))
+}
+
+#[tauri::command]
+pub async fn get_loader_versions(
+    loader_type: String,
+    mc_version: String,
+    include_beta: bool,
+) -> Result<Vec<String>, AppError> {
+    log::debug!(
+        "get_loader_versions: loader={} mc={} include_beta={}",
+        loader_type,
+        mc_version,
+        include_beta
+    );
+    let (mut stable, beta) = fetch_loader_versions(&loader_type, &mc_version).await?;
+
     // If no stable releases found, fall back to returning beta/pre-release versions so UI has options
     if stable.is_empty() {
         if include_beta {
@@ -297,86 +500,102 @@ pub async fn get_loader_versions(
     Ok(stable)
 }
 
+/// The single version most users should pick: newest stable for Fabric/Quilt, the
+/// promoted "recommended" build for Forge. `None` when only betas exist.
+#[tauri::command]
+pub async fn get_recommended_loader_version(
+    loader_type: String,
+    mc_version: String,
+) -> Result<Option<String>, AppError> {
+    if loader_type == "forge" {
+        let text = crate::download::download_text(
+            "https://files.minecraftforge.net/net/minecraftforge/forge/promotions_slim.json",
+        )
+        .await?;
+        let promos: serde_json::Value = serde_json::from_str(&text)?;
+        return Ok(promos
+            .get("promos")
+            .and_then(|p| p.get(format!("{}-recommended", mc_version)))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()));
+    }
+
+    let (stable, _beta) = fetch_loader_versions(&loader_type, &mc_version).await?;
+    Ok(stable.into_iter().next())
+}
+
 #[tauri::command]
 pub async fn find_loader_candidates(
     app: AppHandle,
     instance_id: String,
     loader: String,
 ) -> Result<Vec<LoaderCandidate>, String> {
-    println!(
+    log::debug!(
         "find_loader_candidates: loader={} instance={}",
-        loader, instance_id
+        loader,
+        instance_id
     );
     // Read instance to get game version
     let root = crate::commands::instance_dir(&app, &instance_id)?;
-    let meta_text =
-        std::fs::read_to_string(root.join("instance.json")).map_err(|e| e.to_string())?;
-    let inst: Instance = serde_json::from_str(&meta_text).map_err(|e| e.to_string())?;
+    let inst = crate::instance::read_instance(&root.join("instance.json"))?;
     let mc_version = inst.mc_version.clone().unwrap_or(inst.version.clone());
 
     // Search Modrinth for projects matching loader term
     let search = crate::modrinth::search_projects(&loader, "mod").await?;
     let mut results: Vec<LoaderCandidate> = Vec::new();
 
-    // Also include popular/popular loader projects by searching for common loader names if initial search returned none
-    if search.hits.is_empty() {
-        let common = vec!["fabric", "forge", "quilt"];
-        for name in common.iter() {
+    // Only the most relevant few hits are worth offering
+    let mut hits = search.hits;
+    if hits.is_empty() {
+        // Also include popular loader projects by searching for common loader names if initial search returned none
+        for name in ["fabric", "forge", "quilt"] {
             if let Ok(pop) = crate::modrinth::search_projects(name, "mod").await {
-                for hit in pop.hits {
-                    if let Ok(versions) =
-                        crate::modrinth::get_project_versions(&hit.project_id).await
-                    {
-                        for v in versions {
-                            if v.game_versions.iter().any(|g| g == &mc_version) {
-                                results.push(LoaderCandidate {
-                                    project_id: hit.project_id.clone(),
-                                    project_title: hit.title.clone(),
-                                    version_id: v.id.clone(),
-                                    version_number: v.version_number.clone(),
-                                    game_versions: v.game_versions.clone(),
-                                });
-                            }
-                        }
-                    }
-                }
+                hits.extend(pop.hits.into_iter().take(CANDIDATE_HITS_PER_SEARCH));
             }
         }
     } else {
-        for hit in search.hits.iter() {
-            if let Ok(versions) = crate::modrinth::get_project_versions(&hit.project_id).await {
-                for v in versions.into_iter() {
-                    // Compatible if version.game_versions includes mc_version
-                    if v.game_versions.iter().any(|g| g == &mc_version) {
-                        results.push(LoaderCandidate {
-                            project_id: hit.project_id.clone(),
-                            project_title: hit.title.clone(),
-                            version_id: v.id.clone(),
-                            version_number: v.version_number.clone(),
-                            game_versions: v.game_versions.clone(),
-                        });
-                    }
-                }
-            }
-        }
+        hits.truncate(CANDIDATE_HITS_PER_SEARCH);
     }
 
-    for hit in search.hits.iter() {
-        if let Ok(versions) = crate::modrinth::get_project_versions(&hit.project_id).await {
-            for v in versions.into_iter() {
-                // Compatible if version.game_versions includes mc_version
-                if v.game_versions.iter().any(|g| g == &mc_version) {
-                    results.push(LoaderCandidate {
-                        project_id: hit.project_id.clone(),
-                        project_title: hit.title.clone(),
-                        version_id: v.id.clone(),
-                        version_number: v.version_number.clone(),
-                        game_versions: v.game_versions.clone(),
-                    });
-                }
+    // Two batched requests instead of one versions request per hit
+    let ids: Vec<String> = hits.iter().map(|h| h.project_id.clone()).collect();
+    let projects = crate::modrinth::get_projects_batch(&ids).await?;
+    let version_ids: Vec<String> = projects
+        .iter()
+        .flat_map(|p| p.versions.iter().cloned())
+        .collect();
+    // Candidates from the batches that did load beat no candidates at all
+    let mut versions = crate::modrinth::get_versions_batch_lenient(&version_ids).await?;
+    // Batch results are unordered; keep newest first like `/project/{id}/version`
+    versions.sort_by(|a, b| b.date_published.cmp(&a.date_published));
+
+    for hit in hits.iter() {
+        for v in versions.iter().filter(|v| v.project_id == hit.project_id) {
+            // Compatible if version.game_versions includes mc_version
+            if v.game_versions.iter().any(|g| g == &mc_version) {
+                results.push(LoaderCandidate {
+                    project_id: hit.project_id.clone(),
+                    project_title: hit.title.clone(),
+                    version_id: v.id.clone(),
+                    version_number: v.version_number.clone(),
+                    game_versions: v.game_versions.clone(),
+                });
             }
         }
     }
 
     Ok(results)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derived_version_id_format() {
+        assert_eq!(
+            derived_version_id("fabric", "0.15.11", "1.20.4"),
+            "fabric-loader-0.15.11-1.20.4"
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Launcher log: `log` records go to `launcher.log` in the app log dir, since a
+//! windowed release build has no console for stdout.
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+/// Past this size the log is moved to `launcher.log.1` at startup, replacing the old one.
+const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Default and upper bound for how many lines `get_launcher_log` returns.
+const DEFAULT_LOG_LINES: usize = 500;
+const MAX_LOG_LINES: usize = 5000;
+
+struct FileLogger {
+    file: Mutex<Option<File>>,
+}
+
+static LOGGER: FileLogger = FileLogger {
+    file: Mutex::new(None),
+};
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "{} {:<5} [{}] {}",
+            chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ"),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        if cfg!(debug_assertions) || record.level() <= Level::Warn {
+            eprintln!("{}", line);
+        }
+        if let Some(file) = self.file.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = self.file.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Parse a `log_level` setting; `None` for anything unrecognized.
+pub fn parse_level(level: &str) -> Option<LevelFilter> {
+    match level.trim().to_lowercase().as_str() {
+        "off" => Some(LevelFilter::Off),
+        "error" => Some(LevelFilter::Error),
+        "warn" => Some(LevelFilter::Warn),
+        "info" => Some(LevelFilter::Info),
+        "debug" => Some(LevelFilter::Debug),
+        "trace" => Some(LevelFilter::Trace),
+        _ => None,
+    }
+}
+
+pub fn set_level(level: &str) {
+    log::set_max_level(parse_level(level).unwrap_or(LevelFilter::Info));
+}
+
+fn log_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app.path().app_log_dir().map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join("launcher.log"))
+}
+
+/// Install the logger at the default level; the configured level is applied with
+/// `set_level` once settings are loaded. Without a writable log dir records still reach stderr.
+pub fn init(app: &AppHandle) {
+    if log::set_logger(&LOGGER).is_err() {
+        return;
+    }
+    log::set_max_level(LevelFilter::Info);
+
+    let opened = log_path(app).and_then(|path| {
+        if fs::metadata(&path).is_ok_and(|m| m.len() > MAX_LOG_BYTES) {
+            let _ = fs::rename(&path, path.with_extension("log.1"));
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| format!("Cannot open {}: {}", path.display(), e))
+    });
+    match opened {
+        Ok(file) => *LOGGER.file.lock().unwrap_or_else(|e| e.into_inner()) = Some(file),
+        Err(e) => log::error!("Launcher log disabled: {}", e),
+    }
+    log::info!("tauri-mc launcher {} starting", env!("CARGO_PKG_VERSION"));
+}
+
+/// The last `lines` lines of the launcher log (default 500), oldest first.
+#[tauri::command]
+pub fn get_launcher_log(app: AppHandle, lines: Option<usize>) -> Result<Vec<String>, String> {
+    log::logger().flush();
+    let path = log_path(&app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let wanted = lines.unwrap_or(DEFAULT_LOG_LINES).min(MAX_LOG_LINES);
+    let all: Vec<&str> = text.lines().collect();
+    Ok(all[all.len().saturating_sub(wanted)..]
+        .iter()
+        .map(|l| l.to_string())
+        .collect())
+}
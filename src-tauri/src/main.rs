@@ -3,147 +3,302 @@
     windows_subsystem = "windows"
 )]
 
+mod accounts;
 mod assets;
 mod commands;
 mod download;
+mod error;
+mod http;
 mod install;
 mod instance;
 mod java;
 mod launch;
 mod loader;
+mod logging;
+mod migrate;
 mod minecraft;
 mod modrinth;
+mod nbt;
 mod rules;
 mod settings;
+mod updater;
 mod version;
+mod version_cmp;
 
+use accounts::{add_offline_account, list_accounts, remove_account, set_active_account};
 use commands::{
+    add_local_mod,
+    add_local_mods,
+    backup_world,
+    change_instance_loader,
+    check_data_dir,
     check_java_compatibility,
     check_mod_updates,
     check_version_usage,
     cleanup_unused_versions,
+    clear_all_caches,
     clear_asset_cache,
     clear_instance_logs,
     create_instance,
     delete_instance,
+    delete_instance_group,
+    delete_launch_profile,
+    delete_screenshot,
+    detect_mod_conflicts,
+    diagnose_data_dir,
     download_loader_version,
     download_version,
+    dry_run_launch,
+    ensure_fabric_api,
+    export_instance,
     find_loader_candidates,
+    get_cached_icon,
     get_cleanup_info,
     get_compatible_mod_versions,
+    get_compatible_project_versions,
     // New commands
     get_instance_crash_logs,
     get_instance_minecraft_dir,
     get_instance_saves_dir,
     get_instance_screenshots_dir,
+    get_instance_size,
     get_last_launch_log,
     get_loader_versions,
+    get_mod_icon,
+    get_modrinth_version,
     get_popular_mods,
     get_project_versions,
+    get_recommended_loader_version,
+    get_shader_support,
     get_system_info,
     get_version_manifest,
+    get_world_stats,
+    import_external_instance,
+    inspect_external_dir,
     install_loader,
+    install_modpack_from_url,
     install_modpack_version,
+    install_modrinth_file,
     install_modrinth_mod,
+    install_mods,
+    is_instance_installed,
+    is_version_installed,
+    kill_all_instances,
     kill_instance,
     launch_instance,
+    launch_safe_mode,
+    list_installed_versions,
+    list_instance_configs,
+    list_instance_groups,
     list_instance_mods,
     list_instance_screenshots,
     list_instance_servers,
+    list_instance_sizes,
     list_instance_worlds,
     list_instances,
+    list_instances_filtered,
+    list_world_backups,
     open_path,
+    prune_asset_indexes,
+    read_config_file,
+    reinstall_loader,
     remove_mod,
+    rename_instance,
+    rename_instance_group,
+    reset_to_modpack_defaults,
+    resolve_java_for_instance,
+    restore_world_backup,
+    reveal_in_explorer,
     save_instance,
+    save_launch_profile,
     search_projects,
+    set_active_resourcepacks,
+    set_instance_group,
+    set_instance_tags,
+    set_private_versions,
+    tail_instance_log,
     toggle_mod,
+    update_all_mods,
+    validate_java_args,
+    verify_loader,
+    write_config_file,
     ChildProcessState,
 };
-use settings::{get_settings, save_settings};
-use tauri::Manager;
+use logging::get_launcher_log;
+use settings::{
+    export_settings, get_settings, import_settings, recommended_jvm_args, save_settings,
+};
+use updater::check_for_updates;
+use version_cmp::compare_versions;
+
+/// Reset "Running" or "Installing" states left behind by a previous session.
+fn reset_stale_states(data_dir: &std::path::Path) {
+    let Ok(entries) = std::fs::read_dir(data_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let meta_path = entry.path().join("instance.json");
+        if meta_path.exists() {
+            if let Ok(mut inst) = instance::read_instance(&meta_path) {
+                if inst.state == instance::InstanceState::Running
+                    || inst.state == instance::InstanceState::Installing
+                {
+                    inst.state = instance::InstanceState::Ready;
+                    if let Ok(updated) = serde_json::to_string_pretty(&inst) {
+                        let _ = std::fs::write(&meta_path, updated);
+                    }
+                }
+            }
+        }
+    }
+}
 
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .manage(ChildProcessState::default())
         .setup(|app| {
-            // Reset "Running" or "Installing" states on startup
             let app_handle = app.handle();
-            let data_dir = app_handle
-                .path()
-                .app_data_dir()
-                .unwrap()
-                .join("minecraft")
-                .join("instances");
-            if data_dir.exists() {
-                if let Ok(entries) = std::fs::read_dir(data_dir) {
-                    for entry in entries.flatten() {
-                        let meta_path = entry.path().join("instance.json");
-                        if meta_path.exists() {
-                            if let Ok(text) = std::fs::read_to_string(&meta_path) {
-                                if let Ok(mut inst) =
-                                    serde_json::from_str::<instance::Instance>(&text)
-                                {
-                                    if inst.state == instance::InstanceState::Running
-                                        || inst.state == instance::InstanceState::Installing
-                                    {
-                                        inst.state = instance::InstanceState::Ready;
-                                        if let Ok(updated) = serde_json::to_string_pretty(&inst) {
-                                            let _ = std::fs::write(&meta_path, updated);
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+            // Before settings are read, so their migration warnings reach the log
+            logging::init(app_handle);
+            let settings = get_settings(app_handle.clone()).unwrap_or_default();
+            logging::set_level(&settings.log_level);
+            // An unusable data dir is reported to the UI by `check_data_dir`; don't abort startup
+            if let Err(e) = http::configure(&settings) {
+                log::warn!("Ignoring proxy settings: {}", e);
+            }
+            match commands::minecraft_root(app_handle) {
+                Ok(root) => {
+                    reset_stale_states(&root.join("instances"));
+                    commands::restore_safe_mode_mods(app_handle);
                 }
+                Err(e) => log::error!("Launcher data directory is unavailable: {}", e),
             }
+            updater::spawn_startup_check(app_handle.clone());
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             get_version_manifest,
             download_version,
             launch_instance,
+            launch_safe_mode,
+            dry_run_launch,
+            is_version_installed,
+            is_instance_installed,
             list_instances,
+            list_instances_filtered,
+            set_instance_tags,
+            set_instance_group,
+            list_instance_groups,
+            rename_instance_group,
+            delete_instance_group,
+            rename_instance,
+            set_private_versions,
             create_instance,
+            import_external_instance,
+            inspect_external_dir,
             delete_instance,
+            export_instance,
             check_version_usage,
             check_java_compatibility,
+            resolve_java_for_instance,
+            validate_java_args,
             get_settings,
             save_settings,
+            export_settings,
+            import_settings,
+            recommended_jvm_args,
             save_instance,
+            save_launch_profile,
+            delete_launch_profile,
             kill_instance,
+            kill_all_instances,
             search_projects,
             get_project_versions,
+            get_modrinth_version,
             get_compatible_mod_versions,
+            get_compatible_project_versions,
             get_popular_mods,
+            get_cached_icon,
             install_modpack_version,
+            install_modpack_from_url,
+            reset_to_modpack_defaults,
             install_modrinth_mod,
+            install_modrinth_file,
+            install_mods,
+            add_local_mod,
+            add_local_mods,
+            ensure_fabric_api,
             find_loader_candidates,
             download_loader_version,
+            reinstall_loader,
+            verify_loader,
+            change_instance_loader,
             install_loader,
             get_loader_versions,
+            get_recommended_loader_version,
             list_instance_mods,
+            detect_mod_conflicts,
+            get_shader_support,
+            get_mod_icon,
             remove_mod,
             list_instance_screenshots,
+            delete_screenshot,
             list_instance_worlds,
+            get_world_stats,
+            backup_world,
+            list_world_backups,
+            restore_world_backup,
             list_instance_servers,
+            set_active_resourcepacks,
+            list_instance_configs,
+            read_config_file,
+            write_config_file,
             get_instance_minecraft_dir,
             get_instance_screenshots_dir,
             get_instance_saves_dir,
             open_path,
+            reveal_in_explorer,
             // New crash detection and mod management commands
             get_instance_crash_logs,
             get_last_launch_log,
+            tail_instance_log,
             clear_instance_logs,
             check_mod_updates,
+            update_all_mods,
             toggle_mod,
             // New cleanup commands
             get_cleanup_info,
             cleanup_unused_versions,
+            list_installed_versions,
+            prune_asset_indexes,
             clear_asset_cache,
+            clear_all_caches,
+            get_instance_size,
+            list_instance_sizes,
             get_system_info,
+            check_data_dir,
+            diagnose_data_dir,
+            check_for_updates,
+            compare_versions,
+            get_launcher_log,
+            list_accounts,
+            add_offline_account,
+            set_active_account,
+            remove_account,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|app, event| {
+            // Don't leave orphaned games behind, unless the user asked for the
+            // launcher to close while the game keeps running.
+            if let tauri::RunEvent::Exit = event {
+                let close_on_launch = get_settings(app.clone())
+                    .map(|s| s.close_on_launch)
+                    .unwrap_or(false);
+                if !close_on_launch {
+                    commands::kill_all_children(app);
+                }
+            }
+        });
 }
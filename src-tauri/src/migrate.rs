@@ -0,0 +1,186 @@
+//! Tolerant loading for the launcher's own JSON files (settings.json, instance.json).
+//!
+//! A file that no longer matches the current struct (renamed field, changed type,
+//! missing required field) is rebuilt field by field on top of a baseline value;
+//! whatever can't be read is logged and dropped, and the file is rewritten in
+//! the current schema.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// Read `path` as `T`, migrating it to `current_schema` when needed.
+/// `baseline` provides the values used for fields the file doesn't supply.
+pub fn load<T, F>(path: &Path, current_schema: u32, baseline: F) -> Result<T, String>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce() -> T,
+{
+    let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let raw: Value = serde_json::from_str(&text)
+        .map_err(|e| format!("{} is not valid JSON: {}", path.display(), e))?;
+    let file_schema = raw
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+
+    let (value, rebuilt) = match serde_json::from_value::<T>(raw.clone()) {
+        Ok(value) => (value, false),
+        Err(e) => {
+            log::warn!(
+                "{} does not match the current format ({}); migrating",
+                path.display(),
+                e
+            );
+            (merge_fields(path, &raw, baseline())?, true)
+        }
+    };
+
+    // Written by a newer launcher: use what we understand but don't downgrade the file
+    if file_schema > u64::from(current_schema) {
+        return Ok(value);
+    }
+    if !rebuilt && file_schema == u64::from(current_schema) {
+        return Ok(value);
+    }
+
+    let mut out = serde_json::to_value(&value).map_err(|e| e.to_string())?;
+    out["schema_version"] = Value::from(current_schema);
+    if let (Value::Object(before), Value::Object(after)) = (&raw, &out) {
+        for (key, old) in before {
+            let empty = old.is_null() || old.as_array().is_some_and(|a| a.is_empty());
+            if !empty && !after.contains_key(key) {
+                log::warn!("{}: dropped unknown field `{}`", path.display(), key);
+            }
+        }
+    }
+    let text = serde_json::to_string_pretty(&out).map_err(|e| e.to_string())?;
+    // Failing to rewrite is not fatal; the migration simply runs again next load
+    if let Err(e) = fs::write(path, text) {
+        log::warn!("Could not rewrite {}: {}", path.display(), e);
+    }
+    serde_json::from_value(out).map_err(|e| e.to_string())
+}
+
+/// Schema version to write when saving over `path`: never lower than the one already
+/// on disk, so a save from an older launcher doesn't mark a newer file as current.
+pub fn schema_for_save(path: &Path, current_schema: u32) -> u32 {
+    let stored = fs::read_to_string(path)
+        .ok()
+        .and_then(|text| serde_json::from_str::<Value>(&text).ok())
+        .and_then(|raw| raw.get("schema_version").and_then(Value::as_u64))
+        .and_then(|v| u32::try_from(v).ok())
+        .unwrap_or(0);
+    stored.max(current_schema)
+}
+
+/// Apply each field of `raw` to `baseline`, keeping only those that still deserialize.
+fn merge_fields<T>(path: &Path, raw: &Value, baseline: T) -> Result<T, String>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let Value::Object(fields) = raw else {
+        return Err(format!("{} is not a JSON object", path.display()));
+    };
+    let mut merged = serde_json::to_value(baseline).map_err(|e| e.to_string())?;
+    for (key, value) in fields {
+        let mut trial = merged.clone();
+        trial[key.as_str()] = value.clone();
+        if serde_json::from_value::<T>(trial.clone()).is_ok() {
+            merged = trial;
+        } else {
+            log::warn!("{}: dropped unreadable field `{}`", path.display(), key);
+        }
+    }
+    serde_json::from_value(merged).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Sample {
+        #[serde(default)]
+        schema_version: u32,
+        name: String,
+        memory: u32,
+    }
+
+    fn baseline() -> Sample {
+        Sample {
+            schema_version: 2,
+            name: "default".to_string(),
+            memory: 2048,
+        }
+    }
+
+    fn temp_json(name: &str, value: serde_json::Value) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "tauri-mc-{}-migrate-{}.json",
+            std::process::id(),
+            name
+        ));
+        fs::write(&path, value.to_string()).unwrap();
+        path
+    }
+
+    fn read_raw(path: &Path) -> Value {
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn merge_fields_keeps_existing_values() {
+        // `memory` has the wrong type; `name` must survive the rebuild
+        let path = temp_json(
+            "merge",
+            serde_json::json!({ "schema_version": 1, "name": "Survival", "memory": "lots" }),
+        );
+        let loaded: Sample = load(&path, 2, baseline).unwrap();
+        assert_eq!(loaded.name, "Survival");
+        assert_eq!(loaded.memory, 2048);
+        assert_eq!(loaded.schema_version, 2);
+        assert_eq!(read_raw(&path)["name"], "Survival");
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn unknown_fields_survive_when_nothing_needs_migrating() {
+        let path = temp_json(
+            "unknown",
+            serde_json::json!({
+                "schema_version": 2,
+                "name": "Survival",
+                "memory": 4096,
+                "added_later": true,
+            }),
+        );
+        let loaded: Sample = load(&path, 2, baseline).unwrap();
+        assert_eq!(loaded.memory, 4096);
+        assert_eq!(read_raw(&path)["added_later"], true);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn newer_schema_is_not_downgraded() {
+        let path = temp_json(
+            "newer",
+            serde_json::json!({
+                "schema_version": 5,
+                "name": "Survival",
+                "memory": 4096,
+                "added_later": true,
+            }),
+        );
+        let loaded: Sample = load(&path, 2, baseline).unwrap();
+        assert_eq!(loaded.schema_version, 5);
+        let raw = read_raw(&path);
+        assert_eq!(raw["schema_version"], 5);
+        assert_eq!(raw["added_later"], true);
+        assert_eq!(schema_for_save(&path, 2), 5);
+        let _ = fs::remove_file(path);
+    }
+}
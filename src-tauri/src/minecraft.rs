@@ -1,18 +1,29 @@
+use crate::error::AppError;
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-#[derive(Debug, Serialize, Deserialize)]
+const MANIFEST_URL: &str = "https://launchermeta.mojang.com/mc/game/version_manifest_v2.json";
+
+/// How long a fetched manifest is reused before asking Mojang again.
+const MANIFEST_TTL: Duration = Duration::from_secs(10 * 60);
+
+static MANIFEST: Lazy<Mutex<Option<(Instant, VersionManifest)>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VersionManifest {
     pub latest: Latest,
     pub versions: Vec<McVersion>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Latest {
     pub release: String,
     pub snapshot: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McVersion {
     pub id: String,
     #[serde(rename = "type")]
@@ -20,15 +31,47 @@ pub struct McVersion {
     pub releaseTime: String,
     pub url: String,
 }
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManifestVersion {
     pub id: String,
     #[serde(rename = "type")]
     pub version_type: String,
     pub url: String,
 }
-pub async fn get_manifest() -> Result<VersionManifest, String> {
-    let url = "https://launchermeta.mojang.com/mc/game/version_manifest_v2.json";
-    let text = crate::download::download_text(url).await?;
-    serde_json::from_str(&text).map_err(|e| e.to_string())
+/// The version manifest, reused for `MANIFEST_TTL` after a fetch.
+pub async fn get_manifest() -> Result<VersionManifest, AppError> {
+    let cached = MANIFEST
+        .lock()
+        .unwrap()
+        .as_ref()
+        .filter(|(fetched, _)| fetched.elapsed() < MANIFEST_TTL)
+        .map(|(_, manifest)| manifest.clone());
+    match cached {
+        Some(manifest) => Ok(manifest),
+        None => refresh_manifest().await,
+    }
+}
+
+/// Fetch the manifest from Mojang, replacing the cached copy.
+pub async fn refresh_manifest() -> Result<VersionManifest, AppError> {
+    let text = crate::download::download_text(MANIFEST_URL).await?;
+    let manifest: VersionManifest = serde_json::from_str(&text)?;
+    *MANIFEST.lock().unwrap() = Some((Instant::now(), manifest.clone()));
+    Ok(manifest)
+}
+
+/// Look up `version_id` in the manifest. A miss in the cached copy forces one
+/// refresh before giving up with `AppError::UnknownVersion`.
+pub async fn find_version(version_id: &str) -> Result<McVersion, AppError> {
+    let find =
+        |manifest: VersionManifest| manifest.versions.into_iter().find(|v| v.id == version_id);
+    if let Some(version) = find(get_manifest().await?) {
+        return Ok(version);
+    }
+    find(refresh_manifest().await?).ok_or_else(|| {
+        AppError::UnknownVersion(format!(
+            "Minecraft version {} is not in the version manifest",
+            version_id
+        ))
+    })
 }
@@ -1,13 +1,37 @@
-use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use futures_util::stream::{FuturesUnordered, StreamExt};
+use once_cell::sync::Lazy;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::io::Read;
 use std::path::Path;
-use tauri::AppHandle;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tokio::sync::Semaphore;
 
 const MODRINTH_API: &str = "https://api.modrinth.com/v2";
 
+/// Upper bound for the on-disk icon cache before least-recently-used icons are evicted.
+const ICON_CACHE_MAX_BYTES: u64 = 64 * 1024 * 1024;
+
+/// IDs per batched `/projects` or `/versions` request, keeping URLs a sane length.
+const BATCH_SIZE: usize = 200;
+
+const RATE_LIMIT_RETRIES: u32 = 3;
+const MAX_RETRY_WAIT: Duration = Duration::from_secs(60);
+
+/// Modrinth requests allowed in flight at once, across every caller.
+const MAX_CONCURRENT_REQUESTS: usize = 6;
+
+static REQUEST_SLOTS: Semaphore = Semaphore::const_new(MAX_CONCURRENT_REQUESTS);
+
+/// Set when Modrinth answers 429, so every in-flight caller backs off, not just the one that got throttled.
+static RATE_LIMITED_UNTIL: Lazy<Mutex<Option<Instant>>> = Lazy::new(|| Mutex::new(None));
+
 /// ----------------------------
 /// Loader handling
 /// ----------------------------
@@ -63,6 +87,29 @@ pub struct ModrinthVersion {
     pub game_versions: Vec<String>,
     pub loaders: Vec<String>,
     pub files: Vec<ModrinthFile>,
+    #[serde(default)]
+    pub date_published: Option<String>,
+    /// Release notes (Markdown) as written by the author
+    #[serde(default)]
+    pub changelog: Option<String>,
+}
+
+/// A full project as returned by `/project` and `/projects`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModrinthProject {
+    pub id: String,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub icon_url: Option<String>,
+    pub project_type: String,
+    #[serde(default)]
+    pub game_versions: Vec<String>,
+    #[serde(default)]
+    pub loaders: Vec<String>,
+    /// Version IDs, oldest first
+    #[serde(default)]
+    pub versions: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -78,6 +125,15 @@ pub struct ModrinthFile {
     pub filename: String,
     pub primary: bool,
     pub size: u32,
+    /// `algorithm -> hex digest`; Modrinth always sends `sha512` and usually `sha1`.
+    #[serde(default)]
+    pub hashes: HashMap<String, String>,
+}
+
+impl ModrinthFile {
+    pub fn expected_hash(&self) -> Option<crate::download::FileHash> {
+        crate::download::FileHash::from_hashes(&self.hashes)
+    }
 }
 
 /// ----------------------------
@@ -86,9 +142,10 @@ pub struct ModrinthFile {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ModpackIndex {
+    #[serde(alias = "formatVersion")]
     pub format_version: Option<u32>,
     pub game: String,
-    #[serde(alias = "version_Id")]
+    #[serde(alias = "version_Id", alias = "versionId")]
     pub version_id: Option<String>,
     pub name: String,
     pub summary: Option<String>,
@@ -111,21 +168,74 @@ pub struct ModpackEnv {
     pub server: String,
 }
 
-/// ----------------------------
-/// HTTP client
-/// ----------------------------
+/// How long Modrinth asked us to wait (`Retry-After` is in seconds).
+fn retry_after(res: &Response) -> Option<Duration> {
+    res.headers()
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
 
-fn get_client() -> reqwest::Client {
-    let mut headers = HeaderMap::new();
-    headers.insert(
-        USER_AGENT,
-        HeaderValue::from_static("tauri-mc-launcher/1.0.0"),
-    );
+/// Send a Modrinth request, waiting for a free `REQUEST_SLOTS` permit and out
+/// HTTP 429 responses. Honors `Retry-After` when present, otherwise backs off
+/// exponentially.
+async fn send_with_retry(req: RequestBuilder) -> Result<Response, String> {
+    let mut attempt = 0;
+    loop {
+        let blocked_for = RATE_LIMITED_UNTIL
+            .lock()
+            .unwrap()
+            .and_then(|until| until.checked_duration_since(Instant::now()));
+        if let Some(wait) = blocked_for {
+            tokio::time::sleep(wait).await;
+        }
+
+        let res = {
+            let _slot = REQUEST_SLOTS.acquire().await.map_err(|e| e.to_string())?;
+            req.try_clone()
+                .ok_or("request cannot be retried")?
+                .send()
+                .await
+                .map_err(|e| e.to_string())?
+        };
+
+        if res.status() != StatusCode::TOO_MANY_REQUESTS {
+            return Ok(res);
+        }
+        if attempt >= RATE_LIMIT_RETRIES {
+            return Err("Modrinth rate limit exceeded, please try again later".to_string());
+        }
 
-    reqwest::Client::builder()
-        .default_headers(headers)
-        .build()
-        .unwrap()
+        attempt += 1;
+        let wait = retry_after(&res)
+            .unwrap_or_else(|| Duration::from_secs(2u64.pow(attempt)))
+            .min(MAX_RETRY_WAIT);
+        log::warn!(
+            "Modrinth rate limited, retrying in {}s (attempt {}/{})",
+            wait.as_secs(),
+            attempt,
+            RATE_LIMIT_RETRIES
+        );
+        *RATE_LIMITED_UNTIL.lock().unwrap() = Some(Instant::now() + wait);
+    }
+}
+
+/// Deserialize a Modrinth response, reporting the status and a body snippet instead
+/// of a bare serde error when the API answered with an error page.
+async fn read_json<T: DeserializeOwned>(res: Response) -> Result<T, String> {
+    let status = res.status();
+    let text = res.text().await.map_err(|e| e.to_string())?;
+    let snippet = || text.chars().take(200).collect::<String>();
+    if !status.is_success() {
+        return Err(format!(
+            "Modrinth returned {}: {}",
+            status.as_u16(),
+            snippet()
+        ));
+    }
+    serde_json::from_str(&text)
+        .map_err(|e| format!("Unexpected Modrinth response ({}): {}", e, snippet()))
 }
 
 /// ----------------------------
@@ -141,41 +251,158 @@ pub async fn search_projects(
         MODRINTH_API, query, project_type
     );
 
-    get_client()
-        .get(url)
-        .send()
-        .await
-        .map_err(|e| e.to_string())?
-        .json()
-        .await
-        .map_err(|e| e.to_string())
+    read_json(send_with_retry(crate::http::client().get(url)).await?).await
 }
 
 pub async fn get_project_versions(project_id: &str) -> Result<Vec<ModrinthVersion>, String> {
     let url = format!("{}/project/{}/version", MODRINTH_API, project_id);
 
-    get_client()
-        .get(url)
-        .send()
-        .await
-        .map_err(|e| e.to_string())?
-        .json()
-        .await
-        .map_err(|e| e.to_string())
+    read_json(send_with_retry(crate::http::client().get(url)).await?).await
+}
+
+/// Fetch a project's versions for one game version and loader, letting Modrinth
+/// do the filtering. Results are sorted newest-first.
+pub async fn get_filtered_project_versions(
+    project_id: &str,
+    mc_version: &str,
+    loader: &str,
+) -> Result<Vec<ModrinthVersion>, String> {
+    let url = format!("{}/project/{}/version", MODRINTH_API, project_id);
+
+    let res = send_with_retry(crate::http::client().get(url).query(&[
+        ("game_versions", format!("[\"{}\"]", mc_version)),
+        ("loaders", format!("[\"{}\"]", loader)),
+    ]))
+    .await?;
+    let versions: Vec<ModrinthVersion> = read_json(res).await?;
+
+    // Modrinth already filters, but don't trust it blindly
+    let mut versions = filter_compatible_versions(versions, mc_version, loader);
+    versions.sort_by(|a, b| b.date_published.cmp(&a.date_published));
+    Ok(versions)
 }
 
 /// Fetch a single version by its Modrinth version ID.
 pub async fn get_version(version_id: &str) -> Result<ModrinthVersion, String> {
     let url = format!("{}/version/{}", MODRINTH_API, version_id);
 
-    get_client()
-        .get(url)
-        .send()
-        .await
-        .map_err(|e| e.to_string())?
-        .json()
-        .await
-        .map_err(|e| e.to_string())
+    read_json(send_with_retry(crate::http::client().get(url)).await?).await
+}
+
+/// Fetch many versions by ID, `BATCH_SIZE` per request with the requests run side by side.
+/// Unknown IDs are skipped by Modrinth, and the result is in no particular order.
+pub async fn get_versions_batch(version_ids: &[String]) -> Result<Vec<ModrinthVersion>, String> {
+    fetch_versions_batch(version_ids, false).await
+}
+
+/// `get_versions_batch` for best-effort lookups: a failed request is logged and
+/// only loses its own chunk. Fails only when every request did.
+pub async fn get_versions_batch_lenient(
+    version_ids: &[String],
+) -> Result<Vec<ModrinthVersion>, String> {
+    fetch_versions_batch(version_ids, true).await
+}
+
+async fn fetch_versions_batch(
+    version_ids: &[String],
+    skip_failed: bool,
+) -> Result<Vec<ModrinthVersion>, String> {
+    let url = format!("{}/versions", MODRINTH_API);
+    let mut requests: FuturesUnordered<_> = version_ids
+        .chunks(BATCH_SIZE)
+        .map(|chunk| {
+            let url = &url;
+            async move {
+                let ids = serde_json::to_string(chunk).map_err(|e| e.to_string())?;
+                let res =
+                    send_with_retry(crate::http::client().get(url).query(&[("ids", ids)])).await?;
+                read_json::<Vec<ModrinthVersion>>(res).await
+            }
+        })
+        .collect();
+    let mut versions = Vec::with_capacity(version_ids.len());
+    let mut succeeded = version_ids.is_empty();
+    let mut last_err = None;
+    while let Some(chunk) = requests.next().await {
+        match chunk {
+            Ok(chunk) => {
+                succeeded = true;
+                versions.extend(chunk);
+            }
+            Err(e) if skip_failed => {
+                log::warn!("Skipping a failed /versions batch: {}", e);
+                last_err = Some(e);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    match last_err {
+        Some(e) if !succeeded => Err(e),
+        _ => Ok(versions),
+    }
+}
+
+/// Fetch many projects by ID or slug, `BATCH_SIZE` per request, in no particular order.
+pub async fn get_projects_batch(project_ids: &[String]) -> Result<Vec<ModrinthProject>, String> {
+    let url = format!("{}/projects", MODRINTH_API);
+    let mut requests: FuturesUnordered<_> = project_ids
+        .chunks(BATCH_SIZE)
+        .map(|chunk| {
+            let url = &url;
+            async move {
+                let ids = serde_json::to_string(chunk).map_err(|e| e.to_string())?;
+                let res =
+                    send_with_retry(crate::http::client().get(url).query(&[("ids", ids)])).await?;
+                read_json::<Vec<ModrinthProject>>(res).await
+            }
+        })
+        .collect();
+    let mut projects = Vec::with_capacity(project_ids.len());
+    while let Some(chunk) = requests.next().await {
+        projects.extend(chunk?);
+    }
+    Ok(projects)
+}
+
+/// The version each SHA1 belongs to, keyed by the hash it was asked for.
+/// Hashes Modrinth doesn't know are absent from the map.
+pub async fn versions_for_hashes(
+    sha1_hashes: &[String],
+) -> Result<HashMap<String, ModrinthVersion>, String> {
+    let url = format!("{}/version_files", MODRINTH_API);
+    let mut versions = HashMap::with_capacity(sha1_hashes.len());
+    for chunk in sha1_hashes.chunks(BATCH_SIZE) {
+        let body = serde_json::json!({
+            "hashes": chunk,
+            "algorithm": "sha1",
+        });
+        let res = send_with_retry(crate::http::client().post(&url).json(&body)).await?;
+        versions.extend(read_json::<HashMap<String, ModrinthVersion>>(res).await?);
+    }
+    Ok(versions)
+}
+
+/// For files identified by SHA1, the newest version of their project that fits
+/// `mc_version` and `loader`, keyed by the hash it was asked for. Hashes Modrinth
+/// doesn't know, or whose project has no such version, are absent from the map.
+pub async fn latest_versions_for_hashes(
+    sha1_hashes: &[String],
+    mc_version: &str,
+    loader: ModLoader,
+) -> Result<HashMap<String, ModrinthVersion>, String> {
+    let url = format!("{}/version_files/update", MODRINTH_API);
+    let mut latest = HashMap::with_capacity(sha1_hashes.len());
+    for chunk in sha1_hashes.chunks(BATCH_SIZE) {
+        let body = serde_json::json!({
+            "hashes": chunk,
+            "algorithm": "sha1",
+            "loaders": [loader.as_str()],
+            "game_versions": [mc_version],
+        });
+        let res = send_with_retry(crate::http::client().post(&url).json(&body)).await?;
+        latest.extend(read_json::<HashMap<String, ModrinthVersion>>(res).await?);
+    }
+    Ok(latest)
 }
 
 /// Fetch popular mods (sorted by downloads). Used for discovery.
@@ -186,14 +413,93 @@ pub async fn get_popular_mods(limit: usize) -> Result<ModrinthSearchResult, Stri
         MODRINTH_API, limit
     );
 
-    get_client()
+    read_json(send_with_retry(crate::http::client().get(url)).await?).await
+}
+
+/// ----------------------------
+/// Icon cache
+/// ----------------------------
+
+/// Local copy of a project icon, downloaded on first use. Files are named by the
+/// SHA1 of their URL; mtime doubles as the last-access time for LRU eviction.
+pub async fn cached_icon(app: &AppHandle, url: &str) -> Result<std::path::PathBuf, String> {
+    if !url.starts_with("https://") && !url.starts_with("http://") {
+        return Err(format!("Not an icon URL: {}", url));
+    }
+
+    let dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| e.to_string())?
+        .join("icon_cache");
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let ext = url
+        .rsplit('/')
+        .next()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_lowercase())
+        .filter(|ext| ext.len() <= 5 && ext.chars().all(|c| c.is_ascii_alphanumeric()))
+        .unwrap_or_else(|| "img".to_string());
+    let key = {
+        use sha1::{Digest, Sha1};
+        hex::encode(Sha1::digest(url.as_bytes()))
+    };
+    let path = dir.join(format!("{}.{}", key, ext));
+
+    if path.exists() {
+        // Touch so recently viewed icons survive eviction
+        if let Ok(f) = fs::File::options().append(true).open(&path) {
+            let _ = f.set_modified(std::time::SystemTime::now());
+        }
+        return Ok(path);
+    }
+
+    // Icons come from the CDN, not the API, so they don't take Modrinth rate-limit slots
+    let res = crate::http::client()
         .get(url)
         .send()
         .await
-        .map_err(|e| e.to_string())?
-        .json()
-        .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    if !res.status().is_success() {
+        return Err(format!("HTTP {} fetching icon {}", res.status(), url));
+    }
+    let bytes = res.bytes().await.map_err(|e| e.to_string())?;
+    fs::write(&path, &bytes).map_err(|e| e.to_string())?;
+
+    evict_icons(&dir, &path);
+    Ok(path)
+}
+
+/// Delete least-recently-used icons until the cache fits `ICON_CACHE_MAX_BYTES`.
+fn evict_icons(dir: &Path, keep: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut files: Vec<(std::path::PathBuf, u64, std::time::SystemTime)> = entries
+        .flatten()
+        .filter_map(|e| {
+            let meta = e.metadata().ok().filter(|m| m.is_file())?;
+            Some((e.path(), meta.len(), meta.modified().ok()?))
+        })
+        .collect();
+
+    let mut total: u64 = files.iter().map(|(_, size, _)| size).sum();
+    if total <= ICON_CACHE_MAX_BYTES {
+        return;
+    }
+    files.sort_by_key(|(_, _, modified)| *modified);
+    for (path, size, _) in files {
+        if total <= ICON_CACHE_MAX_BYTES {
+            break;
+        }
+        if path == keep {
+            continue;
+        }
+        if fs::remove_file(&path).is_ok() {
+            total = total.saturating_sub(size);
+        }
+    }
 }
 
 /// ----------------------------
@@ -203,13 +509,13 @@ pub async fn get_popular_mods(limit: usize) -> Result<ModrinthSearchResult, Stri
 pub fn filter_compatible_versions(
     versions: Vec<ModrinthVersion>,
     mc_version: &str,
-    loader: ModLoader,
+    loader: &str,
 ) -> Vec<ModrinthVersion> {
     versions
         .into_iter()
         .filter(|v| {
             v.game_versions.iter().any(|gv| gv == mc_version)
-                && v.loaders.iter().any(|l| l == loader.as_str())
+                && v.loaders.iter().any(|l| l == loader)
         })
         .collect()
 }
@@ -224,7 +530,7 @@ pub async fn resolve_mod_version(
     loader: ModLoader,
 ) -> Result<ModrinthVersion, String> {
     let versions = get_project_versions(project_id).await?;
-    let compatible = filter_compatible_versions(versions, mc_version, loader);
+    let compatible = filter_compatible_versions(versions, mc_version, loader.as_str());
 
     pick_best_version(&compatible).ok_or_else(|| "No compatible mod version found".to_string())
 }
@@ -234,20 +540,36 @@ pub async fn list_compatible_versions(
     mc_version: &str,
     loader: ModLoader,
 ) -> Result<Vec<ModrinthVersion>, String> {
-    let versions = get_project_versions(project_id).await?;
-    Ok(filter_compatible_versions(versions, mc_version, loader))
+    get_filtered_project_versions(project_id, mc_version, loader.as_str()).await
 }
 
 /// ----------------------------
 /// File selection
 /// ----------------------------
 
+/// Build artifacts some projects upload next to the real jar.
+fn is_auxiliary_artifact(file_name: &str) -> bool {
+    let name = file_name.to_lowercase();
+    let stem = name.strip_suffix(".jar").unwrap_or(&name);
+    stem.ends_with("-sources") || stem.ends_with("-javadoc")
+}
+
+/// The file to install from a version: the primary one, but never a sources or
+/// javadoc jar, and a `.jar` when several files are marked primary.
 pub fn select_primary_file(version: &ModrinthVersion) -> Result<&ModrinthFile, String> {
     version
         .files
         .iter()
-        .find(|f| f.primary)
-        .or_else(|| version.files.first())
+        .enumerate()
+        .min_by_key(|(i, f)| {
+            (
+                is_auxiliary_artifact(&f.filename),
+                !f.primary,
+                !f.filename.to_lowercase().ends_with(".jar"),
+                *i,
+            )
+        })
+        .map(|(_, f)| f)
         .ok_or_else(|| "No downloadable file found".to_string())
 }
 
@@ -266,7 +588,136 @@ pub fn parse_mrpack_index(mrpack_path: &Path) -> Result<ModpackIndex, String> {
         .read_to_string(&mut index_content)
         .map_err(|e| e.to_string())?;
 
-    serde_json::from_str(&index_content).map_err(|e| e.to_string())
+    let index: ModpackIndex = serde_json::from_str(&index_content).map_err(|e| e.to_string())?;
+    validate_mrpack_index(&index)?;
+    Ok(index)
+}
+
+/// The only `.mrpack` format version there is so far.
+const SUPPORTED_MRPACK_FORMAT: u32 = 1;
+
+/// Reject an index we can't install before anything is downloaded for it: a pack
+/// for another game, a newer format, or one that doesn't name its Minecraft version.
+pub fn validate_mrpack_index(index: &ModpackIndex) -> Result<(), String> {
+    if index.game != "minecraft" {
+        return Err(format!(
+            "The modpack is for \"{}\", not Minecraft",
+            index.game
+        ));
+    }
+    match index.format_version {
+        Some(SUPPORTED_MRPACK_FORMAT) => {}
+        Some(v) => {
+            return Err(format!(
+                "Unsupported modpack format version {} (expected {})",
+                v, SUPPORTED_MRPACK_FORMAT
+            ))
+        }
+        None => return Err("The modpack index has no format version".to_string()),
+    }
+    if index
+        .version_id
+        .as_deref()
+        .filter(|v| !v.trim().is_empty())
+        .is_none()
+    {
+        return Err("The modpack index has no version id".to_string());
+    }
+    if index
+        .dependencies
+        .get("minecraft")
+        .filter(|v| !v.trim().is_empty())
+        .is_none()
+    {
+        return Err("The modpack doesn't say which Minecraft version it needs".to_string());
+    }
+    Ok(())
+}
+
+/// How a pack's indexed files split between client and server.
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ModpackSides {
+    /// Files the client installs (`env.client` not `unsupported`, or no `env`)
+    pub client_files: usize,
+    /// Files marked `env.client: unsupported`, which `install_mrpack` skips
+    pub server_only_files: usize,
+}
+
+impl ModpackSides {
+    /// Mostly server-only files means a server pack: the client install ends up near empty.
+    pub fn looks_like_server_pack(&self) -> bool {
+        self.server_only_files > 0 && self.server_only_files > self.client_files
+    }
+}
+
+fn client_supported(file: &ModpackFile) -> bool {
+    file.env
+        .as_ref()
+        .map(|e| e.client != "unsupported")
+        .unwrap_or(true)
+}
+
+pub fn count_sides(index: &ModpackIndex) -> ModpackSides {
+    let client_files = index.files.iter().filter(|f| client_supported(f)).count();
+    ModpackSides {
+        client_files,
+        server_only_files: index.files.len() - client_files,
+    }
+}
+
+/// Files that only mean something to a dedicated server. Server packs put them at the
+/// top of `overrides/`; written into a client game dir they're just clutter.
+const SERVER_ONLY_OVERRIDES: &[&str] = &[
+    "server.properties",
+    "eula.txt",
+    "ops.json",
+    "whitelist.json",
+    "banned-players.json",
+    "banned-ips.json",
+    "server-icon.png",
+    "user_jvm_args.txt",
+];
+
+fn server_only_override(rel: &Path) -> bool {
+    if rel.components().count() != 1 {
+        return false;
+    }
+    let name = rel.to_string_lossy().to_lowercase();
+    SERVER_ONLY_OVERRIDES.contains(&name.as_str())
+        || (name.starts_with("start")
+            && (name.ends_with(".sh") || name.ends_with(".bat") || name.ends_with(".cmd")))
+}
+
+/// Written next to instance.json by `install_mrpack`: what the pack put into the game dir.
+pub const MODPACK_MANIFEST: &str = ".modpack_manifest.json";
+/// Copy of the installed `.mrpack`, kept for its overrides.
+pub const MODPACK_ARCHIVE: &str = ".modpack.mrpack";
+
+/// A modpack's shipped state, so `reset_mrpack` can return an instance to it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModpackManifest {
+    pub name: String,
+    /// Indexed files that were downloaded (client-supported ones only)
+    pub files: Vec<ModpackFile>,
+    /// Paths relative to `.minecraft` written from the pack's overrides
+    pub overrides: Vec<String>,
+}
+
+/// Download one indexed file, trying its mirrors in order.
+async fn download_pack_file(file: &ModpackFile, target: &Path) -> Result<(), String> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let expected = crate::download::FileHash::from_hashes(&file.hashes);
+    for url in &file.downloads {
+        if crate::download::download_verified(url, target, expected.as_ref())
+            .await
+            .is_ok()
+        {
+            return Ok(());
+        }
+    }
+    Err(format!("Failed to download {}", file.path))
 }
 
 pub async fn install_mrpack(
@@ -278,37 +729,179 @@ pub async fn install_mrpack(
     let root = crate::commands::instance_dir(app, instance_id)?;
     let mc_dir = root.join(".minecraft");
 
-    for file in &index.files {
-        let client_ok = file
-            .env
-            .as_ref()
-            .map(|e| e.client != "unsupported")
-            .unwrap_or(true);
+    let files: Vec<ModpackFile> = index
+        .files
+        .iter()
+        .filter(|f| client_supported(f))
+        .cloned()
+        .collect();
+    for file in &files {
+        let target = crate::commands::resolve_within(&mc_dir, &file.path)?;
+        download_pack_file(file, &target).await?;
+    }
+
+    crate::install::emit_install_phase(
+        app,
+        Some(instance_id),
+        crate::install::InstallPhase::ExtractingOverrides,
+    );
+    let overrides = extract_overrides(mrpack_path, &mc_dir, false)?;
+
+    // Only needed by a later reset, so failing to record it doesn't fail the install
+    let manifest = ModpackManifest {
+        name: index.name.clone(),
+        files,
+        overrides,
+    };
+    let saved = fs::copy(mrpack_path, root.join(MODPACK_ARCHIVE))
+        .map_err(|e| e.to_string())
+        .and_then(|_| serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string()))
+        .and_then(|text| fs::write(root.join(MODPACK_MANIFEST), text).map_err(|e| e.to_string()));
+    if let Err(e) = saved {
+        log::warn!(
+            "Could not record modpack manifest for {}: {}",
+            instance_id,
+            e
+        );
+    }
 
-        if !client_ok {
-            continue;
-        }
+    Ok(index)
+}
+
+/// Copy `overrides/` then `client-overrides/` (which wins) from the pack into the game dir,
+/// leaving out server-only files such as `server.properties`, and `saves/` when `skip_saves`
+/// is set. Returns the files written, relative to the game dir.
+fn extract_overrides(
+    mrpack_path: &Path,
+    mc_dir: &Path,
+    skip_saves: bool,
+) -> Result<Vec<String>, String> {
+    let file = fs::File::open(mrpack_path).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
 
-        let target = mc_dir.join(&file.path);
-        if let Some(parent) = target.parent() {
-            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    let mut written = Vec::new();
+    for prefix in ["overrides", "client-overrides"] {
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i).map_err(|e| e.to_string())?;
+            // enclosed_name rejects absolute paths and `..` components
+            let Some(path) = entry.enclosed_name() else {
+                continue;
+            };
+            let Ok(rel) = path.strip_prefix(prefix) else {
+                continue;
+            };
+            if rel.as_os_str().is_empty() || server_only_override(rel) {
+                continue;
+            }
+            if skip_saves && rel.starts_with("saves") {
+                continue;
+            }
+            let target = mc_dir.join(rel);
+            if entry.is_dir() {
+                fs::create_dir_all(&target).map_err(|e| e.to_string())?;
+                continue;
+            }
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            let mut out = fs::File::create(&target).map_err(|e| e.to_string())?;
+            std::io::copy(&mut entry, &mut out).map_err(|e| e.to_string())?;
+            let rel = rel
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if !written.contains(&rel) {
+                written.push(rel);
+            }
         }
+    }
+    Ok(written)
+}
 
-        let mut success = false;
-        for url in &file.downloads {
-            if crate::download::download_to_file(url, &target)
-                .await
-                .is_ok()
-            {
-                success = true;
-                break;
+/// What `reset_mrpack` changed, as paths relative to `.minecraft`.
+#[derive(Debug, Serialize, Default)]
+pub struct ModpackReset {
+    /// Mods the user added after installing
+    pub removed: Vec<String>,
+    /// Pack files that were missing, disabled or modified
+    pub restored: Vec<String>,
+    pub overrides_applied: usize,
+}
+
+/// Return an instance to the state its modpack shipped in: mods that aren't part of the
+/// pack are deleted, pack files that are missing, disabled or changed are put back, and
+/// the overrides are written again. Saves are never touched.
+pub async fn reset_mrpack(app: &AppHandle, instance_id: &str) -> Result<ModpackReset, String> {
+    let root = crate::commands::instance_dir(app, instance_id)?;
+    let mc_dir = root.join(".minecraft");
+    let text = fs::read_to_string(root.join(MODPACK_MANIFEST))
+        .map_err(|_| "This instance has no record of a modpack install to reset to".to_string())?;
+    let manifest: ModpackManifest = serde_json::from_str(&text).map_err(|e| e.to_string())?;
+    let mut reset = ModpackReset::default();
+
+    let shipped: std::collections::HashSet<&str> = manifest
+        .files
+        .iter()
+        .map(|f| f.path.as_str())
+        .chain(manifest.overrides.iter().map(String::as_str))
+        .collect();
+    if let Ok(entries) = fs::read_dir(mc_dir.join("mods")) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            let rel = format!("mods/{}", name.trim_end_matches(".disabled"));
+            if !shipped.contains(rel.as_str()) && fs::remove_file(&path).is_ok() {
+                reset.removed.push(format!("mods/{}", name));
             }
         }
+    }
 
-        if !success {
-            return Err(format!("Failed to download {}", file.path));
+    for file in &manifest.files {
+        let target = crate::commands::resolve_within(&mc_dir, &file.path)?;
+        let disabled = target.with_file_name(format!(
+            "{}.disabled",
+            target.file_name().unwrap_or_default().to_string_lossy()
+        ));
+        let mut restored = false;
+        if !target.exists() && disabled.exists() {
+            fs::rename(&disabled, &target).map_err(|e| e.to_string())?;
+            restored = true;
+        }
+        let expected = crate::download::FileHash::from_hashes(&file.hashes);
+        let intact = target.exists()
+            && expected
+                .as_ref()
+                .map_or(true, |h| crate::download::verify_hash(&target, h).is_ok());
+        if !intact {
+            download_pack_file(file, &target).await?;
+            restored = true;
+        }
+        if restored {
+            reset.restored.push(file.path.clone());
         }
     }
 
-    Ok(index)
+    let archive = root.join(MODPACK_ARCHIVE);
+    if archive.exists() {
+        reset.overrides_applied = extract_overrides(&archive, &mc_dir, true)?.len();
+    } else {
+        log::warn!(
+            "{} has no saved modpack archive; overrides were not reapplied",
+            instance_id
+        );
+    }
+
+    log::info!(
+        "Reset {} to {}: {} removed, {} restored, {} overrides",
+        instance_id,
+        manifest.name,
+        reset.removed.len(),
+        reset.restored.len(),
+        reset.overrides_applied
+    );
+    Ok(reset)
 }
@@ -0,0 +1,323 @@
+//! Minimal read-only NBT decoder, enough for `level.dat` and `servers.dat`.
+//! Accepts both gzip-compressed and raw input.
+
+use flate2::read::GzDecoder;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+
+// Every tag is decoded so parsing stays in sync; callers only read a few kinds
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Tag {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(Vec<u8>),
+    String(String),
+    List(Vec<Tag>),
+    Compound(HashMap<String, Tag>),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
+}
+
+impl Tag {
+    pub fn get(&self, key: &str) -> Option<&Tag> {
+        match self {
+            Tag::Compound(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Tag::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Any integer tag widened to i64.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Tag::Byte(v) => Some(*v as i64),
+            Tag::Short(v) => Some(*v as i64),
+            Tag::Int(v) => Some(*v as i64),
+            Tag::Long(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[Tag]> {
+        match self {
+            Tag::List(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+// Deeper than anything vanilla writes; guards against malicious files
+const MAX_DEPTH: usize = 512;
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|&e| e <= self.buf.len())
+            .ok_or("Unexpected end of NBT data")?;
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn i16(&mut self) -> Result<i16, String> {
+        Ok(i16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Result<i64, String> {
+        Ok(i64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn len(&mut self) -> Result<usize, String> {
+        let n = self.i32()?;
+        usize::try_from(n).map_err(|_| format!("Negative NBT length {}", n))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let n = self.i16()? as u16 as usize;
+        // Java's modified UTF-8 only differs for NUL and supplementary chars
+        Ok(String::from_utf8_lossy(self.take(n)?).into_owned())
+    }
+
+    fn payload(&mut self, id: u8, depth: usize) -> Result<Tag, String> {
+        if depth > MAX_DEPTH {
+            return Err("NBT nested too deeply".to_string());
+        }
+        Ok(match id {
+            1 => Tag::Byte(self.u8()? as i8),
+            2 => Tag::Short(self.i16()?),
+            3 => Tag::Int(self.i32()?),
+            4 => Tag::Long(self.i64()?),
+            5 => Tag::Float(f32::from_bits(self.i32()? as u32)),
+            6 => Tag::Double(f64::from_bits(self.i64()? as u64)),
+            7 => {
+                let n = self.len()?;
+                Tag::ByteArray(self.take(n)?.to_vec())
+            }
+            8 => Tag::String(self.string()?),
+            9 => {
+                let item = self.u8()?;
+                let n = self.len()?;
+                let mut items = Vec::with_capacity(n.min(1024));
+                for _ in 0..n {
+                    items.push(self.payload(item, depth + 1)?);
+                }
+                Tag::List(items)
+            }
+            10 => {
+                let mut map = HashMap::new();
+                loop {
+                    let child = self.u8()?;
+                    if child == 0 {
+                        break;
+                    }
+                    let name = self.string()?;
+                    map.insert(name, self.payload(child, depth + 1)?);
+                }
+                Tag::Compound(map)
+            }
+            11 => {
+                let n = self.len()?;
+                let mut v = Vec::with_capacity(n.min(1024));
+                for _ in 0..n {
+                    v.push(self.i32()?);
+                }
+                Tag::IntArray(v)
+            }
+            12 => {
+                let n = self.len()?;
+                let mut v = Vec::with_capacity(n.min(1024));
+                for _ in 0..n {
+                    v.push(self.i64()?);
+                }
+                Tag::LongArray(v)
+            }
+            other => return Err(format!("Unknown NBT tag id {}", other)),
+        })
+    }
+}
+
+/// Decode a named root tag from `bytes`, gunzipping first if needed.
+pub fn parse(bytes: &[u8]) -> Result<Tag, String> {
+    let mut inflated = Vec::new();
+    let data = if bytes.starts_with(&[0x1f, 0x8b]) {
+        GzDecoder::new(bytes)
+            .read_to_end(&mut inflated)
+            .map_err(|e| format!("Invalid gzip data: {}", e))?;
+        &inflated[..]
+    } else {
+        bytes
+    };
+
+    let mut r = Reader { buf: data, pos: 0 };
+    let id = r.u8()?;
+    if id == 0 {
+        return Ok(Tag::Compound(HashMap::new()));
+    }
+    let _root_name = r.string()?;
+    r.payload(id, 0)
+}
+
+pub fn read_file(path: &Path) -> Result<Tag, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    fn name(out: &mut Vec<u8>, s: &str) {
+        out.extend_from_slice(&(s.len() as u16).to_be_bytes());
+        out.extend_from_slice(s.as_bytes());
+    }
+
+    // Root compound shaped like level.dat: Data { LevelName, LastPlayed, Version { Name } }
+    fn level_dat() -> Vec<u8> {
+        let mut out = vec![10];
+        name(&mut out, "");
+        out.push(10);
+        name(&mut out, "Data");
+        out.push(8);
+        name(&mut out, "LevelName");
+        name(&mut out, "My World");
+        out.push(4);
+        name(&mut out, "LastPlayed");
+        out.extend_from_slice(&1_700_000_000_000i64.to_be_bytes());
+        out.push(10);
+        name(&mut out, "Version");
+        out.push(8);
+        name(&mut out, "Name");
+        name(&mut out, "1.20.1");
+        out.push(0);
+        out.push(0);
+        out.push(0);
+        out
+    }
+
+    #[test]
+    fn gzip_level_dat_round_trip() {
+        let mut gz = GzEncoder::new(Vec::new(), Compression::default());
+        gz.write_all(&level_dat()).unwrap();
+        let compressed = gz.finish().unwrap();
+
+        for bytes in [compressed, level_dat()] {
+            let root = parse(&bytes).unwrap();
+            let data = root.get("Data").unwrap();
+            assert_eq!(
+                data.get("LevelName").and_then(Tag::as_str),
+                Some("My World")
+            );
+            assert_eq!(
+                data.get("LastPlayed").and_then(Tag::as_i64),
+                Some(1_700_000_000_000)
+            );
+            let version = data.get("Version").and_then(|v| v.get("Name"));
+            assert_eq!(version.and_then(Tag::as_str), Some("1.20.1"));
+        }
+    }
+
+    #[test]
+    fn decodes_lists_of_compounds() {
+        // servers.dat: servers: [ { name, ip }, { name, ip } ]
+        let mut out = vec![10];
+        name(&mut out, "");
+        out.push(9);
+        name(&mut out, "servers");
+        out.push(10);
+        out.extend_from_slice(&2i32.to_be_bytes());
+        for (n, ip) in [("Local", "localhost"), ("Hub", "mc.example.net")] {
+            out.push(8);
+            name(&mut out, "name");
+            name(&mut out, n);
+            out.push(8);
+            name(&mut out, "ip");
+            name(&mut out, ip);
+            out.push(0);
+        }
+        out.push(9);
+        name(&mut out, "empty");
+        out.push(0);
+        out.extend_from_slice(&0i32.to_be_bytes());
+        out.push(0);
+
+        let root = parse(&out).unwrap();
+        let servers = root.get("servers").and_then(Tag::as_list).unwrap();
+        let ips: Vec<_> = servers
+            .iter()
+            .map(|s| s.get("ip").and_then(Tag::as_str).unwrap())
+            .collect();
+        assert_eq!(ips, ["localhost", "mc.example.net"]);
+        assert_eq!(servers[1].get("name").and_then(Tag::as_str), Some("Hub"));
+        assert_eq!(
+            root.get("empty").and_then(Tag::as_list).map(<[_]>::len),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn truncated_input_is_an_error() {
+        let full = level_dat();
+        for len in 1..full.len() {
+            assert!(parse(&full[..len]).is_err(), "truncated to {}", len);
+        }
+        assert!(parse(&[0x1f, 0x8b, 0x08]).is_err());
+    }
+
+    #[test]
+    fn oversized_lengths_are_errors() {
+        // Each declares i32::MAX elements but carries none; must fail without
+        // reserving the declared size up front
+        for id in [7u8, 11, 12] {
+            let mut out = vec![id];
+            name(&mut out, "");
+            out.extend_from_slice(&i32::MAX.to_be_bytes());
+            assert!(parse(&out).is_err(), "tag {}", id);
+        }
+
+        let mut list = vec![9];
+        name(&mut list, "");
+        list.push(4);
+        list.extend_from_slice(&i32::MAX.to_be_bytes());
+        assert!(parse(&list).is_err());
+
+        let mut negative = vec![7];
+        name(&mut negative, "");
+        negative.extend_from_slice(&(-1i32).to_be_bytes());
+        assert!(parse(&negative).is_err());
+
+        let mut string = vec![8];
+        name(&mut string, "");
+        string.extend_from_slice(&u16::MAX.to_be_bytes());
+        assert!(parse(&string).is_err());
+    }
+}
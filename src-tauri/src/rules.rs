@@ -7,7 +7,26 @@ const OS: &str = "osx";
 
 use crate::version::Rule;
 
+/// Launcher features that rules can test for. Unlisted features count as off.
+#[derive(Debug, Default, Clone)]
+pub struct Features {
+    pub is_demo_user: bool,
+}
+
+impl Features {
+    fn get(&self, name: &str) -> bool {
+        match name {
+            "is_demo_user" => self.is_demo_user,
+            _ => false,
+        }
+    }
+}
+
 pub fn rules_allow(rules: &[Rule]) -> bool {
+    rules_allow_with(rules, &Features::default())
+}
+
+pub fn rules_allow_with(rules: &[Rule], features: &Features) -> bool {
     if rules.is_empty() {
         return true;
     }
@@ -18,7 +37,10 @@ pub fn rules_allow(rules: &[Rule]) -> bool {
         let applies = match &rule.os {
             Some(os) => os.name == OS,
             None => true,
-        };
+        } && rule
+            .features
+            .iter()
+            .all(|(name, wanted)| features.get(name) == *wanted);
 
         if applies {
             allowed = rule.action == "allow";
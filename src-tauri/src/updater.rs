@@ -0,0 +1,65 @@
+use crate::error::AppError;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+/// A newer launcher release than the one running.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateInfo {
+    pub current: String,
+    pub latest: String,
+    pub url: String,
+    pub notes: Option<String>,
+}
+
+/// The subset of a GitHub release we care about.
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    html_url: String,
+    body: Option<String>,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+}
+
+fn is_newer(latest: &str, current: &str) -> bool {
+    let strip = |v: &str| v.trim().trim_start_matches(['v', 'V']).to_string();
+    crate::version_cmp::is_newer(&strip(latest), &strip(current))
+}
+
+/// Query the configured releases endpoint; `None` when we're up to date.
+#[tauri::command]
+pub async fn check_for_updates(app: AppHandle) -> Result<Option<UpdateInfo>, AppError> {
+    let settings = crate::settings::get_settings(app).unwrap_or_default();
+    let current = env!("CARGO_PKG_VERSION");
+
+    let release: Release = crate::http::client()
+        .get(&settings.update_endpoint)
+        .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    if release.draft || release.prerelease || !is_newer(&release.tag_name, current) {
+        return Ok(None);
+    }
+
+    Ok(Some(UpdateInfo {
+        current: current.to_string(),
+        latest: release.tag_name.trim_start_matches(['v', 'V']).to_string(),
+        url: release.html_url,
+        notes: release.body.filter(|b| !b.trim().is_empty()),
+    }))
+}
+
+/// Startup check: emits `update-available` if a newer release exists. Failures are silent.
+pub fn spawn_startup_check(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        if let Ok(Some(info)) = check_for_updates(app.clone()).await {
+            let _ = app.emit("update-available", info);
+        }
+    });
+}
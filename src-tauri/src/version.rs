@@ -1,3 +1,4 @@
+use crate::error::AppError;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -22,10 +23,42 @@ pub struct VersionJson {
     pub r#type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arguments: Option<serde_json::Value>,
+    /// Pre-1.13 game argument template (`--username ${auth_player_name} ...`), used when `arguments` is absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minecraftArguments: Option<String>,
     pub libraries: Vec<Library>,
     pub downloads: Downloads,
     pub mainClass: String,
-    pub assetIndex: AssetIndex,
+    /// Loader profiles usually omit this and inherit it from `inheritsFrom`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assetIndex: Option<AssetIndex>,
+    /// Java runtime Mojang declares for this version (absent on old versions and most loader profiles)
+    #[serde(
+        rename = "javaVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub java_version: Option<JavaVersion>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JavaVersion {
+    #[serde(default)]
+    pub component: Option<String>,
+    #[serde(rename = "majorVersion")]
+    pub major_version: u8,
+}
+
+impl VersionJson {
+    /// The asset index, which must already be resolved for inheriting profiles.
+    pub fn asset_index(&self) -> Result<&AssetIndex, AppError> {
+        self.assetIndex.as_ref().ok_or_else(|| {
+            AppError::NotFound(format!(
+                "version {} has no assetIndex",
+                self.id.as_deref().unwrap_or("<unknown>")
+            ))
+        })
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -58,6 +91,9 @@ pub struct Rule {
     pub action: String,
     #[serde(default)]
     pub os: Option<OsRule>,
+    /// Launcher features the rule requires, e.g. `{"is_demo_user": true}`
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub features: std::collections::HashMap<String, bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -76,3 +112,59 @@ pub struct DownloadInfo {
     pub sha1: String,
     pub size: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A Fabric profile as the launcher stores it: no `assetIndex`, inherited instead.
+    const FABRIC_PROFILE: &str = r#"{
+        "id": "fabric-loader-0.15.11-1.20.4",
+        "inheritsFrom": "1.20.4",
+        "type": "release",
+        "mainClass": "net.fabricmc.loader.impl.launch.knot.KnotClient",
+        "libraries": [
+            {
+                "name": "net.fabricmc:fabric-loader:0.15.11",
+                "downloads": {
+                    "artifact": {
+                        "path": "net/fabricmc/fabric-loader/0.15.11/fabric-loader-0.15.11.jar",
+                        "url": "https://maven.fabricmc.net/net/fabricmc/fabric-loader/0.15.11/fabric-loader-0.15.11.jar",
+                        "sha1": "",
+                        "size": 0
+                    }
+                }
+            }
+        ],
+        "downloads": {
+            "client": { "url": "https://example.invalid/client.jar", "sha1": "", "size": 0 }
+        }
+    }"#;
+
+    #[test]
+    fn profile_without_asset_index_parses() {
+        let version: VersionJson = serde_json::from_str(FABRIC_PROFILE).unwrap();
+        assert_eq!(version.inheritsFrom.as_deref(), Some("1.20.4"));
+        assert!(version.assetIndex.is_none());
+        assert!(matches!(version.asset_index(), Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn missing_asset_index_is_not_written_back() {
+        let version: VersionJson = serde_json::from_str(FABRIC_PROFILE).unwrap();
+        let json = serde_json::to_value(&version).unwrap();
+        assert!(json.get("assetIndex").is_none());
+    }
+
+    #[test]
+    fn resolved_asset_index_is_returned() {
+        let mut version: VersionJson = serde_json::from_str(FABRIC_PROFILE).unwrap();
+        version.assetIndex = Some(AssetIndex {
+            id: "12".to_string(),
+            url: "https://example.invalid/12.json".to_string(),
+            sha1: String::new(),
+            size: 0,
+        });
+        assert_eq!(version.asset_index().unwrap().id, "12");
+    }
+}
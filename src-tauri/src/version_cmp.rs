@@ -0,0 +1,174 @@
+//! Ordering for version strings as Minecraft, mod loaders, mods and Maven write them.
+//!
+//! Segments are compared numerically (`0.10.0` is newer than `0.9.0`), a pre-release
+//! (`1.20.4-rc1`, `0.15.0-beta.2`, `1.21-pre1`) sorts before its release, and known
+//! qualifiers sort `alpha < beta < pre < rc`. Build metadata after `+`
+//! (`0.141.2+1.21.11`) only breaks ties between two versions that both have it.
+
+use std::cmp::Ordering;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Num(&'a str),
+    Word(&'a str),
+}
+
+/// Split on separators and on every switch between digits and letters,
+/// so `rc1` is `[rc, 1]` and `24w14a` is `[24, w, 14, a]`.
+fn tokens(s: &str) -> Vec<Token<'_>> {
+    let mut out = Vec::new();
+    let mut start = None;
+    let mut digits = false;
+    for (i, c) in s.char_indices() {
+        if !c.is_alphanumeric() {
+            if let Some(st) = start.take() {
+                out.push(token(&s[st..i], digits));
+            }
+            continue;
+        }
+        let is_digit = c.is_ascii_digit();
+        match start {
+            Some(st) if is_digit != digits => {
+                out.push(token(&s[st..i], digits));
+                start = Some(i);
+            }
+            Some(_) => {}
+            None => start = Some(i),
+        }
+        digits = is_digit;
+    }
+    if let Some(st) = start {
+        out.push(token(&s[st..], digits));
+    }
+    out
+}
+
+fn token(s: &str, digits: bool) -> Token<'_> {
+    if digits {
+        Token::Num(s)
+    } else {
+        Token::Word(s)
+    }
+}
+
+/// Digit runs of any length, without parsing (and overflowing) them.
+fn cmp_num(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Known qualifiers in release order; anything else sorts between `pre` and `rc`.
+fn qualifier_rank(word: &str) -> u8 {
+    match word.to_ascii_lowercase().as_str() {
+        "snapshot" => 0,
+        "alpha" | "a" => 1,
+        "beta" | "b" => 2,
+        "milestone" | "m" => 3,
+        "pre" | "preview" => 4,
+        "rc" | "cr" => 6,
+        _ => 5,
+    }
+}
+
+fn cmp_tokens(a: &[Token], b: &[Token]) -> Ordering {
+    for i in 0..a.len().max(b.len()) {
+        let ord = match (a.get(i), b.get(i)) {
+            (Some(Token::Num(x)), Some(Token::Num(y))) => cmp_num(x, y),
+            (Some(Token::Word(x)), Some(Token::Word(y))) => qualifier_rank(x)
+                .cmp(&qualifier_rank(y))
+                .then_with(|| x.to_ascii_lowercase().cmp(&y.to_ascii_lowercase())),
+            (Some(Token::Num(_)), Some(Token::Word(_))) => Ordering::Greater,
+            (Some(Token::Word(_)), Some(Token::Num(_))) => Ordering::Less,
+            // `1.20` is `1.20.0`
+            (Some(Token::Num(x)), None) => cmp_num(x, "0"),
+            (None, Some(Token::Num(y))) => cmp_num("0", y),
+            // A trailing qualifier marks something before the plain version (`1.0` > `1.0a`)
+            (Some(Token::Word(_)), None) => Ordering::Less,
+            (None, Some(Token::Word(_))) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Compare two version strings; `Greater` means `a` is the newer one.
+pub fn compare(a: &str, b: &str) -> Ordering {
+    let (a, a_build) = a.trim().split_once('+').unwrap_or((a.trim(), ""));
+    let (b, b_build) = b.trim().split_once('+').unwrap_or((b.trim(), ""));
+    let (a_core, a_pre) = split_pre(a);
+    let (b_core, b_pre) = split_pre(b);
+
+    cmp_tokens(&tokens(a_core), &tokens(b_core))
+        .then_with(|| match (a_pre, b_pre) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(x), Some(y)) => cmp_tokens(&tokens(x), &tokens(y)),
+        })
+        .then_with(|| {
+            if a_build.is_empty() || b_build.is_empty() {
+                Ordering::Equal
+            } else {
+                cmp_tokens(&tokens(a_build), &tokens(b_build))
+            }
+        })
+}
+
+fn split_pre(v: &str) -> (&str, Option<&str>) {
+    match v.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (v, None),
+    }
+}
+
+/// Whether `a` is a newer version than `b`.
+pub fn is_newer(a: &str, b: &str) -> bool {
+    compare(a, b) == Ordering::Greater
+}
+
+/// `1` when `a` is newer than `b`, `-1` when older, `0` when they're the same version.
+#[tauri::command]
+pub fn compare_versions(a: String, b: String) -> i8 {
+    match compare(&a, &b) {
+        Ordering::Greater => 1,
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_table() {
+        use Ordering::*;
+        for (a, b, expected) in [
+            ("0.9.0", "0.10.0", Less),
+            ("1.21-pre1", "1.21", Less),
+            ("1.21-pre1", "1.21-pre2", Less),
+            ("0.15.0-beta.2", "0.15.0-rc1", Less),
+            ("1.20.4-rc1", "1.20.4-beta.2", Greater),
+            ("1.20", "1.20.0", Equal),
+            ("24w14a", "24w13a", Greater),
+            ("24w14a", "24w14b", Less),
+            ("0.141.2+1.21.11", "0.141.2", Equal),
+            ("0.15.0+build.2", "0.15.0+build.10", Less),
+            ("0.15.1+build.1", "0.15.0+build.9", Greater),
+            ("1.02", "1.2", Equal),
+            ("1.007", "1.10", Less),
+            (
+                "1.123456789012345678901234567890",
+                "1.123456789012345678901234567891",
+                Less,
+            ),
+        ] {
+            assert_eq!(compare(a, b), expected, "{} vs {}", a, b);
+            assert_eq!(compare(b, a), expected.reverse(), "{} vs {}", b, a);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 import { useState, useEffect } from "react";
 import { Trash2, HardDrive, RefreshCw, AlertTriangle } from "lucide-react";
-import type { CleanupInfo } from "../types/types";
+import type { CleanupInfo, ClearedFiles } from "../types/types";
 import { invoke } from "@tauri-apps/api/core";
 
 interface CleanupSettingsProps {
@@ -53,7 +53,12 @@ export default function CleanupSettings({
   };
 
   const clearAssetCache = async () => {
-    if (!cleanupInfo || cleanupInfo.cache_size_mb === 0) return;
+    if (
+      !cleanupInfo ||
+      cleanupInfo.shared_assets_dir ||
+      cleanupInfo.cache_size_mb === 0
+    )
+      return;
 
     const confirmed = confirm(
       "This will clear the asset cache. Assets will be re-downloaded when needed. Continue?",
@@ -74,6 +79,26 @@ export default function CleanupSettings({
     }
   };
 
+  const clearAllCaches = async () => {
+    try {
+      setCleaning(true);
+      const cleared = await invoke<ClearedFiles>("clear_all_caches");
+      console.log(
+        "Cleared caches:",
+        cleared.files,
+        "files,",
+        (cleared.bytes / 1024 / 1024).toFixed(1),
+        "MB",
+      );
+      await loadCleanupInfo();
+      onCleanupComplete();
+    } catch (error) {
+      console.error("Failed to clear caches:", error);
+    } finally {
+      setCleaning(false);
+    }
+  };
+
   const formatSize = (mb: number) => {
     if (mb < 1024) {
       return `${mb.toFixed(1)} MB`;
@@ -182,7 +207,16 @@ export default function CleanupSettings({
             </div>
           </div>
 
-          {cleanupInfo.cache_size_mb > 0 && (
+          {cleanupInfo.shared_assets_dir && (
+            <div className="cleanup-section-empty">
+              <p className="text-secondary">
+                Shared with other launchers at {cleanupInfo.shared_assets_dir};
+                manage it from there
+              </p>
+            </div>
+          )}
+
+          {!cleanupInfo.shared_assets_dir && cleanupInfo.cache_size_mb > 0 && (
             <div className="cleanup-section-content">
               <p className="text-secondary">
                 Assets will be re-downloaded when needed
@@ -205,11 +239,39 @@ export default function CleanupSettings({
             </div>
           )}
 
-          {cleanupInfo.cache_size_mb === 0 && (
-            <div className="cleanup-section-empty">
-              <p className="text-secondary">Cache is empty</p>
+          {!cleanupInfo.shared_assets_dir &&
+            cleanupInfo.cache_size_mb === 0 && (
+              <div className="cleanup-section-empty">
+                <p className="text-secondary">Cache is empty</p>
+              </div>
+            )}
+        </div>
+
+        <div className="cleanup-section">
+          <div className="cleanup-section-header">
+            <div className="cleanup-section-info">
+              <h4>Launcher Caches</h4>
+              <p className="text-secondary">
+                Mod icons, leftover Java downloads and loader installer logs
+              </p>
             </div>
-          )}
+          </div>
+          <div className="cleanup-section-content">
+            <button
+              className="btn-secondary"
+              onClick={clearAllCaches}
+              disabled={cleaning}
+              style={{
+                display: "flex",
+                alignItems: "center",
+                gap: 8,
+                marginTop: 10,
+              }}
+            >
+              <Trash2 size={16} />
+              {cleaning ? "Clearing..." : "Clear Launcher Caches"}
+            </button>
+          </div>
         </div>
       </div>
 
@@ -7,7 +7,11 @@ import {
   Trash2,
   AlertCircle,
 } from "lucide-react";
-import type { ModFileEntry, ModUpdateInfo } from "../types/types";
+import type {
+  ModConflict,
+  ModFileEntry,
+  ModUpdateInfo,
+} from "../types/types";
 import { invoke } from "@tauri-apps/api/core";
 
 interface ModManagerProps {
@@ -18,6 +22,7 @@ interface ModManagerProps {
 export default function ModManager({ instanceId, onRefresh }: ModManagerProps) {
   const [mods, setMods] = useState<ModFileEntry[]>([]);
   const [updateInfo, setUpdateInfo] = useState<ModUpdateInfo[]>([]);
+  const [conflicts, setConflicts] = useState<ModConflict[]>([]);
   const [loading, setLoading] = useState(false);
   const [checkingUpdates, setCheckingUpdates] = useState(false);
 
@@ -32,6 +37,9 @@ export default function ModManager({ instanceId, onRefresh }: ModManagerProps) {
         instanceId,
       });
       setMods(modList);
+      setConflicts(
+        await invoke<ModConflict[]>("detect_mod_conflicts", { instanceId }),
+      );
     } catch (error) {
       console.error("Failed to load mods:", error);
     } finally {
@@ -77,6 +85,27 @@ export default function ModManager({ instanceId, onRefresh }: ModManagerProps) {
     }
   };
 
+  const keepNewest = async (conflict: ModConflict) => {
+    const [keep, ...rest] = conflict.files;
+    if (
+      !confirm(
+        `Keep ${keep.file_name} and remove ${rest.map((f) => f.file_name).join(", ")}?`,
+      )
+    ) {
+      return;
+    }
+
+    try {
+      for (const file of rest) {
+        await invoke("remove_mod", { instanceId, filename: file.file_name });
+      }
+      await loadMods();
+      onRefresh();
+    } catch (error) {
+      console.error("Failed to resolve mod conflict:", error);
+    }
+  };
+
   const updateMod = async (modInfo: ModUpdateInfo) => {
     try {
       // Remove old version
@@ -154,6 +183,31 @@ export default function ModManager({ instanceId, onRefresh }: ModManagerProps) {
         </div>
       </div>
 
+      {conflicts.length > 0 && (
+        <div className="mod-conflicts">
+          {conflicts.map((conflict) => (
+            <div
+              key={`${conflict.kind}-${conflict.files[0].sha1}`}
+              className="mod-conflict"
+            >
+              <AlertCircle size={16} />
+              <span>
+                {conflict.kind === "identical"
+                  ? `${conflict.name} is installed ${conflict.files.length} times`
+                  : `${conflict.files.length} versions of ${conflict.name} installed`}
+                : {conflict.files.map((f) => f.file_name).join(", ")}
+              </span>
+              <button
+                className="btn-secondary"
+                onClick={() => keepNewest(conflict)}
+              >
+                Keep newest
+              </button>
+            </div>
+          ))}
+        </div>
+      )}
+
       {mods.length === 0 ? (
         <div className="empty-state">
           <p>No mods installed</p>
@@ -164,7 +218,9 @@ export default function ModManager({ instanceId, onRefresh }: ModManagerProps) {
           {mods.map((mod) => {
             const updateInfo = getModUpdateInfo(mod.name);
             const disabled = isModDisabled(mod.name);
-            const displayName = getModDisplayName(mod.name);
+            const displayName = mod.info.id
+              ? `${mod.info.name} ${mod.info.version}`
+              : getModDisplayName(mod.name);
 
             return (
               <div
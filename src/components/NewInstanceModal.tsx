@@ -8,6 +8,7 @@ import type {
   ModrinthProjectHit,
   ModrinthVersion,
 } from "../types/types";
+import { errorMessage } from "../errors";
 
 type Tab = "version" | "modpack";
 
@@ -61,7 +62,7 @@ export default function NewInstanceModal({
       });
       setModpackResults(results);
     } catch (e) {
-      addToast(String(e), "error");
+      addToast(errorMessage(e), "error");
     } finally {
       setModpackLoading(false);
     }
@@ -74,7 +75,7 @@ export default function NewInstanceModal({
         await onCreateFromVersion(version);
         onClose();
       } catch (e) {
-        addToast(String(e), "error");
+        addToast(errorMessage(e), "error");
       } finally {
         setCreating(false);
       }
@@ -96,7 +97,7 @@ export default function NewInstanceModal({
           addToast("No modpack version found", "error");
         }
       } catch (e) {
-        addToast(String(e), "error");
+        addToast(errorMessage(e), "error");
       }
     },
     [onSelectModpack, onClose, addToast],
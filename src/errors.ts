@@ -0,0 +1,9 @@
+import type { AppError } from "./types/types";
+
+/** Human-readable text for an invoke() rejection (plain string or structured AppError). */
+export function errorMessage(e: unknown): string {
+  if (e && typeof e === "object" && "message" in e) {
+    return String((e as AppError).message);
+  }
+  return String(e);
+}
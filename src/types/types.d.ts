@@ -16,6 +16,7 @@ export interface VersionManifest {
 }
 
 export interface Instance {
+  schema_version?: number;
   id: string;
   name: string;
   version: string;
@@ -29,13 +30,35 @@ export interface Instance {
   max_memory?: number;
   min_memory?: number;
   java_args?: string;
+  game_args?: string;
+  auto_connect_server?: string;
+  auto_load_world?: string;
   java_warning_ignored: boolean;
+  demo?: boolean;
   loader?: string;
   loader_version?: string;
   mc_version?: string;
+  tags?: string[];
+  group?: string;
+  launch_profiles?: LaunchProfile[];
+  private_versions?: boolean;
+  pinned_version?: boolean;
+  /** Unset follows Settings.auto_update_mods_on_launch */
+  auto_update_mods_on_launch?: ModsUpdateOnLaunch;
+}
+
+/** Overrides for one launch; unset fields fall back to the instance */
+export interface LaunchProfile {
+  name: string;
+  max_memory?: number;
+  min_memory?: number;
+  java_args?: string;
+  game_args?: string;
+  java_path_override?: string;
 }
 
 export interface Settings {
+  schema_version?: number;
   max_memory: number;
   min_memory: number;
   close_on_launch: boolean;
@@ -43,8 +66,30 @@ export interface Settings {
   global_java_args: string;
   global_java_path?: string;
   skip_java_check: boolean;
+  use_recommended_flags?: boolean;
+  data_root_override?: string;
+  assets_dir_override?: string;
+  verify_on_launch?: VerifyMode;
+  maven_repositories?: string[];
+  auto_memory?: boolean;
+  run_loader_installers?: boolean;
+  sequential_install?: boolean;
+  update_endpoint?: string;
+  http_proxy?: string;
+  https_proxy?: string;
+  no_proxy?: string;
+  connect_timeout_secs?: number;
+  request_timeout_secs?: number;
+  log_level?: string;
+  keep_unused_asset_indexes?: number;
+  auto_update_mods_on_launch?: ModsUpdateOnLaunch;
 }
 
+export type VerifyMode = "none" | "quick" | "full";
+
+/** "check" reports available updates without downloading them */
+export type ModsUpdateOnLaunch = "off" | "check" | "apply";
+
 /* Modrinth Types */
 export interface ModrinthSearchResult {
   hits: ModrinthProjectHit[];
@@ -70,6 +115,8 @@ export interface ModrinthVersion {
   game_versions: string[];
   loaders: string[];
   files: ModrinthFile[];
+  date_published?: string;
+  changelog?: string | null;
 }
 
 export interface LoaderCandidate {
@@ -85,22 +132,65 @@ export interface ModrinthFile {
   filename: string;
   primary: boolean;
   size: number;
+  hashes?: Record<string, string>;
 }
 
 export interface ModFileEntry {
   name: string;
   size_bytes: number;
+  info: ModInfo;
+}
+
+export interface ModInfo {
+  id?: string;
+  name: string;
+  version: string;
+  description?: string;
+  depends: string[];
+  loader?: "fabric" | "quilt" | "forge";
+  project_id?: string;
+  icon?: string;
+}
+
+export interface LocalModResult {
+  path: string;
+  info?: ModInfo | null;
+  error?: string | null;
 }
 
 export interface ScreenshotEntry {
   name: string;
   path: string;
+  size_bytes: number;
+  modified: number;
+  width?: number;
+  height?: number;
 }
 
-export interface WorldEntry {
+export interface WorldInfo {
   name: string;
   folder: string;
   path: string;
+  /** Unix milliseconds */
+  last_played?: number;
+  game_mode?: "survival" | "creative" | "adventure" | "spectator" | "hardcore";
+  icon?: string;
+}
+
+export interface WorldStats {
+  world: string;
+  players: number;
+  play_time_ticks: number;
+  distance_walked_cm: number;
+  mobs_killed: number;
+  deaths: number;
+}
+
+export interface WorldBackup {
+  file_name: string;
+  world: string;
+  size_bytes: number;
+  created: number;
 }
 
 export interface ServerEntry {
@@ -123,6 +213,7 @@ export interface ModUpdateInfo {
   latest_version: string;
   project_id: string;
   update_available: boolean;
+  changelog?: string | null;
 }
 
 /* Cleanup Types */
@@ -131,6 +222,8 @@ export interface CleanupInfo {
   orphaned_libraries: string[];
   cache_size_mb: number;
   total_cleanup_mb: number;
+  /** Set when assets are shared via the assets directory override; asset cleanup skips them */
+  shared_assets_dir?: string | null;
 }
 /* System Info Types */
 export interface SystemInfo {
@@ -143,3 +236,322 @@ export interface SystemInfo {
   total_memory?: number;
   launcher_version: string;
 }
+/* Structured backend errors */
+export type AppErrorKind =
+  | "network"
+  | "io"
+  | "parse"
+  | "not_found"
+  | "unknown_version"
+  | "loader_unsupported"
+  | "verification"
+  | "other";
+
+export interface AppError {
+  kind: AppErrorKind;
+  message: string;
+}
+
+export interface InstallStatus {
+  json: boolean;
+  client_jar: boolean;
+  libraries_complete: boolean;
+  assets_complete: boolean;
+}
+
+export interface UpdateInfo {
+  current: string;
+  latest: string;
+  url: string;
+  notes?: string;
+}
+
+export interface LoaderChange {
+  loader: string;
+  loader_version: string;
+  incompatible_mods: string[];
+}
+
+export interface ModConflictFile {
+  file_name: string;
+  version: string;
+  sha1: string;
+  modified: number;
+}
+
+export interface ModConflict {
+  kind: "duplicate_id" | "identical";
+  mod_id?: string;
+  name: string;
+  /** Newest first; files[0] is the one to keep */
+  files: ModConflictFile[];
+}
+
+export interface ShaderMod {
+  id: string;
+  file_name: string;
+  version: string;
+}
+
+export interface ShaderSupport {
+  shader_capable: boolean;
+  installed: ShaderMod[];
+  suggested_project?: "iris" | "oculus";
+  suggested_version_id?: string;
+}
+
+/* Install progress (event: "install-phase") */
+export type InstallPhase =
+  | { phase: "installing_vanilla"; mc_version: string }
+  | { phase: "downloading_libraries" }
+  | { phase: "downloading_assets" }
+  | { phase: "installing_loader"; loader: string }
+  | { phase: "downloading_mods" }
+  | { phase: "extracting_overrides" }
+  | { phase: "verifying" }
+  | { phase: "done" };
+
+export type InstallPhaseEvent = InstallPhase & { instance_id?: string };
+
+export interface InstanceSize {
+  instance_id: string;
+  total: number;
+  mods: number;
+  saves: number;
+  resourcepacks: number;
+  shared_versions_estimate: number;
+}
+
+/* Accounts (event: "accounts-changed" carries Account[]) */
+export interface Account {
+  uuid: string;
+  username: string;
+  active: boolean;
+}
+
+/* Install outcome. "instance-install-complete" carries the Instance itself */
+export interface InstallFailed {
+  instance: Instance;
+  error: string;
+}
+
+export interface InstallFinished {
+  instance: Instance;
+  success: boolean;
+  error?: string;
+}
+
+export interface JavaArgsValidation {
+  valid: boolean;
+  java_path: string;
+  error?: string;
+}
+
+export interface JavaResolution {
+  status:
+    | "compatible"
+    | "found_system"
+    | "downloaded"
+    | "needs_download"
+    | "override_mismatch";
+  required_version: number;
+  java_path: string;
+  steps: string[];
+}
+
+/* event: "java-resolve-progress" */
+export interface JavaResolveProgress {
+  instance_id: string;
+  required_version: number;
+  stage: "searching" | "downloading" | "done";
+}
+
+/* event: "asset-verify-progress" */
+export interface AssetVerifyProgress {
+  checked: number;
+  total: number;
+}
+
+export interface ClearedFiles {
+  files: number;
+  bytes: number;
+}
+
+export interface AssetPruneSummary {
+  removed_indexes: string[];
+  indexes: ClearedFiles;
+  objects: ClearedFiles;
+  objects_skipped?: string | null;
+}
+
+export type ConfigFormat = "toml" | "json" | "properties" | "other";
+
+export interface ConfigFileEntry {
+  /** Relative to .minecraft/config, "/"-separated */
+  path: string;
+  format: ConfigFormat;
+  size_bytes: number;
+  modified: number;
+}
+
+export interface FabricApiStatus {
+  already_installed: boolean;
+  file_name: string;
+  version: string;
+}
+
+export interface SettingsImport {
+  warnings: string[];
+  instances_restored: number;
+}
+
+export interface ModsInstallProgress {
+  done: number;
+  total: number;
+  current: string;
+}
+
+export interface DryRunReport {
+  version_id: string;
+  java_ok: boolean;
+  java_path: string;
+  java_version?: number | null;
+  required_java: number;
+  classpath_entries: string[];
+  missing_files: string[];
+  natives_extracted: boolean;
+  error?: string | null;
+  would_launch: boolean;
+}
+
+export interface ModInstallResult {
+  version_id: string;
+  project_id?: string | null;
+  file_name?: string | null;
+  dependency: boolean;
+  error?: string | null;
+}
+
+export interface LoaderLibraryStatus {
+  name: string;
+  path?: string | null;
+  present: boolean;
+}
+
+export interface LoaderVerification {
+  loader: string;
+  version_id: string;
+  version_json: string;
+  version_json_present: boolean;
+  loader_library?: LoaderLibraryStatus | null;
+  intermediary?: LoaderLibraryStatus | null;
+  missing: string[];
+}
+
+export interface ExternalDirInfo {
+  game_dir: string;
+  detected_launcher?: "vanilla" | "multimc" | null;
+  mc_versions_present: string[];
+  worlds: string[];
+  mods_count: number;
+  has_loader_signs: boolean;
+}
+
+export interface ModsUpdateSummary {
+  updated: { old_file: string; new_file: string; version: string }[];
+  skipped: string[];
+  failed: { file: string; error: string }[];
+}
+
+/* event: "launch-mods-update-finished" ("launch-mods-update-started" carries the instance id) */
+export interface LaunchModsUpdate {
+  instance_id: string;
+  summary?: ModsUpdateSummary | null;
+  /** Check-only mode: updates found but not downloaded */
+  available?: ModsUpdateSummary["updated"];
+  error?: string | null;
+}
+
+/* event: "launch-mods-update-progress" */
+export type LaunchModsProgress = ModsInstallProgress & { instance_id: string };
+
+export interface ServerPackWarning {
+  instance_id: string;
+  client_files: number;
+  server_only_files: number;
+}
+
+export interface ExportOptions {
+  include_saves?: boolean;
+  include_configs?: boolean;
+  include_resourcepacks?: boolean;
+  exclude_globs?: string[];
+}
+
+export interface ExportSummary {
+  path: string;
+  files: number;
+  size_bytes: number;
+}
+
+export type DiagnosticSeverity = "info" | "warning" | "error";
+
+export interface DataDirIssue {
+  severity: DiagnosticSeverity;
+  path: string;
+  message: string;
+}
+
+export interface DataDirReport {
+  root: string;
+  instances_ok: number;
+  /** Worst first */
+  issues: DataDirIssue[];
+}
+
+/* event: "install-progress" (client jar, libraries and assets combined) */
+export interface InstallProgress {
+  downloadedBytes: number;
+  totalBytes: number;
+  percent: number;
+  speed: number;
+  eta: number;
+}
+
+export interface LogTail {
+  lines: string[];
+  /** Pass back as from_offset on the next call */
+  offset: number;
+  /** The log restarted (new game session); lines begin at the start of the file */
+  reset: boolean;
+}
+
+export interface ModpackReset {
+  removed: string[];
+  restored: string[];
+  overrides_applied: number;
+}
+
+/* events: "safe-mode-started", "safe-mode-ended" */
+export interface SafeModeEvent {
+  instance_id: string;
+}
+
+/* event: "launch-notice" */
+export interface LaunchNotice {
+  instance_id: string;
+  message: string;
+}
+
+export interface InstalledVersion {
+  version_id: string;
+  is_loader_derived: boolean;
+  size_bytes: number;
+  instance_ids: string[];
+}
+
+export interface InstanceGroup {
+  /** null for the ungrouped bucket, which comes last */
+  name?: string | null;
+  instances: Instance[];
+}